@@ -0,0 +1,141 @@
+/*!
+CI pipeline backends.
+
+A backend defines which template renders the pipeline file, where the pipeline file is written
+to and which backend specific data is added to the render context built by
+`Regression::build_context`.
+ */
+
+use serde_json::{json, Value};
+use tera::Context;
+
+use crate::constants::*;
+use crate::error::Result;
+use crate::pipeline::{self, Pipeline};
+use crate::RegressionConfig;
+
+/// Backend names accepted by [`new_backend`].
+pub const BACKENDS: &[&str] = &[BACKEND_GITLAB, BACKEND_GITHUB];
+
+pub trait PipelineBackend {
+    /// Template file name looked up in template directory.
+    fn template(&self) -> &'static str;
+    /// Bundled template used if template directory does not provide [`PipelineBackend::template`].
+    fn default_template(&self) -> Option<&'static str> {
+        None
+    }
+    /// Pipeline file path relative to CI file path.
+    fn file_name(&self, config: &RegressionConfig) -> String;
+    /// Add backend specific data to render context.
+    fn extend_context(&self, _config: &RegressionConfig, _context: &mut Context) -> Result<()> {
+        Ok(())
+    }
+    /// Render pipeline file without template. Returns `None` if backend has no native pipeline
+    /// model.
+    fn render_native(&self, _config: &RegressionConfig, _context: &Context) -> Result<Option<String>> {
+        Ok(None)
+    }
+    /// Check rendered pipeline file before it is written.
+    fn verify(&self, _data: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_backend(name: &str) -> Option<Box<dyn PipelineBackend>> {
+    match name {
+        BACKEND_GITLAB => Some(Box::new(GitlabBackend)),
+        BACKEND_GITHUB => Some(Box::new(GithubBackend)),
+        _ => None,
+    }
+}
+
+pub struct GitlabBackend;
+
+impl PipelineBackend for GitlabBackend {
+    fn template(&self) -> &'static str {
+        PIPELINE_TEMPLATE_FILE_NAME
+    }
+
+    fn default_template(&self) -> Option<&'static str> {
+        Some(include_str!("../templates/.gitlab-ci.yml.tpl"))
+    }
+
+    fn file_name(&self, _config: &RegressionConfig) -> String {
+        PIPELINE_FILE_NAME.to_string()
+    }
+
+    fn render_native(&self, config: &RegressionConfig, context: &Context) -> Result<Option<String>> {
+        Ok(Some(Pipeline::build(config, context)?.to_yaml()?))
+    }
+
+    fn verify(&self, data: &str) -> Result<()> {
+        pipeline::check(data)
+    }
+}
+
+/// Renders GitHub Actions workflow. Stages become jobs linked with `needs`, actions become
+/// `workflow_dispatch` choice input and CI artifacts are passed between jobs with
+/// upload-artifact / download-artifact steps.
+pub struct GithubBackend;
+
+impl PipelineBackend for GithubBackend {
+    fn template(&self) -> &'static str {
+        GITHUB_WORKFLOW_TEMPLATE_FILE_NAME
+    }
+
+    fn default_template(&self) -> Option<&'static str> {
+        Some(include_str!("../templates/github-workflow.yml.tpl"))
+    }
+
+    fn file_name(&self, config: &RegressionConfig) -> String {
+        format!("{}/{}.yml", GITHUB_WORKFLOWS_PATH, config.project.module)
+    }
+
+    fn extend_context(&self, config: &RegressionConfig, context: &mut Context) -> Result<()> {
+        let stages: Vec<String> = context.get(KEY_STAGES)
+            .and_then(|s| serde_json::from_value(s.clone()).ok())
+            .unwrap_or_default();
+        let mut jobs: Vec<Value> = Vec::new();
+        let mut prev: Option<String> = None;
+
+        for stage in stages.iter() {
+            let id = github_job_id(stage);
+            jobs.push(json!({
+                KEY_ID: id,
+                KEY_NAME: stage,
+                KEY_NEEDS: prev.iter().collect::<Vec<_>>(),
+            }));
+            prev = Some(id);
+        }
+
+        let mut inputs: Vec<String> = vec![GITHUB_ACTION_ALL.to_string()];
+        if let Some(actions) = context.get(KEY_ACTIONS).and_then(|a| a.as_object()) {
+            for (_, jobs) in actions.iter() {
+                for job in jobs.as_array().into_iter().flatten().filter_map(|j| j.as_str()) {
+                    inputs.push(format!("{KEY_DEPLOY}-{job}"));
+                    inputs.push(format!("{KEY_DESTROY}-{job}"));
+                }
+            }
+        }
+
+        context.insert(KEY_WORKFLOW, &json!({
+            KEY_NAME: config.project.module,
+            KEY_JOBS: jobs,
+            KEY_INPUTS: inputs,
+            KEY_ARTIFACTS: &config.ci.artifacts,
+        }));
+
+        Ok(())
+    }
+}
+
+/// GitHub job ids may only contain alphanumeric characters, `-` or `_` and must start with a
+/// letter or `_`.
+fn github_job_id(stage: &str) -> String {
+    let id: String = stage.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' }).collect();
+
+    match id.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => id,
+        _ => format!("_{id}"),
+    }
+}
@@ -1,9 +1,19 @@
 pub const CONFIG_FILE_NAME: &str = "config.json";
+pub const CONFIG_FILE_NAMES: &[&str] = &["config.json", "config.yaml", "config.yml", "config.toml"];
+pub const CONFIG_EXTENSION_YAML: &str = "yaml";
+pub const CONFIG_EXTENSION_YML: &str = "yml";
+pub const CONFIG_EXTENSION_TOML: &str = "toml";
 pub const CONFIG_FILE_PATH: &str = "regression/config";
+pub const OVERLAY_KEY_MODULES: &str = "modules";
+pub const TERA_FN_ENV: &str = "env";
+pub const ARRAY_MERGE_REPLACE: &str = "replace";
+pub const ARRAY_MERGE_APPEND: &str = "append";
+pub const ARRAY_MERGE_BY_NAME: &str = "merge-by-name";
 
 // KEYS
 pub const KEY_CI: &str = "ci";
 pub const KEY_EUT: &str = "eut";
+pub const KEY_EUTS: &str = "euts";
 pub const KEY_RTE: &str = "rte";
 pub const KEY_SRC: &str = "src";
 pub const KEY_DST: &str = "dst";
@@ -13,20 +23,36 @@ pub const KEY_RTES: &str = "rtes";
 pub const KEY_TYPE: &str = "type";
 pub const KEY_TEST: &str = "test";
 pub const KEY_TESTS: &str = "tests";
+pub const KEY_TAGS: &str = "tags";
+pub const KEY_AFTER: &str = "after";
+pub const KEY_ID: &str = "id";
 pub const KEY_GVID: &str = "id";
 pub const KEY_NAME: &str = "name";
+pub const KEY_VARS: &str = "vars";
+pub const KEY_DEFAULT: &str = "default";
+pub const KEY_JOBS: &str = "jobs";
 pub const KEY_APPLY: &str = "apply";
+pub const KEY_NEEDS: &str = "needs";
 pub const KEY_SITES: &str = "sites";
 pub const KEY_DEPLOY: &str = "deploy";
 pub const KEY_DESTROY: &str = "destroy";
+pub const KEY_DEPENDS_ON: &str = "depends_on";
 pub const KEY_SHARE: &str = "share";
 pub const KEY_COUNT: &str = "count";
 pub const KEY_CONFIG: &str = "config";
+pub const KEY_CONTEXT: &str = "context";
+pub const KEY_ROOT_PATH: &str = "root_path";
+pub const KEY_STAGE: &str = "stage";
 pub const KEY_STAGES: &str = "stages";
 pub const KEY_MODULE: &str = "module";
 pub const KEY_DATA: &str = "data";
+pub const KEY_EDGES: &str = "edges";
+pub const KEY_EXECUTION: &str = "execution";
 pub const KEY_SCRIPT: &str = "script";
+pub const KEY_INPUTS: &str = "inputs";
 pub const KEY_RELEASE: &str = "release";
+pub const KEY_WORKFLOW: &str = "workflow";
+pub const KEY_ARTIFACTS: &str = "artifacts";
 pub const KEY_ID_PATH: &str = "id_path";
 pub const KEY_SCRIPTS: &str = "scripts";
 pub const KEY_ACTIONS: &str = "actions";
@@ -51,6 +77,7 @@ pub const KEY_APPLICATIONS: &str = "applications";
 pub const KEY_DESTINATIONS: &str = "destinations";
 pub const KEY_VERIFICATION: &str = "verification";
 pub const KEY_SCRIPTS_PATH: &str = "scripts_path";
+pub const KEY_VERTICES: &str = "vertices";
 pub const KEY_VERIFICATIONS: &str = "verifications";
 pub const KEY_ARTIFACTS_PATH: &str = "artifacts_path";
 pub const KEY_REFERS_COLLECTION: &str = "refers_collection";
@@ -60,13 +87,47 @@ pub const KEY_REF_ARTIFACTS_PATH: &str = "ref_artifacts_path";
 // miscellaneous
 pub const PIPELINE_FILE_NAME: &str = ".gitlab-ci.yml";
 pub const PIPELINE_TEMPLATE_FILE_NAME: &str = ".gitlab-ci.yml.tpl";
+pub const ACTION_TEST: &str = "test";
+pub const ACTION_VERIFY: &str = "verify";
 
+pub const GITHUB_WORKFLOWS_PATH: &str = ".github/workflows";
+pub const GITHUB_WORKFLOW_TEMPLATE_FILE_NAME: &str = "github-workflow.yml.tpl";
+pub const GITHUB_ACTION_ALL: &str = "all";
+
+pub const OUT_DIR: &str = "./out";
 pub const ENTRY_FILE_NAME: &str = "entry.md";
+pub const ENTRY_TEMPLATE_FILE_NAME: &str = "entry.tpl";
+pub const ACTIONS_TEMPLATE_FILE_NAME: &str = "actions.tpl";
+pub const GRAPH_JSON_FILE_NAME: &str = "graph.json";
+pub const GRAPH_GV_FILE_NAME: &str = "graph.gv";
+pub const GRAPH_TEMPLATE_FILE_NAME: &str = "graph.tpl";
+pub const STAGES_GV_FILE_NAME: &str = "stages.gv";
+pub const STAGES_MERMAID_FILE_NAME: &str = "stages.mmd";
+
+// GraphViz output formats and views
+pub const GV_FORMAT_DOT: &str = "dot";
+pub const GV_FORMAT_SVG: &str = "svg";
+pub const GV_FORMAT_PNG: &str = "png";
+pub const GV_FORMAT_MERMAID: &str = "mermaid";
+pub const TOPOLOGY_FORMAT_PLANTUML: &str = "plantuml";
+pub const GV_VIEW_ALL: &str = "all";
+pub const GV_VIEW_REFS: &str = "refs";
+pub const GV_VIEW_STAGES: &str = "stages";
+pub const GV_VIEW_TOPOLOGY: &str = "topology";
 pub const ACTIONS_FILE_NAME: &str = "actions.json";
 
+pub const STAGE_TEST_PARALLEL: &str = "test-parallel-deploy";
+pub const STAGE_VERIFICATION_PARALLEL: &str = "verification-parallel-deploy";
+
 pub const PROPERTY_TYPE_GV: &str = "gv";
 pub const PROPERTY_TYPE_BASE: &str = "base";
 pub const PROPERTY_TYPE_MODULE: &str = "module";
+pub const PROPERTY_TYPE_SEQ: &str = "seq";
+pub const PROPERTY_TYPE_SNAPSHOT: &str = "snapshot";
+
+// Pipeline backends
+pub const BACKEND_GITLAB: &str = "gitlab";
+pub const BACKEND_GITHUB: &str = "github";
 
 // Key order
 pub const EUT_KEY_ORDER: &[&str] = &["ci", "provider", "sites", "features", "collectors",
@@ -124,6 +185,7 @@ pub const EDGE_TYPE_REFERS_RTE: &str = "refers_rte";
 pub const EDGE_TYPE_REFERS_EUT: &str = "refers_eut";
 pub const EDGE_TYPE_HAS_REPORTS: &str = "has_reports";
 pub const EDGE_TYPE_NEXT_STAGE: &str = "next_stage";
+pub const EDGE_TYPE_DEPENDS_ON: &str = "depends_on";
 pub const EDGE_TYPE_REFERS_TEST: &str = "refers_test";
 pub const EDGE_TYPE_REFERS_SITE: &str = "refers_site";
 pub const EDGE_TYPE_HAS_FEATURE: &str = "has_feature";
@@ -156,4 +218,5 @@ pub const EDGE_TYPE_APPLICATION_REFERS_FEATURE: &str = "application_refers_featu
 
 //RTE TYPES
 pub const RTE_TYPE_A: &str = "rte_type_a";
-pub const RTE_TYPE_B: &str = "rte_type_b";
\ No newline at end of file
+pub const RTE_TYPE_B: &str = "rte_type_b";
+pub const RTE_TYPE_MESH: &str = "rte_type_mesh";
\ No newline at end of file
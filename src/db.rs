@@ -1,4 +1,6 @@
 //use graphviz_rust::dot_structures::Id;
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use indradb::{AllVertexQuery, BulkInsertItem, Edge, Identifier, Json, QueryExt, Vertex, VertexProperties};
 use log::{error, info};
 use serde_derive::{Deserialize, Serialize};
@@ -6,9 +8,15 @@ use serde_json::{json, to_value, Value};
 use uuid::Uuid;
 
 use crate::{constants::*, EDGE_TYPES, EDGES_COUNT, EdgeTypes, PropertyType, VertexTuple, VertexTypes};
+use crate::error::{Result, Sense8Error};
+
+/// Namespace of UUIDv5 vertex ids.
+const VERTEX_ID_NAMESPACE: Uuid = Uuid::from_u128(0x5e45e8c1_6a0b_4d7e_9f3c_2b8d1e0a7c64);
 
 pub struct Db {
     pub db: indradb::Database<indradb::MemoryDatastore>,
+    /// Sequence number of the next created vertex
+    seq: AtomicU64,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -59,63 +67,129 @@ impl Default for Db {
 
 impl Db {
     pub fn new() -> Self {
-        Db { db: indradb::MemoryDatastore::new_db() }
+        Db { db: indradb::MemoryDatastore::new_db(), seq: AtomicU64::new(0) }
     }
 
-    pub fn create_object(&self, object_type: VertexTypes) -> Vertex {
+    /// In memory graph persisted as msgpack to `path` on [`Db::save`]. Existing file is replaced.
+    pub fn create(path: &str) -> Self {
+        Db { db: indradb::MemoryDatastore::create_msgpack_db(path), seq: AtomicU64::new(0) }
+    }
+
+    /// Load graph previously written with [`Db::save`].
+    pub fn open(path: &str) -> Result<Self> {
+        let db = indradb::MemoryDatastore::read_msgpack_db(path)
+            .map_err(|e| Sense8Error::Db(format!("failed to load graph <{path}>: {e}")))?;
+        let db = Db { db, seq: AtomicU64::new(0) };
+        let next = db.get_all_objects().unwrap_or_default().iter()
+            .filter_map(|v| Self::seq(&db.get_object_with_properties(&v.id)))
+            .max()
+            .map_or(0, |s| s + 1);
+        db.seq.store(next, Ordering::Relaxed);
+        Ok(db)
+    }
+
+    /// Write graph to file given to [`Db::create`] or [`Db::open`].
+    pub fn save(&self) -> Result<()> {
+        info!("Save object graph...");
+        self.db.sync()?;
+        info!("Save object graph -> Done.");
+        Ok(())
+    }
+
+    /// Store regression configuration and render context graph was built with as snapshot
+    /// property of `id`. Makes a saved graph self-contained. See [`Db::get_snapshot`].
+    pub fn set_snapshot(&self, id: &Uuid, snapshot: &Value) -> Result<()> {
+        self.db.bulk_insert(vec![BulkInsertItem::VertexProperty(*id, Identifier::new(PROPERTY_TYPE_SNAPSHOT)
+            .map_err(|e| Sense8Error::Db(e.to_string()))?, Json::new(snapshot.clone()))])?;
+        Ok(())
+    }
+
+    /// Snapshot property of `id` stored with [`Db::set_snapshot`].
+    pub fn get_snapshot(&self, id: &Uuid) -> Option<Value> {
+        self.get_object_with_properties(id).props.into_iter()
+            .find(|p| p.name.as_str() == PROPERTY_TYPE_SNAPSHOT)
+            .map(|p| (*p.value).clone())
+    }
+
+    pub fn create_object(&self, object_type: VertexTypes) -> Result<Vertex> {
         info!("Create new object of type <{}>...", object_type.name());
-        let o = Vertex::new(Identifier::new(object_type.name()).unwrap());
-        self.db.create_vertex(&o).expect("panic while creating project db entry");
-        self.add_object_property(&o, &json!({}), PropertyType::Base);
-        self.add_object_property(&o, &json!({}), PropertyType::Gv);
-        self.add_object_property(&o, &json!({}), PropertyType::Module);
+        let o = self.create_vertex(&object_type, object_type.name())?;
+        self.add_object_property(&o, &json!({}), PropertyType::Base)?;
+        self.add_object_property(&o, &json!({}), PropertyType::Gv)?;
+        self.add_object_property(&o, &json!({}), PropertyType::Module)?;
         info!("Create new object of type <{}> -> Done", object_type.name());
-        o
+        Ok(o)
     }
 
-    pub fn create_object_and_init(&self, object_type: VertexTypes, path: &mut Vec<String>, label: &str, pop: usize) -> (Vertex, IdPath) {
+    pub fn create_object_and_init(&self, object_type: VertexTypes, path: &mut Vec<String>, label: &str, pop: usize) -> Result<(Vertex, IdPath)> {
         info!("Create new object of type <{}>...", object_type.name());
-        let o = Vertex::new(Identifier::new(object_type.name()).unwrap());
-        self.db.create_vertex(&o).expect("panic while creating project db entry");
         let id_path: IdPath = IdPath::new(path, object_type.name(), label, pop);
-        self.add_object_property(&o, &json!({KEY_ID_PATH: id_path.vec}), PropertyType::Base);
+        let o = self.create_vertex(&object_type, &id_path.str)?;
+        self.add_object_property(&o, &json!({KEY_ID_PATH: id_path.vec}), PropertyType::Base)?;
 
         if label == "" {
             self.add_object_property(&o, &json!({
                 KEY_GVID: id_path.str.replace('-', "_"),
                 KEY_GV_LABEL: object_type.name().replace('-', "_"),
-            }), PropertyType::Gv);
+            }), PropertyType::Gv)?;
         } else {
             self.add_object_property(&o, &json!({
                 KEY_GVID: id_path.str.replace('-', "_"),
                 KEY_GV_LABEL: label.replace('-', "_"),
-            }), PropertyType::Gv);
+            }), PropertyType::Gv)?;
         }
 
-        self.add_object_property(&o, &json!({}), PropertyType::Module);
+        self.add_object_property(&o, &json!({}), PropertyType::Module)?;
         info!("Create new object of type <{}> -> Done", object_type.name());
-        (o, id_path)
+        Ok((o, id_path))
+    }
+
+    /// Create vertex with id derived from `name`. Ids are UUIDv5 over name so building the same
+    /// regression twice yields the same ids. Names are id_paths which are expected to be unique.
+    /// Should a name be taken anyway, a counter is appended until a free id is found. Vertex gets
+    /// a sequence number keeping the order objects are created in.
+    fn create_vertex(&self, object_type: &VertexTypes, name: &str) -> Result<Vertex> {
+        let t = Self::create_vertex_identifier(object_type)?;
+        let mut o = Vertex::with_id(Uuid::new_v5(&VERTEX_ID_NAMESPACE, name.as_bytes()), t);
+        let mut i = 1;
+
+        while !self.db.create_vertex(&o)? {
+            error!("Vertex id for <{name}> already taken. Trying <{name}#{i}>");
+            o = Vertex::with_id(Uuid::new_v5(&VERTEX_ID_NAMESPACE, format!("{name}#{i}").as_bytes()), t);
+            i += 1;
+        }
+
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+        self.db.bulk_insert(vec![BulkInsertItem::VertexProperty(o.id, Identifier::new(PROPERTY_TYPE_SEQ)
+            .map_err(|e| Sense8Error::Db(e.to_string()))?, Json::new(json!(seq)))])?;
+
+        Ok(o)
+    }
+
+    fn create_vertex_identifier(object_type: &VertexTypes) -> Result<Identifier> {
+        Identifier::new(object_type.name()).map_err(|_| Sense8Error::UnknownVertexType(object_type.name().to_string()))
     }
 
-    fn create_relationship_identifier(&self, a: &Vertex, b: &Vertex) -> Identifier {
+    fn create_relationship_identifier(&self, a: &Vertex, b: &Vertex) -> Result<Identifier> {
         info!("Create relationship identifier for <{}> and <{}>...", a.t.as_str(), b.t.as_str());
-        let i = Identifier::new(self.get_relationship_type(a, b)).unwrap();
+        let t = self.get_relationship_type(a, b)?;
+        let i = Identifier::new(t).map_err(|_| Sense8Error::UnknownVertexType(t.to_string()))?;
         info!("Create relationship identifier for <{}> and <{}> -> Done.", a.t.as_str(), b.t.as_str());
-        i
+        Ok(i)
     }
 
-    pub fn create_relationship(&self, a: &Vertex, b: &Vertex) -> bool {
+    pub fn create_relationship(&self, a: &Vertex, b: &Vertex) -> Result<bool> {
         info!("Create relationship for <{}> and <{}>...", a.t.as_str(), b.t.as_str());
-        let i = self.create_relationship_identifier(a, b);
+        let i = self.create_relationship_identifier(a, b)?;
         let e = Edge::new(a.id, i, b.id);
-        let status = self.db.create_edge(&e).unwrap_or_else(|_| panic!("panic build relationship between {} and {}", a.t.as_str(), b.t.as_str()));
+        let status = self.db.create_edge(&e)?;
         info!("Create relationship for <{}> and <{}> -> Done.", a.t.as_str(), b.t.as_str());
-        status
+        Ok(status)
     }
 
-    pub fn add_object_property<T: serde::Serialize>(&self, object: &Vertex, value: &T, property_type: PropertyType) {
+    pub fn add_object_property<T: serde::Serialize>(&self, object: &Vertex, value: &T, property_type: PropertyType) -> Result<()> {
         info!("Add new property to object <{}>...", object.t.as_str());
-        let v = to_value(value).unwrap();
+        let v = to_value(value).map_err(|e| Sense8Error::Db(e.to_string()))?;
         let p: BulkInsertItem;
 
         match property_type {
@@ -124,14 +198,15 @@ impl Db {
                     .unwrap(), Json::new(v.clone()));
             }
             PropertyType::Base => {
-                let o_p = self.get_object_properties(&object);
+                let o_p = self.get_object_properties(&object)
+                    .and_then(|o| o.props.into_iter().find(|x| x.name.as_str() == PROPERTY_TYPE_BASE));
                 match o_p {
                     None => {
                         p = BulkInsertItem::VertexProperty(object.id, Identifier::new(PROPERTY_TYPE_BASE)
                             .unwrap(), Json::new(v.clone()));
                     }
                     Some(o) => {
-                        let mut current = o.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().clone();
+                        let mut current = o.value.as_object().unwrap().clone();
                         let mut a = v.as_object().unwrap().clone();
                         a.append(&mut current);
                         p = BulkInsertItem::VertexProperty(object.id, Identifier::new(PROPERTY_TYPE_BASE)
@@ -165,8 +240,9 @@ impl Db {
             }
         }
 
-        self.db.bulk_insert(vec![p]).unwrap();
+        self.db.bulk_insert(vec![p])?;
         info!("Add new property to object <{}> -> Done", object.t.as_str());
+        Ok(())
     }
 
     #[allow(dead_code)]
@@ -199,20 +275,22 @@ impl Db {
         *EDGES_COUNT
     }
 
-    fn get_relationship_type(&self, a: &Vertex, b: &Vertex) -> &str {
+    fn get_relationship_type(&self, a: &Vertex, b: &Vertex) -> Result<&str> {
         info!("Get relationship type for <{}> and <{}>...", a.t.as_str(), b.t.as_str());
         // error!("RELA -----> {:?}, {:?}", a.t, b.t);
-        let e = EDGE_TYPES.get(&VertexTuple(a.t.to_string(), b.t.to_string())).unwrap();
+        let e = EDGE_TYPES.get(&VertexTuple(a.t.to_string(), b.t.to_string()))
+            .ok_or_else(|| Sense8Error::UnknownVertexType(format!("{} -> {}", a.t.as_str(), b.t.as_str())))?;
         info!("Get relationship type for <{}> and <{}> -> Done.", a.t.as_str(), b.t.as_str());
-        e
+        Ok(e)
     }
 
-    pub fn get_relationship_type_by_v_type(&self, a: &Vertex, b: &VertexTypes) -> &str {
+    pub fn get_relationship_type_by_v_type(&self, a: &Vertex, b: &VertexTypes) -> Result<&str> {
         info!("Get relationship type for <{}> and <{}>...", a.t.as_str(), b.name());
         // error!("RELA -----> {:?}, {:?}", a.t, b.t);
-        let e = EDGE_TYPES.get(&VertexTuple(a.t.to_string(), b.name().to_string())).unwrap();
+        let e = EDGE_TYPES.get(&VertexTuple(a.t.to_string(), b.name().to_string()))
+            .ok_or_else(|| Sense8Error::UnknownVertexType(format!("{} -> {}", a.t.as_str(), b.name())))?;
         info!("Get relationship type for <{}> and <{}> -> Done.", a.t.as_str(), b.name());
-        e
+        Ok(e)
     }
 
     pub(crate) fn get_all_objects(&self) -> Option<Vec<Vertex>> {
@@ -221,12 +299,22 @@ impl Db {
         indradb::util::extract_vertices(result.unwrap())
     }
 
+    pub(crate) fn get_object_by_type(&self, object_type: VertexTypes) -> Option<Vertex> {
+        self.get_all_objects()?.into_iter().find(|v| v.t.as_str() == object_type.name())
+    }
+
     pub(crate) fn get_all_edges(&self) -> Option<Vec<Edge>> {
         let q = AllVertexQuery.include().outbound();
         let result = self.db.get(q.unwrap()).unwrap();
         indradb::util::extract_edges(result)
     }
 
+    /// Delete objects together with all their relationships.
+    pub(crate) fn delete_objects(&self, ids: Vec<Uuid>) -> Result<()> {
+        self.db.delete(indradb::SpecificVertexQuery::new(ids))?;
+        Ok(())
+    }
+
     pub(crate) fn get_object(&self, id: &Uuid) -> Vertex {
         let q = self.db.get(indradb::SpecificVertexQuery::single(*id));
         let _objs = indradb::util::extract_vertices(q.unwrap());
@@ -257,12 +345,13 @@ impl Db {
     pub fn get_object_neighbours_out(&self, id: &Uuid, identifier: EdgeTypes) -> Vec<Vertex> {
         let i = Identifier::new(identifier.name().to_string()).unwrap();
         let o = self.db.get(indradb::SpecificVertexQuery::single(*id).outbound().unwrap().t(i));
-        let mut objs: Vec<Vertex> = Vec::new();
+        let mut objs: Vec<VertexProperties> = Vec::new();
 
         for item in indradb::util::extract_edges(o.unwrap()).unwrap().iter() {
-            objs.push(self.get_object(&item.inbound_id));
+            objs.push(self.get_object_with_properties(&item.inbound_id));
         }
-        objs
+        Self::sort_by_seq(&mut objs);
+        objs.into_iter().map(|o| o.vertex).collect()
     }
 
     pub fn get_object_neighbour_with_properties_out(&self, id: &Uuid, identifier: EdgeTypes) -> Option<VertexProperties> {
@@ -288,6 +377,7 @@ impl Db {
         for item in indradb::util::extract_edges(o.unwrap()).unwrap().iter() {
             objs.push(self.get_object_with_properties(&item.inbound_id));
         }
+        Self::sort_by_seq(&mut objs);
         objs
     }
 
@@ -346,12 +436,13 @@ impl Db {
     pub fn get_object_neighbours_in(&self, id: &Uuid, identifier: EdgeTypes) -> Vec<Vertex> {
         let i = Identifier::new(identifier.name().to_string()).unwrap();
         let o = self.db.get(indradb::SpecificVertexQuery::single(*id).inbound().unwrap().t(i));
-        let mut objs: Vec<Vertex> = Vec::new();
+        let mut objs: Vec<VertexProperties> = Vec::new();
 
         for item in indradb::util::extract_edges(o.unwrap()).unwrap().iter() {
-            objs.push(self.get_object(&item.outbound_id));
+            objs.push(self.get_object_with_properties(&item.outbound_id));
         }
-        objs
+        Self::sort_by_seq(&mut objs);
+        objs.into_iter().map(|o| o.vertex).collect()
     }
 
     pub fn get_object_neighbours_with_properties_in(&self, id: &Uuid, identifier: EdgeTypes) -> Vec<VertexProperties> {
@@ -362,9 +453,23 @@ impl Db {
         for item in indradb::util::extract_edges(o.unwrap()).unwrap().iter() {
             objs.push(self.get_object_with_properties(&item.outbound_id));
         }
+        Self::sort_by_seq(&mut objs);
         objs
     }
 
+    /// Order neighbours by creation sequence so traversal order follows configuration declaration
+    /// order and not vertex ids or datastore internals. Objects without sequence number keep their
+    /// order after the others.
+    fn sort_by_seq(objs: &mut [VertexProperties]) {
+        objs.sort_by_cached_key(|o| Self::seq(o).unwrap_or(u64::MAX));
+    }
+
+    fn seq(object: &VertexProperties) -> Option<u64> {
+        object.props.iter()
+            .find(|p| p.name.as_str() == PROPERTY_TYPE_SEQ)
+            .and_then(|p| p.value.as_u64())
+    }
+
     pub fn get_object_properties(&self, object: &Vertex) -> Option<VertexProperties> {
         info!("Get object <{}> properties...", object.t.as_str());
         let b = indradb::SpecificVertexQuery::new(vec!(object.id)).properties().unwrap();
@@ -0,0 +1,268 @@
+/*!
+Regression diff.
+
+Builds two regressions, e.g. from two root paths or two EUT configuration files, and compares
+what ends up in the generated pipeline. Stages, jobs and their script lines are taken from the
+native pipeline model, artifacts paths and refs from the object graph.
+ */
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde_derive::Serialize;
+use serde_json::Value;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::error::Result;
+use crate::overlay::Overlay;
+use crate::pipeline::{Job, Pipeline};
+use crate::Regression;
+
+/// Regression configuration one side of the diff is built from.
+pub struct Source<'a> {
+    pub path: &'a str,
+    pub file: &'a str,
+    pub eut_file: &'a Option<String>,
+    pub overlay: &'a Overlay,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    fn sign(&self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Modified => '~',
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Subject {
+    Stage,
+    Job,
+    Artifacts,
+    Ref,
+}
+
+impl Subject {
+    fn name(&self) -> &'static str {
+        match self {
+            Subject::Stage => "stage",
+            Subject::Job => "job",
+            Subject::Artifacts => "artifacts",
+            Subject::Ref => "ref",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub subject: Subject,
+    pub name: String,
+    pub details: Vec<String>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.kind.sign(), self.subject.name(), self.name)?;
+        for detail in self.details.iter() {
+            write!(f, "\n    {detail}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct DiffReport {
+    pub changes: Vec<Change>,
+}
+
+impl DiffReport {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+
+    fn push(&mut self, kind: ChangeKind, subject: Subject, name: &str, details: Vec<String>) {
+        self.changes.push(Change { kind, subject, name: name.to_string(), details });
+    }
+}
+
+impl fmt::Display for DiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in self.changes.iter() {
+            writeln!(f, "{change}")?;
+        }
+        match self.changes.len() {
+            0 => write!(f, "no changes"),
+            1 => write!(f, "1 change"),
+            n => write!(f, "{n} changes"),
+        }
+    }
+}
+
+/// Comparable outcome of one regression.
+struct Snapshot {
+    stages: Vec<String>,
+    jobs: BTreeMap<String, Job>,
+    artifacts: BTreeMap<String, String>,
+    refs: BTreeSet<String>,
+}
+
+impl Snapshot {
+    fn build(source: &Source) -> Result<Self> {
+        let db = Db::new();
+        let r = Regression::new(&db, source.path, source.file, "", source.eut_file, source.overlay)?;
+        let (p, refs) = r.init()?;
+        r.init_refs(p, &refs)?;
+        r.init_artifacts(p, &refs)?;
+        r.init_stages(p)?;
+        let ctx = r.build_context(p)?;
+        let pipeline = Pipeline::build(&r.config, &ctx)?;
+        let graph = r.export_graph();
+
+        let mut artifacts: BTreeMap<String, String> = BTreeMap::new();
+        for v in graph[KEY_VERTICES].as_array().into_iter().flatten() {
+            let id_path = v[KEY_ID_PATH].as_str().unwrap_or_default();
+            for key in [KEY_ARTIFACTS_PATH, KEY_REF_ARTIFACTS_PATH] {
+                match &v[PROPERTY_TYPE_BASE][key] {
+                    Value::Null => {}
+                    Value::String(s) => { artifacts.insert(format!("{id_path} {key}"), s.to_string()); }
+                    value => { artifacts.insert(format!("{id_path} {key}"), value.to_string()); }
+                }
+            }
+        }
+
+        let refs: BTreeSet<String> = graph[KEY_EDGES].as_array().into_iter().flatten()
+            .filter(|e| e[KEY_TYPE].as_str().unwrap_or_default().contains("refers"))
+            .map(|e| format!("{} -[{}]-> {}", e[KEY_SRC].as_str().unwrap_or_default(),
+                             e[KEY_TYPE].as_str().unwrap_or_default(), e[KEY_DST].as_str().unwrap_or_default()))
+            .collect();
+
+        Ok(Snapshot {
+            stages: pipeline.stages,
+            jobs: pipeline.jobs.into_iter().filter(|(n, _)| !n.starts_with('.')).collect(),
+            artifacts,
+            refs,
+        })
+    }
+}
+
+/// Build regressions `old` and `new` and report added, removed and modified stages, jobs, script
+/// lines, artifacts paths and refs.
+pub fn diff(old: &Source, new: &Source) -> Result<DiffReport> {
+    let a = Snapshot::build(old)?;
+    let b = Snapshot::build(new)?;
+    let mut report = DiffReport::default();
+
+    // Stages
+    for stage in a.stages.iter().filter(|s| !b.stages.contains(s)) {
+        report.push(ChangeKind::Removed, Subject::Stage, stage, vec![]);
+    }
+    for stage in b.stages.iter().filter(|s| !a.stages.contains(s)) {
+        report.push(ChangeKind::Added, Subject::Stage, stage, vec![]);
+    }
+    let a_common: Vec<&String> = a.stages.iter().filter(|s| b.stages.contains(s)).collect();
+    let b_common: Vec<&String> = b.stages.iter().filter(|s| a.stages.contains(s)).collect();
+    if a_common != b_common {
+        report.push(ChangeKind::Modified, Subject::Stage, "order", diff_lines(&a_common, &b_common));
+    }
+
+    // Jobs
+    for (name, job) in a.jobs.iter().filter(|(n, _)| !b.jobs.contains_key(*n)) {
+        report.push(ChangeKind::Removed, Subject::Job, name, stage_detail(job));
+    }
+    for (name, job) in b.jobs.iter().filter(|(n, _)| !a.jobs.contains_key(*n)) {
+        report.push(ChangeKind::Added, Subject::Job, name, stage_detail(job));
+    }
+    for (name, old_job) in a.jobs.iter() {
+        if let Some(new_job) = b.jobs.get(name) {
+            let mut details: Vec<String> = Vec::new();
+            if old_job.stage != new_job.stage {
+                details.push(format!("stage: {} -> {}", old_job.stage.as_deref().unwrap_or_default(),
+                                     new_job.stage.as_deref().unwrap_or_default()));
+            }
+            let old_paths = old_job.artifacts.as_ref().map(|a| a.paths.clone()).unwrap_or_default();
+            let new_paths = new_job.artifacts.as_ref().map(|a| a.paths.clone()).unwrap_or_default();
+            if old_paths != new_paths {
+                details.push(format!("artifacts: [{}] -> [{}]", old_paths.join(", "), new_paths.join(", ")));
+            }
+            details.extend(diff_lines(&old_job.script, &new_job.script));
+            if !details.is_empty() {
+                report.push(ChangeKind::Modified, Subject::Job, name, details);
+            }
+        }
+    }
+
+    // Artifacts paths
+    for (name, path) in a.artifacts.iter() {
+        match b.artifacts.get(name) {
+            None => report.push(ChangeKind::Removed, Subject::Artifacts, name, vec![path.to_string()]),
+            Some(p) if p != path => report.push(ChangeKind::Modified, Subject::Artifacts, name, vec![format!("{path} -> {p}")]),
+            Some(_) => {}
+        }
+    }
+    for (name, path) in b.artifacts.iter().filter(|(n, _)| !a.artifacts.contains_key(*n)) {
+        report.push(ChangeKind::Added, Subject::Artifacts, name, vec![path.to_string()]);
+    }
+
+    // Refs
+    for r in a.refs.difference(&b.refs) {
+        report.push(ChangeKind::Removed, Subject::Ref, r, vec![]);
+    }
+    for r in b.refs.difference(&a.refs) {
+        report.push(ChangeKind::Added, Subject::Ref, r, vec![]);
+    }
+
+    Ok(report)
+}
+
+fn stage_detail(job: &Job) -> Vec<String> {
+    job.stage.iter().map(|s| format!("stage: {s}")).collect()
+}
+
+/// Changed lines between `a` and `b` based on their longest common subsequence. Removed lines
+/// are prefixed with `-`, added lines with `+`.
+fn diff_lines<T: AsRef<str>>(a: &[T], b: &[T]) -> Vec<String> {
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = match a[i].as_ref() == b[j].as_ref() {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a[i].as_ref() == b[j].as_ref() {
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("- {}", a[i].as_ref()));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", b[j].as_ref()));
+            j += 1;
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// Crate wide error type returned by config loading, object graph initialisation and rendering.
+#[derive(Debug)]
+pub enum Sense8Error {
+    /// Configuration, module configuration or script file could not be read.
+    ConfigNotFound { path: String, source: std::io::Error },
+    /// File content is not valid JSON, YAML or TOML.
+    JsonParse { path: String, line: usize, column: usize, msg: String },
+    /// File content is valid but does not match the expected structure.
+    ConfigInvalid { path: String, msg: String },
+    /// Mandatory key is missing in object configuration identified by id_path.
+    MissingKey { key: String, id_path: String },
+    /// Module configuration does not match the typed module configuration of its kind.
+    ModuleConfigInvalid { kind: String, module: String, path: String, msg: String },
+    /// Key exists but holds a value of unexpected type.
+    InvalidType { key: String, expected: &'static str, id_path: String },
+    /// Vertex type or vertex type pair is not known to the object graph.
+    UnknownVertexType(String),
+    /// Object refers to another object which does not exist.
+    DanglingReference { id_path: String, r#type: String, name: String },
+    /// Tera template could not be loaded or rendered.
+    TemplateRender { template: String, msg: String },
+    /// Native pipeline model could not be built or serialized.
+    Pipeline(String),
+    /// Rendered pipeline file failed post render checks. Holds job name and problem per issue.
+    PipelineInvalid(Vec<(String, String)>),
+    /// Sub-graph filter is malformed or selects no object.
+    Filter(String),
+    /// Command line override or variable is malformed or does not fit the configuration.
+    Override(String),
+    /// Tests depend on each other in a cycle. Holds id_paths of tests which could not be ordered.
+    DependencyCycle(Vec<String>),
+    /// Object graph could not be converted by GraphViz.
+    GraphViz(String),
+    /// Underlying graph datastore operation failed.
+    Db(String),
+    /// Output file could not be written.
+    Io { path: String, source: std::io::Error },
+}
+
+pub type Result<T> = std::result::Result<T, Sense8Error>;
+
+impl fmt::Display for Sense8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sense8Error::ConfigNotFound { path, source } => {
+                write!(f, "configuration file <{path}> could not be read: {source}")
+            }
+            Sense8Error::JsonParse { path, line, column, msg } => {
+                write!(f, "failed to parse <{path}> at line {line} column {column}: {msg}")
+            }
+            Sense8Error::ConfigInvalid { path, msg } => {
+                write!(f, "invalid configuration in <{path}>: {msg}")
+            }
+            Sense8Error::MissingKey { key, id_path } => {
+                write!(f, "missing key <{key}> in <{id_path}>")
+            }
+            Sense8Error::ModuleConfigInvalid { kind, module, path, msg } => {
+                write!(f, "invalid {kind} module <{module}> configuration in <{path}>: {msg}")
+            }
+            Sense8Error::InvalidType { key, expected, id_path } => {
+                write!(f, "key <{key}> in <{id_path}> is expected to be {expected}")
+            }
+            Sense8Error::UnknownVertexType(t) => {
+                write!(f, "unknown vertex type <{t}>")
+            }
+            Sense8Error::DanglingReference { id_path, r#type, name } => {
+                write!(f, "<{}> refers to unknown {} <{}>", id_path, r#type, name)
+            }
+            Sense8Error::TemplateRender { template, msg } => {
+                write!(f, "failed to render template <{template}>: {msg}")
+            }
+            Sense8Error::Pipeline(msg) => {
+                write!(f, "failed to build pipeline: {msg}")
+            }
+            Sense8Error::PipelineInvalid(issues) => {
+                write!(f, "rendered pipeline is invalid")?;
+                for (job, msg) in issues.iter() {
+                    match job.is_empty() {
+                        true => write!(f, "\n  {msg}")?,
+                        false => write!(f, "\n  job <{job}>: {msg}")?,
+                    }
+                }
+                Ok(())
+            }
+            Sense8Error::Filter(msg) => {
+                write!(f, "invalid filter: {msg}")
+            }
+            Sense8Error::Override(msg) => {
+                write!(f, "invalid override: {msg}")
+            }
+            Sense8Error::DependencyCycle(tests) => {
+                write!(f, "test dependencies form a cycle, unable to order <{}>", tests.join(">, <"))
+            }
+            Sense8Error::GraphViz(msg) => {
+                write!(f, "failed to render graph: {msg}")
+            }
+            Sense8Error::Db(msg) => {
+                write!(f, "graph datastore error: {msg}")
+            }
+            Sense8Error::Io { path, source } => {
+                write!(f, "failed to write <{path}>: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Sense8Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Sense8Error::ConfigNotFound { source, .. } => Some(source),
+            Sense8Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<indradb::Error> for Sense8Error {
+    fn from(e: indradb::Error) -> Self {
+        Sense8Error::Db(e.to_string())
+    }
+}
+
+impl Sense8Error {
+    pub(crate) fn template(template: &str, e: tera::Error) -> Self {
+        // Tera wraps the actual cause into a chain of errors. Flatten it to get a useful message.
+        let mut msg = e.to_string();
+        let mut source = std::error::Error::source(&e);
+
+        while let Some(s) = source {
+            msg = format!("{msg}: {s}");
+            source = s.source();
+        }
+
+        Sense8Error::TemplateRender { template: template.to_string(), msg }
+    }
+
+    pub(crate) fn json(path: &str, e: serde_json::Error) -> Self {
+        if e.is_data() {
+            Sense8Error::ConfigInvalid { path: path.to_string(), msg: e.to_string() }
+        } else {
+            Sense8Error::JsonParse { path: path.to_string(), line: e.line(), column: e.column(), msg: e.to_string() }
+        }
+    }
+}
+
+/// Read file content and map io errors to [`Sense8Error::ConfigNotFound`].
+pub(crate) fn read_file(path: &str) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| Sense8Error::ConfigNotFound { path: path.to_string(), source: e })
+}
+
+/// Typed access to mandatory keys of configuration and property maps.
+pub(crate) trait PropertiesExt {
+    fn get_value(&self, key: &str, id_path: &str) -> Result<&Value>;
+
+    fn get_str_value(&self, key: &str, id_path: &str) -> Result<&str> {
+        self.get_value(key, id_path)?.as_str().ok_or_else(|| invalid_type(key, "a string", id_path))
+    }
+
+    fn get_i64_value(&self, key: &str, id_path: &str) -> Result<i64> {
+        self.get_value(key, id_path)?.as_i64().ok_or_else(|| invalid_type(key, "an integer", id_path))
+    }
+
+    fn get_array_value(&self, key: &str, id_path: &str) -> Result<&Vec<Value>> {
+        self.get_value(key, id_path)?.as_array().ok_or_else(|| invalid_type(key, "an array", id_path))
+    }
+
+    fn get_object_value(&self, key: &str, id_path: &str) -> Result<&Map<String, Value>> {
+        self.get_value(key, id_path)?.as_object().ok_or_else(|| invalid_type(key, "an object", id_path))
+    }
+}
+
+impl PropertiesExt for Map<String, Value> {
+    fn get_value(&self, key: &str, id_path: &str) -> Result<&Value> {
+        self.get(key).ok_or_else(|| Sense8Error::MissingKey { key: key.to_string(), id_path: id_path.to_string() })
+    }
+}
+
+impl PropertiesExt for Value {
+    fn get_value(&self, key: &str, id_path: &str) -> Result<&Value> {
+        self.get(key).ok_or_else(|| Sense8Error::MissingKey { key: key.to_string(), id_path: id_path.to_string() })
+    }
+}
+
+fn invalid_type(key: &str, expected: &'static str, id_path: &str) -> Sense8Error {
+    Sense8Error::InvalidType { key: key.to_string(), expected, id_path: id_path.to_string() }
+}
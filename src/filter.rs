@@ -0,0 +1,287 @@
+/*!
+Sub-graph filtering.
+
+Prunes the object graph to a subset of rtes, sites or tests before artifacts and stages are
+initialised, so only reachable jobs and stages end up in the rendered pipeline. Selected
+connection sources keep their connection, rte, connection destinations and the sites they refer
+to. Selected tests keep their verifications, the collectors they refer to and the tests they
+depend on. Collectors referred to by reports are always kept. Tests and verifications may further
+be selected by their tags.
+ */
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use indradb::VertexProperties;
+use log::info;
+use regex::Regex;
+use uuid::Uuid;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::error::{Result, Sense8Error};
+use crate::{EdgeTypes, ObjRefs, PropertyType, VertexTypes};
+
+/// Object types removed by [`prune`] if not selected. Objects below them, e.g. rte components or
+/// test ci, go with them.
+const PRUNABLE_TYPES: &[&str] = &[VERTEX_TYPE_RTE, VERTEX_TYPE_CONNECTION, VERTEX_TYPE_CONNECTION_SRC,
+    VERTEX_TYPE_CONNECTION_DST, VERTEX_TYPE_SITE, VERTEX_TYPE_TEST, VERTEX_TYPE_VERIFICATION, VERTEX_TYPE_COLLECTOR];
+
+/// Selects part of the object graph. Parsed from `<kind>=<value>`.
+#[derive(Clone, Debug)]
+pub enum Filter {
+    /// Rte by name with all its connections and tests
+    Rte(String),
+    /// Sites by regex with connections having them as source or destination
+    Site(Regex),
+    /// Test by name
+    Test(String),
+}
+
+impl Filter {
+    pub fn parse(s: &str) -> Result<Self> {
+        let (kind, value) = s.split_once('=')
+            .ok_or_else(|| Sense8Error::Filter(format!("<{s}> is expected to be <kind>=<value>")))?;
+
+        match kind {
+            KEY_RTE => Ok(Filter::Rte(value.to_string())),
+            VERTEX_TYPE_SITE => Regex::new(value)
+                .map(Filter::Site)
+                .map_err(|e| Sense8Error::Filter(format!("<{s}>: {e}"))),
+            KEY_TEST => Ok(Filter::Test(value.to_string())),
+            _ => Err(Sense8Error::Filter(format!("<{s}> has unknown kind <{kind}>. Known kinds: {KEY_RTE}, {VERTEX_TYPE_SITE}, {KEY_TEST}"))),
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::Rte(name) => write!(f, "{KEY_RTE}={name}"),
+            Filter::Site(re) => write!(f, "{VERTEX_TYPE_SITE}={re}"),
+            Filter::Test(name) => write!(f, "{KEY_TEST}={name}"),
+        }
+    }
+}
+
+/// Selects tests and verifications by their `tags`.
+#[derive(Clone, Debug, Default)]
+pub struct TagFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl TagFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Object carrying `tags` is selected if it has any included tag, or no tags are included,
+    /// and none of the excluded tags.
+    fn selects(&self, tags: &[String]) -> bool {
+        (self.include.is_empty() || tags.iter().any(|t| self.include.contains(t)))
+            && !tags.iter().any(|t| self.exclude.contains(t))
+    }
+}
+
+/// Remove objects not selected by any of `filters` from graph. Refs pointing to removed objects
+/// are dropped from `obj_refs`. Returns refs of remaining objects.
+pub fn prune(db: &Db, filters: &[Filter], obj_refs: Vec<ObjRefs>) -> Result<Vec<ObjRefs>> {
+    if filters.is_empty() {
+        return Ok(obj_refs);
+    }
+
+    info!("Prune object graph...");
+    let objects = all_objects(db);
+    let of_type = |t: &'static str| objects.iter().filter(move |o| o.vertex.t.as_str() == t);
+
+    let mut sources: BTreeSet<Uuid> = BTreeSet::new();
+    let mut tests: BTreeSet<Uuid> = BTreeSet::new();
+
+    for filter in filters.iter() {
+        let mut f_sources: BTreeSet<Uuid> = BTreeSet::new();
+        let mut f_tests: BTreeSet<Uuid> = BTreeSet::new();
+
+        match filter {
+            Filter::Rte(name) => {
+                for rte in of_type(VERTEX_TYPE_RTE).filter(|o| base_str(o, KEY_NAME) == *name) {
+                    for c in db.get_object_neighbours_out(&rte.vertex.id, EdgeTypes::HasConnections).iter() {
+                        for conn in db.get_object_neighbours_out(&c.id, EdgeTypes::HasConnection).iter() {
+                            f_sources.extend(db.get_object_neighbours_out(&conn.id, EdgeTypes::HasConnectionSrc).iter().map(|s| s.id));
+                        }
+                    }
+                }
+            }
+            Filter::Site(re) => {
+                for site in of_type(VERTEX_TYPE_SITE).filter(|o| re.is_match(&base_str(o, KEY_NAME))) {
+                    for v in db.get_object_neighbours_in(&site.vertex.id, EdgeTypes::RefersSite).iter() {
+                        match v.t.as_str() {
+                            VERTEX_TYPE_CONNECTION_SRC => { f_sources.insert(v.id); }
+                            VERTEX_TYPE_CONNECTION_DST => f_sources.extend(db.get_object_neighbours_in(&v.id, EdgeTypes::HasConnectionDst).iter().map(|s| s.id)),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            Filter::Test(name) => {
+                for test in of_type(VERTEX_TYPE_TEST).filter(|o| base_str(o, KEY_NAME) == *name) {
+                    f_tests.insert(test.vertex.id);
+                    f_sources.extend(db.get_object_neighbours_in(&test.vertex.id, EdgeTypes::Runs).iter().map(|s| s.id));
+                }
+            }
+        }
+
+        if f_sources.is_empty() && f_tests.is_empty() {
+            return Err(Sense8Error::Filter(format!("<{filter}> does not select any object")));
+        }
+
+        // Rte and site filters select all tests of selected connection sources
+        if !matches!(filter, Filter::Test(_)) {
+            for s in f_sources.iter() {
+                f_tests.extend(db.get_object_neighbours_out(s, EdgeTypes::Runs).iter().map(|t| t.id));
+            }
+        }
+
+        sources.extend(f_sources);
+        tests.extend(f_tests);
+    }
+
+    // Tests selected tests depend on
+    let mut pending: Vec<Uuid> = tests.iter().copied().collect();
+    while let Some(t) = pending.pop() {
+        for d in db.get_object_neighbours_out(&t, EdgeTypes::DependsOn).iter() {
+            if tests.insert(d.id) {
+                pending.push(d.id);
+                sources.extend(db.get_object_neighbours_in(&d.id, EdgeTypes::Runs).iter().map(|s| s.id));
+            }
+        }
+    }
+
+    // Dependencies of selected connection sources and tests
+    let mut keep: HashSet<Uuid> = HashSet::new();
+
+    for s in sources.iter() {
+        keep.insert(*s);
+        let mut endpoints = vec![*s];
+        endpoints.extend(db.get_object_neighbours_out(s, EdgeTypes::HasConnectionDst).iter().map(|d| d.id));
+
+        for e in endpoints.iter() {
+            keep.insert(*e);
+            keep.extend(db.get_object_neighbours_out(e, EdgeTypes::RefersSite).iter().map(|site| site.id));
+        }
+
+        for conn in db.get_object_neighbours_in(s, EdgeTypes::HasConnectionSrc).iter() {
+            keep.insert(conn.id);
+            for c in db.get_object_neighbours_in(&conn.id, EdgeTypes::HasConnection).iter() {
+                keep.extend(db.get_object_neighbours_in(&c.id, EdgeTypes::HasConnections).iter().map(|rte| rte.id));
+            }
+        }
+    }
+
+    for t in tests.iter() {
+        keep.insert(*t);
+        keep.extend(db.get_object_neighbours_out(t, EdgeTypes::Needs).iter().map(|v| v.id));
+        keep.extend(db.get_object_neighbours_out(t, EdgeTypes::TestRefersCollector).iter().map(|c| c.id));
+    }
+
+    // Reports are not pruned and keep the collectors they refer to
+    for report in of_type(VERTEX_TYPE_REPORT) {
+        keep.extend(db.get_object_neighbours_out(&report.vertex.id, EdgeTypes::ReportRefersCollector).iter().map(|c| c.id));
+    }
+
+    // Unselected objects and everything below them
+    let roots: Vec<Vec<String>> = objects.iter()
+        .filter(|o| PRUNABLE_TYPES.contains(&o.vertex.t.as_str()) && !keep.contains(&o.vertex.id))
+        .map(id_path)
+        .collect();
+    let obj_refs = remove(db, &objects, &roots, &keep, obj_refs)?;
+
+    info!("Prune object graph -> Done.");
+    Ok(obj_refs)
+}
+
+/// Remove tests and verifications not selected by `tags` from graph. Verifications without tags
+/// follow their test. Refs pointing to removed objects are dropped from `obj_refs`. Returns refs
+/// of remaining objects.
+pub fn prune_tags(db: &Db, tags: &TagFilter, obj_refs: Vec<ObjRefs>) -> Result<Vec<ObjRefs>> {
+    if tags.is_empty() {
+        return Ok(obj_refs);
+    }
+
+    info!("Prune object graph by tags...");
+    let objects = all_objects(db);
+    let roots: Vec<Vec<String>> = objects.iter()
+        .filter(|o| match o.vertex.t.as_str() {
+            VERTEX_TYPE_TEST => !tags.selects(&base_tags(o)),
+            VERTEX_TYPE_VERIFICATION => {
+                let v_tags = base_tags(o);
+                !v_tags.is_empty() && !tags.selects(&v_tags)
+            }
+            _ => false,
+        })
+        .map(id_path)
+        .collect();
+    let obj_refs = remove(db, &objects, &roots, &HashSet::new(), obj_refs)?;
+
+    info!("Prune object graph by tags -> Done.");
+    Ok(obj_refs)
+}
+
+/// Delete objects below `roots` not in `keep` and drop refs pointing to deleted objects.
+fn remove(db: &Db, objects: &[VertexProperties], roots: &[Vec<String>], keep: &HashSet<Uuid>, obj_refs: Vec<ObjRefs>) -> Result<Vec<ObjRefs>> {
+    let (removed, remaining): (Vec<&VertexProperties>, Vec<&VertexProperties>) = objects.iter()
+        .partition(|o| !keep.contains(&o.vertex.id) && roots.iter().any(|r| id_path(o).starts_with(r)));
+
+    info!("Removing <{}> of <{}> objects", removed.len(), objects.len());
+    let removed: HashSet<Uuid> = removed.iter().map(|o| o.vertex.id).collect();
+    db.delete_objects(removed.iter().copied().collect())?;
+
+    // Remaining type and name pairs to check refs against
+    let names: HashSet<(String, String)> = remaining.iter()
+        .map(|o| (o.vertex.t.as_str().to_string(), base_str(o, KEY_NAME)))
+        .collect();
+    let exists = |t: &str, name: &str| !PRUNABLE_TYPES.contains(&t) || names.contains(&(t.to_string(), name.to_string()));
+
+    Ok(obj_refs.into_iter()
+        .filter(|o| !removed.contains(&o.id))
+        .map(|mut o| {
+            o.refs.retain(|r| {
+                let r_type = VertexTypes::get_type_by_key(r.get(KEY_TYPE).and_then(|t| t.as_str()).unwrap_or_default()).name();
+                let r_name = r.get(KEY_NAME).and_then(|n| n.as_str()).unwrap_or_default();
+                let r_rte = r.get(KEY_RTE).and_then(|n| n.as_str());
+                exists(r_type, r_name) && r_rte.is_none_or(|rte| exists(VERTEX_TYPE_RTE, rte))
+            });
+            o
+        })
+        .collect())
+}
+
+fn all_objects(db: &Db) -> Vec<VertexProperties> {
+    db.get_all_objects().unwrap_or_default().iter()
+        .map(|v| db.get_object_with_properties(&v.id))
+        .collect()
+}
+
+fn base_tags(object: &VertexProperties) -> Vec<String> {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(KEY_TAGS))
+        .and_then(|t| t.as_array())
+        .map(|t| t.iter().filter_map(|t| t.as_str()).map(|t| t.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn base_str(object: &VertexProperties, key: &str) -> String {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn id_path(object: &VertexProperties) -> Vec<String> {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(KEY_ID_PATH))
+        .and_then(|p| p.as_array())
+        .map(|p| p.iter().filter_map(|c| c.as_str()).map(|c| c.to_string()).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,73 @@
+/*!
+Configuration file formats.
+
+Regression configuration, EUT configuration and module configuration files are accepted as JSON,
+YAML or TOML. The format is detected by file extension. Files without known extension are read
+as JSON. Every format is parsed into the same JSON value the object graph stores.
+ */
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::constants::*;
+use crate::error::{Result, Sense8Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Detect format of file `path` by its extension.
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some(CONFIG_EXTENSION_YAML) | Some(CONFIG_EXTENSION_YML) => ConfigFormat::Yaml,
+            Some(CONFIG_EXTENSION_TOML) => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "JSON",
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Toml => "TOML",
+        }
+    }
+}
+
+/// Parse configuration `raw` read from `path` in the format detected by the extension of `path`.
+pub(crate) fn parse(path: &str, raw: &str) -> Result<Value> {
+    match ConfigFormat::from_path(path) {
+        ConfigFormat::Json => serde_json::from_str(raw).map_err(|e| Sense8Error::json(path, e)),
+        ConfigFormat::Yaml => serde_yaml::from_str(raw).map_err(|e| {
+            let (line, column) = e.location().map(|l| (l.line(), l.column())).unwrap_or_default();
+            Sense8Error::JsonParse { path: path.to_string(), line, column, msg: e.to_string() }
+        }),
+        ConfigFormat::Toml => toml::from_str(raw).map_err(|e| {
+            let (line, column) = e.span().map(|s| position(raw, s.start)).unwrap_or_default();
+            Sense8Error::JsonParse { path: path.to_string(), line, column, msg: e.message().to_string() }
+        }),
+    }
+}
+
+/// Module configuration file in directory `dir`. First existing of `config.json`, `config.yaml`,
+/// `config.yml` and `config.toml`. Falls back to `config.json` so a missing file is reported
+/// with its default name.
+pub(crate) fn module_config_file(dir: &str) -> String {
+    CONFIG_FILE_NAMES.iter()
+        .map(|name| format!("{dir}/{name}"))
+        .find(|path| Path::new(path).is_file())
+        .unwrap_or_else(|| format!("{dir}/{CONFIG_FILE_NAME}"))
+}
+
+/// One based line and column of byte `offset` in `raw`.
+fn position(raw: &str, offset: usize) -> (usize, usize) {
+    let before = &raw[..offset.min(raw.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, column)
+}
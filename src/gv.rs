@@ -0,0 +1,250 @@
+/*!
+GraphViz export of the object graph.
+
+Builds the render context for `graph.tpl`. Nodes are styled per vertex type and edges are
+labeled with their edge type. Optionally objects are grouped into clusters by id_path prefix,
+one cluster per EUT, RTE and connection. A project holding more than one EUT is always grouped
+into one cluster per EUT. Rendered output is checked to be valid DOT and may be
+converted to SVG or PNG with a locally installed GraphViz `dot` executable.
+
+Views restrict the graph to a subset of objects:
+- `all` renders every object,
+- `stages` renders deploy and destroy stages,
+- `topology` renders RTEs with their connections, components and sites,
+- `refs` renders objects linked by edges resolved from refs.
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
+
+use indradb::VertexProperties;
+use serde_json::{json, Value};
+use tera::Context;
+use graphviz_rust::cmd::{CommandArg, Format};
+use graphviz_rust::dot_structures::Graph;
+use graphviz_rust::printer::PrinterContext;
+use uuid::Uuid;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::error::{Result, Sense8Error};
+use crate::PropertyType;
+
+/// Output formats accepted by [`render`].
+pub const GV_FORMATS: &[&str] = &[GV_FORMAT_DOT, GV_FORMAT_SVG, GV_FORMAT_PNG];
+/// Views accepted by [`build_context`].
+pub const GV_VIEWS: &[&str] = &[GV_VIEW_ALL, GV_VIEW_STAGES, GV_VIEW_TOPOLOGY, GV_VIEW_REFS];
+
+/// Vertex types rendered in `topology` view.
+const TOPOLOGY_TYPES: &[&str] = &[VERTEX_TYPE_RTE, VERTEX_TYPE_CONNECTIONS, VERTEX_TYPE_CONNECTION,
+    VERTEX_TYPE_CONNECTION_SRC, VERTEX_TYPE_CONNECTION_DST, VERTEX_TYPE_COMPONENTS, VERTEX_TYPE_COMPONENT_SRC,
+    VERTEX_TYPE_COMPONENT_DST, VERTEX_TYPE_SITE];
+
+/// Vertex types which open a cluster if clusters are enabled.
+const CLUSTER_TYPES: &[&str] = &[VERTEX_TYPE_EUT, VERTEX_TYPE_RTE, VERTEX_TYPE_CONNECTION];
+
+/// Node shape and fill color per vertex type.
+fn style(vertex_type: &str) -> (&'static str, &'static str) {
+    match vertex_type {
+        VERTEX_TYPE_PROJECT => ("house", "#f46d43"),
+        VERTEX_TYPE_CI => ("component", "#fdae61"),
+        VERTEX_TYPE_EUT => ("doubleoctagon", "#d73027"),
+        VERTEX_TYPE_RTE => ("box3d", "#4575b4"),
+        VERTEX_TYPE_CONNECTION => ("hexagon", "#74add1"),
+        VERTEX_TYPE_CONNECTION_SRC | VERTEX_TYPE_COMPONENT_SRC => ("invhouse", "#abd9e9"),
+        VERTEX_TYPE_CONNECTION_DST | VERTEX_TYPE_COMPONENT_DST => ("house", "#abd9e9"),
+        VERTEX_TYPE_SITE => ("box", "#66bd63"),
+        VERTEX_TYPE_TEST => ("parallelogram", "#fee08b"),
+        VERTEX_TYPE_VERIFICATION => ("trapezium", "#d9ef8b"),
+        VERTEX_TYPE_STAGE_DEPLOY | VERTEX_TYPE_STAGE_DESTROY => ("cds", "#a6d96a"),
+        VERTEX_TYPE_EUT_PROVIDER | VERTEX_TYPE_RTE_PROVIDER
+        | VERTEX_TYPE_DASHBOARD_PROVIDER | VERTEX_TYPE_APPLICATION_PROVIDER => ("cylinder", "#1a9850"),
+        VERTEX_TYPE_FEATURE | VERTEX_TYPE_APPLICATION => ("septagon", "#e0f3f8"),
+        VERTEX_TYPE_COLLECTOR | VERTEX_TYPE_REPORT | VERTEX_TYPE_DASHBOARD => ("note", "#fee090"),
+        VERTEX_TYPE_SCRIPT | VERTEX_TYPE_SHARE => ("tab", "#ffffbf"),
+        VERTEX_TYPE_RTES | VERTEX_TYPE_SITES | VERTEX_TYPE_FEATURES | VERTEX_TYPE_PROVIDERS | VERTEX_TYPE_SCRIPTS
+        | VERTEX_TYPE_REPORTS | VERTEX_TYPE_COLLECTORS | VERTEX_TYPE_COMPONENTS | VERTEX_TYPE_CONNECTIONS
+        | VERTEX_TYPE_APPLICATIONS => ("folder", "#bababa"),
+        _ => ("ellipse", "#ffffff"),
+    }
+}
+
+struct Node {
+    id: String,
+    label: String,
+    t: String,
+    id_path: Vec<String>,
+}
+
+impl Node {
+    fn new(object: &VertexProperties) -> Self {
+        let t = object.vertex.t.as_str().to_string();
+        let gv = object.props.get(PropertyType::Gv.index()).map(|p| &p.value);
+        let gv_str = |key: &str| gv.and_then(|v| v.get(key)).and_then(|v| v.as_str()).map(|v| v.to_string());
+        let id_path = object.props.get(PropertyType::Base.index())
+            .and_then(|p| p.value.get(KEY_ID_PATH))
+            .and_then(|p| p.as_array())
+            .map(|p| p.iter().filter_map(|c| c.as_str()).map(|c| c.to_string()).collect())
+            .unwrap_or_default();
+
+        Node {
+            id: gv_str(KEY_GVID).unwrap_or_else(|| format!("{}_{}", t, object.vertex.id.simple())),
+            label: gv_str(KEY_GV_LABEL).unwrap_or_else(|| t.clone()),
+            t,
+            id_path,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let (shape, fillcolor) = style(&self.t);
+        json!({
+            "kind": "node",
+            "id": quote(&self.id),
+            "label": quote(&self.label),
+            "type": self.t,
+            "shape": shape,
+            "fillcolor": fillcolor,
+        })
+    }
+}
+
+/// Whether object of type `vertex_type` is rendered in `view`. Objects in `refs` view are selected
+/// by their edges instead.
+fn in_view(view: &str, vertex_type: &str) -> bool {
+    match view {
+        GV_VIEW_STAGES => [VERTEX_TYPE_STAGE_DEPLOY, VERTEX_TYPE_STAGE_DESTROY].contains(&vertex_type),
+        GV_VIEW_TOPOLOGY => TOPOLOGY_TYPES.contains(&vertex_type),
+        _ => true,
+    }
+}
+
+/// Whether edge of type `edge_type` is a resolved ref rendered in `refs` view.
+fn is_ref(edge_type: &str) -> bool {
+    edge_type.contains("refers") || edge_type == EDGE_TYPE_DEPENDS_ON
+}
+
+/// File name GraphViz output in `format` is written to if no path is given.
+pub fn file_name(format: &str) -> String {
+    match format {
+        GV_FORMAT_DOT => GRAPH_GV_FILE_NAME.to_string(),
+        _ => format!("graph.{format}"),
+    }
+}
+
+/// Escape `value` for use inside a double quoted DOT string.
+pub(crate) fn quote(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Index of the cluster with the longest id_path being a prefix of `id_path`. `own` excludes the
+/// cluster at that index itself.
+fn deepest_cluster(nodes: &[Node], clusters: &[usize], id_path: &[String], own: Option<usize>) -> Option<usize> {
+    clusters.iter()
+        .filter(|c| Some(**c) != own)
+        .filter(|c| {
+            let p = &nodes[**c].id_path;
+            !p.is_empty() && id_path.starts_with(p)
+        })
+        .max_by_key(|c| nodes[**c].id_path.len())
+        .copied()
+}
+
+fn emit(nodes: &[Node], members: &HashMap<Option<usize>, Vec<usize>>, children: &HashMap<Option<usize>, Vec<usize>>,
+        cluster: Option<usize>, items: &mut Vec<Value>) {
+    if let Some(c) = cluster {
+        let n = &nodes[c];
+        items.push(json!({"kind": "begin", "id": quote(&format!("cluster_{}", n.id)), "label": quote(&format!("{}: {}", n.t, n.label))}));
+    }
+    for m in members.get(&cluster).map(|m| m.as_slice()).unwrap_or_default() {
+        items.push(nodes[*m].to_json());
+    }
+    for c in children.get(&cluster).map(|c| c.as_slice()).unwrap_or_default() {
+        emit(nodes, members, children, Some(*c), items);
+    }
+    if cluster.is_some() {
+        items.push(json!({"kind": "end"}));
+    }
+}
+
+/// Build render context for `graph.tpl` restricted to objects in `view`. Context holds `items`,
+/// a flat list of nodes with `begin` and `end` markers for clusters, and `edges` labeled with
+/// their edge type. Multiple EUTs are clustered even if `clusters` is disabled.
+pub fn build_context(db: &Db, clusters: bool, view: &str) -> Context {
+    let all_edges = db.get_all_edges().unwrap_or_default();
+    let refs: HashSet<Uuid> = all_edges.iter()
+        .filter(|e| is_ref(e.t.as_str()))
+        .flat_map(|e| [e.outbound_id, e.inbound_id])
+        .collect();
+
+    let mut objects: Vec<(Uuid, Node)> = db.get_all_objects().unwrap_or_default().iter()
+        .filter(|v| match view {
+            GV_VIEW_REFS => refs.contains(&v.id),
+            _ => in_view(view, v.t.as_str()),
+        })
+        .map(|v| (v.id, Node::new(&db.get_object_with_properties(&v.id))))
+        .collect();
+    objects.sort_by(|(_, a), (_, b)| a.id_path.cmp(&b.id_path).then_with(|| a.id.cmp(&b.id)));
+
+    let index: HashMap<Uuid, usize> = objects.iter().enumerate().map(|(i, (id, _))| (*id, i)).collect();
+    let nodes: Vec<Node> = objects.into_iter().map(|(_, n)| n).collect();
+
+    let euts: Vec<usize> = (0..nodes.len()).filter(|i| nodes[*i].t.as_str() == VERTEX_TYPE_EUT).collect();
+    let cluster_nodes: Vec<usize> = match clusters {
+        true => (0..nodes.len()).filter(|i| CLUSTER_TYPES.contains(&nodes[*i].t.as_str())).collect(),
+        false if euts.len() > 1 => euts,
+        false => vec![],
+    };
+
+    let mut members: HashMap<Option<usize>, Vec<usize>> = HashMap::new();
+    for (i, n) in nodes.iter().enumerate() {
+        members.entry(deepest_cluster(&nodes, &cluster_nodes, &n.id_path, None)).or_default().push(i);
+    }
+    let mut children: HashMap<Option<usize>, Vec<usize>> = HashMap::new();
+    for c in cluster_nodes.iter() {
+        children.entry(deepest_cluster(&nodes, &cluster_nodes, &nodes[*c].id_path, Some(*c))).or_default().push(*c);
+    }
+
+    let mut items: Vec<Value> = Vec::new();
+    emit(&nodes, &members, &children, None, &mut items);
+
+    let mut edges: Vec<(String, String, String)> = all_edges.iter()
+        .filter(|e| view != GV_VIEW_REFS || is_ref(e.t.as_str()))
+        .filter_map(|e| Some((
+            nodes[*index.get(&e.outbound_id)?].id.clone(),
+            nodes[*index.get(&e.inbound_id)?].id.clone(),
+            e.t.as_str().to_string(),
+        )))
+        .collect();
+    edges.sort();
+
+    let mut context = Context::new();
+    context.insert("items", &items);
+    context.insert("edges", &edges.iter()
+        .map(|(src, dst, t)| json!({KEY_SRC: quote(src), KEY_DST: quote(dst), "label": quote(t)}))
+        .collect::<Vec<Value>>());
+    context
+}
+
+/// Parse rendered `data` to check it is DOT which graphviz-rust is able to handle.
+pub fn parse(data: &str) -> Result<Graph> {
+    graphviz_rust::parse(data)
+        .map_err(|e| Sense8Error::TemplateRender { template: GRAPH_TEMPLATE_FILE_NAME.to_string(), msg: format!("rendered graph is not valid DOT: {e}") })
+}
+
+/// Convert rendered DOT `data` to `format`. DOT is returned as is, SVG and PNG are produced by
+/// GraphViz `dot` executable which has to be found in PATH.
+pub fn render(data: &str, format: &str) -> Result<Vec<u8>> {
+    let graph = parse(data)?;
+    let format = match format {
+        GV_FORMAT_DOT => return Ok(data.as_bytes().to_vec()),
+        GV_FORMAT_SVG => Format::Svg,
+        GV_FORMAT_PNG => Format::Png,
+        f => return Err(Sense8Error::GraphViz(format!("unknown output format <{f}>"))),
+    };
+
+    graphviz_rust::exec(graph, &mut PrinterContext::default(), vec![CommandArg::Format(format)])
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => Sense8Error::GraphViz("GraphViz <dot> executable not found in PATH. Install GraphViz or use format <dot>".to_string()),
+            _ => Sense8Error::GraphViz(e.to_string()),
+        })
+}
@@ -0,0 +1,216 @@
+/*!
+Object graph integrity.
+
+Checks a populated object graph for problems neither `Regression::init_refs` nor the stage chain
+building report themselves: refs pointing at objects which do not exist, objects not reachable
+from the project object, cycles in the `next_stage` chain, sites without provider and connection
+sources without site.
+ */
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use indradb::VertexProperties;
+use serde_derive::Serialize;
+use uuid::Uuid;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::{ObjRefs, PropertyType, VertexTypes};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// Ref points at an object which is not part of the graph.
+    DanglingReference,
+    /// Object is not reachable from the project object.
+    Orphan,
+    /// Stages linked with `next_stage` loop back onto themselves.
+    StageCycle,
+    /// Site does not use any provider.
+    SiteWithoutProvider,
+    /// Connection source does not refer to any site.
+    SourceWithoutSite,
+}
+
+impl IssueKind {
+    fn name(&self) -> &'static str {
+        match self {
+            IssueKind::DanglingReference => "dangling_reference",
+            IssueKind::Orphan => "orphan",
+            IssueKind::StageCycle => "stage_cycle",
+            IssueKind::SiteWithoutProvider => "site_without_provider",
+            IssueKind::SourceWithoutSite => "source_without_site",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Issue {
+    pub kind: IssueKind,
+    pub id_path: String,
+    pub msg: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error[{}] {}: {}", self.kind.name(), self.id_path, self.msg)
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct IntegrityReport {
+    pub issues: Vec<Issue>,
+}
+
+impl IntegrityReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+
+    fn push(&mut self, kind: IssueKind, id_path: &str, msg: String) {
+        self.issues.push(Issue { kind, id_path: id_path.to_string(), msg });
+    }
+}
+
+impl fmt::Display for IntegrityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in self.issues.iter() {
+            writeln!(f, "{issue}")?;
+        }
+        match self.issues.len() {
+            0 => write!(f, "object graph is consistent"),
+            1 => write!(f, "found 1 problem"),
+            n => write!(f, "found {n} problems"),
+        }
+    }
+}
+
+/// Check object graph in `db`. `obj_refs` are the refs returned by `Regression::init`. Refs are
+/// not checked if empty, e.g. for a graph loaded from file.
+pub fn check(db: &Db, obj_refs: &[ObjRefs]) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+    let mut objects: Vec<VertexProperties> = db.get_all_objects().unwrap_or_default().iter()
+        .map(|v| db.get_object_with_properties(&v.id))
+        .collect();
+    objects.sort_by_key(id_path);
+    let by_id: HashMap<Uuid, &VertexProperties> = objects.iter().map(|o| (o.vertex.id, o)).collect();
+
+    // Outgoing edges per object as edge type and target
+    let mut edges: HashMap<Uuid, Vec<(String, Uuid)>> = HashMap::new();
+    for e in db.get_all_edges().unwrap_or_default().iter() {
+        edges.entry(e.outbound_id).or_default().push((e.t.as_str().to_string(), e.inbound_id));
+    }
+    let out = |id: &Uuid| edges.get(id).map(|e| e.as_slice()).unwrap_or_default();
+
+    // Dangling refs
+    for obj in obj_refs.iter() {
+        let o = match by_id.get(&obj.id) {
+            Some(o) => o,
+            None => continue,
+        };
+
+        for r in obj.refs.iter() {
+            let r_key = r.get(KEY_TYPE).and_then(|t| t.as_str()).unwrap_or_default();
+            let r_type = VertexTypes::get_type_by_key(r_key).name();
+            let r_name = r.get(KEY_NAME).and_then(|n| n.as_str()).unwrap_or_default();
+            let matches = |v: &VertexProperties| v.vertex.t.as_str() == r_type && base_str(v, KEY_NAME) == r_name;
+
+            let linked = out(&obj.id).iter().any(|(_, t)| by_id.get(t).is_some_and(|v| matches(v)));
+            // Tests are only unique per connection. Refs to them are resolved to an edge by init_refs
+            let exists = match r_type == VERTEX_TYPE_TEST && [VERTEX_TYPE_COLLECTOR, VERTEX_TYPE_TEST].contains(&o.vertex.t.as_str()) {
+                true => false,
+                false => objects.iter().any(matches),
+            };
+
+            if !linked && !exists {
+                report.push(IssueKind::DanglingReference, &id_path(o), format!("refers to unknown {r_key} <{r_name}>"));
+            }
+        }
+    }
+
+    // Orphans
+    let mut reachable: HashSet<Uuid> = HashSet::new();
+    let mut pending: Vec<Uuid> = objects.iter()
+        .filter(|o| o.vertex.t.as_str() == VERTEX_TYPE_PROJECT)
+        .map(|o| o.vertex.id)
+        .collect();
+    while let Some(id) = pending.pop() {
+        if reachable.insert(id) {
+            pending.extend(out(&id).iter().map(|(_, t)| *t));
+        }
+    }
+    for o in objects.iter().filter(|o| !reachable.contains(&o.vertex.id)) {
+        report.push(IssueKind::Orphan, &id_path(o), format!("{} object is not reachable from {VERTEX_TYPE_PROJECT}", o.vertex.t.as_str()));
+    }
+
+    // Stage cycles
+    let next = |id: &Uuid| -> Vec<Uuid> {
+        out(id).iter().filter(|(t, _)| t == EDGE_TYPE_NEXT_STAGE).map(|(_, t)| *t).collect()
+    };
+    let mut state: BTreeMap<Uuid, bool> = BTreeMap::new(); // false while on current path, true when done
+    for o in objects.iter().filter(|o| !next(&o.vertex.id).is_empty()) {
+        if state.contains_key(&o.vertex.id) {
+            continue;
+        }
+        state.insert(o.vertex.id, false);
+        let mut path: Vec<(Uuid, Vec<Uuid>)> = vec![(o.vertex.id, next(&o.vertex.id))];
+
+        while let Some(top) = path.len().checked_sub(1) {
+            let id = path[top].0;
+            match path[top].1.pop() {
+                Some(c) => match state.get(&c) {
+                    None => {
+                        state.insert(c, false);
+                        path.push((c, next(&c)));
+                    }
+                    Some(false) => {
+                        let from = by_id.get(&id).map(|v| id_path(v)).unwrap_or_default();
+                        let to = by_id.get(&c).map(|v| id_path(v)).unwrap_or_default();
+                        report.push(IssueKind::StageCycle, &from, format!("{EDGE_TYPE_NEXT_STAGE} leads back to <{to}>"));
+                    }
+                    Some(true) => {}
+                },
+                None => {
+                    state.insert(id, true);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    // Sites without provider and connection sources without site
+    for o in objects.iter() {
+        let has_edge = |edge_type: &str| out(&o.vertex.id).iter().any(|(t, _)| t == edge_type);
+        match o.vertex.t.as_str() {
+            VERTEX_TYPE_SITE if !has_edge(EDGE_TYPE_USES_PROVIDER) => {
+                report.push(IssueKind::SiteWithoutProvider, &id_path(o), format!("{VERTEX_TYPE_SITE} does not use any provider"));
+            }
+            VERTEX_TYPE_CONNECTION_SRC if !has_edge(EDGE_TYPE_REFERS_SITE) => {
+                report.push(IssueKind::SourceWithoutSite, &id_path(o), format!("{VERTEX_TYPE_CONNECTION_SRC} does not refer to any site"));
+            }
+            _ => {}
+        }
+    }
+
+    report
+}
+
+fn base_str(object: &VertexProperties, key: &str) -> String {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn id_path(object: &VertexProperties) -> String {
+    match object.props.get(PropertyType::Base.index()).and_then(|p| p.value.get(KEY_ID_PATH)).and_then(|p| p.as_array()) {
+        Some(p) => p.iter().filter_map(|c| c.as_str()).collect::<Vec<&str>>().join("__"),
+        None => object.vertex.t.as_str().to_string(),
+    }
+}
@@ -1,31 +1,52 @@
 use std::any::Any;
 use std::cmp::Ordering;
-use std::collections::{HashMap};
-use std::error::Error;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Debug};
 use std::format;
 use std::io::{Write};
 
 use indradb::{Vertex, VertexProperties};
 use lazy_static::lazy_static;
+use schemars::JsonSchema;
 use log::{error, info};
 use serde_derive::{Deserialize, Serialize};
-use serde_json::{json, Map, to_value, Value};
+use serde_json::{json, Map, Value};
 use serde_json::Value::Null;
 use tera::{Context, Tera};
 use uuid::Uuid;
 
 use objects::{Ci, Eut, Features, Feature, Project, Providers, EutProvider, Rtes, Rte, Sites, Site,
               Dashboard, Application, Applications, Collectors, Collector, Reports, Report,
-              Connections};
+              Connections, ProjectExt};
 
+use crate::backend::PipelineBackend;
 use crate::constants::*;
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result, Sense8Error};
+use crate::filter::{Filter, TagFilter};
+use crate::format::ConfigFormat;
+use crate::integrity::IntegrityReport;
 use crate::objects::{ConnectionSource, Test};
+use crate::objects::config::{ExecutionConfig, ExecutionStrategy};
+use crate::objects::object::ObjectExt;
+use crate::overlay::{ArrayMerge, Overlay};
+use crate::pipeline::Pipeline;
+use crate::topology::RteTopology;
 
+pub mod backend;
 pub mod constants;
 pub mod db;
+pub mod diff;
+pub mod error;
+pub mod filter;
+pub mod format;
+pub mod gv;
+pub mod integrity;
 pub mod objects;
+pub mod overlay;
+pub mod pipeline;
+pub mod topology;
+pub mod validate;
 
 pub enum PropertyType {
     Gv,
@@ -86,6 +107,7 @@ pub enum EdgeTypes {
     RefersEut,
     RefersSite,
     RefersTest,
+    DependsOn,
     NeedsShare,
     HasReports,
     HasFeature,
@@ -253,6 +275,7 @@ impl EdgeTypes {
             EdgeTypes::RefersEut => EDGE_TYPE_REFERS_EUT,
             EdgeTypes::NextStage => EDGE_TYPE_NEXT_STAGE,
             EdgeTypes::RefersTest => EDGE_TYPE_REFERS_TEST,
+            EdgeTypes::DependsOn => EDGE_TYPE_DEPENDS_ON,
             EdgeTypes::RefersSite => EDGE_TYPE_REFERS_SITE,
             EdgeTypes::HasReports => EDGE_TYPE_HAS_REPORTS,
             EdgeTypes::HasFeature => EDGE_TYPE_HAS_FEATURE,
@@ -297,6 +320,13 @@ impl PropertyType {
     }
 }
 
+/// Templates shipped with the tool. Used if the template directory does not provide them.
+const BUNDLED_TEMPLATES: &[(&str, &str)] = &[
+    (GRAPH_TEMPLATE_FILE_NAME, include_str!("../templates/graph.tpl")),
+    (ENTRY_TEMPLATE_FILE_NAME, include_str!("../templates/entry.tpl")),
+    (ACTIONS_TEMPLATE_FILE_NAME, include_str!("../templates/actions.tpl")),
+];
+
 lazy_static! {
     static ref EDGE_TYPES: HashMap<VertexTuple, &'static str> = {
         let mut map = HashMap::new();
@@ -335,10 +365,12 @@ lazy_static! {
         map.insert(VertexTuple(VertexTypes::ConnectionSrc.name().to_string(), VertexTypes::Site.name().to_string()), EdgeTypes::RefersSite.name());
         map.insert(VertexTuple(VertexTypes::ConnectionDst.name().to_string(), VertexTypes::Site.name().to_string()), EdgeTypes::RefersSite.name());
         map.insert(VertexTuple(VertexTypes::ConnectionDst.name().to_string(), VertexTypes::ComponentDst.name().to_string()), EdgeTypes::HasComponentDst.name());
+        map.insert(VertexTuple(VertexTypes::ConnectionDst.name().to_string(), VertexTypes::ComponentSrc.name().to_string()), EdgeTypes::HasComponentSrc.name());
         map.insert(VertexTuple(VertexTypes::Test.name().to_string(), VertexTypes::Ci.name().to_string()), EdgeTypes::HasCi.name());
         map.insert(VertexTuple(VertexTypes::Test.name().to_string(), VertexTypes::Collector.name().to_string()), EdgeTypes::TestRefersCollector.name());
         map.insert(VertexTuple(VertexTypes::Test.name().to_string(), VertexTypes::Application.name().to_string()), EdgeTypes::TestRefersApplication.name());
         map.insert(VertexTuple(VertexTypes::Test.name().to_string(), VertexTypes::Verification.name().to_string()), EdgeTypes::Needs.name());
+        map.insert(VertexTuple(VertexTypes::Test.name().to_string(), VertexTypes::Test.name().to_string()), EdgeTypes::DependsOn.name());
         map.insert(VertexTuple(VertexTypes::Ci.name().to_string(), VertexTypes::StageDeploy.name().to_string()), EdgeTypes::HasDeployStages.name());
         map.insert(VertexTuple(VertexTypes::Ci.name().to_string(), VertexTypes::StageDestroy.name().to_string()), EdgeTypes::HasDestroyStages.name());
         map.insert(VertexTuple(VertexTypes::StageDeploy.name().to_string(), VertexTypes::StageDeploy.name().to_string()), EdgeTypes::NextStage.name());
@@ -363,36 +395,36 @@ lazy_static! {
 #[derive(Hash, Eq, PartialEq, Debug)]
 struct VertexTuple(String, String);
 
-#[derive(Default, Deserialize, Serialize, Clone, Debug)]
+#[derive(Default, Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigGenericCiStages {
     deploy: Vec<String>,
     destroy: Vec<String>,
 }
 
-#[derive(Default, Deserialize, Serialize, Clone, Debug)]
+#[derive(Default, Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigGenericCi {
     stages: RegressionConfigGenericCiStages,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigCiVariables {
     name: String,
     value: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigJobTemplates {
     name: String,
     variables: Vec<RegressionConfigCiVariables>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigCiArtifacts {
     path: String,
     expire_in: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigCi {
     tags: Vec<String>,
     image: String,
@@ -401,7 +433,7 @@ struct RegressionConfigCi {
     job_templates: Vec<RegressionConfigJobTemplates>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigApplications {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -409,31 +441,47 @@ struct RegressionConfigApplications {
     artifacts_file: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+struct RegressionConfigEutVariant {
+    /// Name jobs, stages and artifacts of this EUT are namespaced with
+    name: String,
+    module: String,
+    /// EUT configuration file in module directory. Defaults to --eut-config-file
+    config: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigEut {
     ci: RegressionConfigGenericCi,
     path: String,
+    #[serde(default)]
     module: String,
     config: Option<String>,
     artifacts_dir: String,
     artifacts_file: String,
+    /// EUTs regressed by the project. `module` and `config` select the only EUT if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    variants: Vec<RegressionConfigEutVariant>,
+    #[serde(skip)]
+    #[schemars(skip)]
+    name: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigCollectors {
     path: String,
     artifacts_dir: String,
     artifacts_file: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigReports {
     ci: RegressionConfigGenericCi,
     path: String,
     data_vars_path: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigFeatures {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -441,7 +489,7 @@ struct RegressionConfigFeatures {
     artifacts_file: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigRte {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -450,7 +498,7 @@ struct RegressionConfigRte {
     data_vars_path: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigTests {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -460,12 +508,12 @@ struct RegressionConfigTests {
     data_scripts_path: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigVerificationsSummaries {
     path: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigVerifications {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -473,7 +521,7 @@ struct RegressionConfigVerifications {
     data_scripts_path: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigDashboard {
     ci: RegressionConfigGenericCi,
     path: String,
@@ -481,7 +529,7 @@ struct RegressionConfigDashboard {
     provider: String,
 }
 
-#[derive(Default, Deserialize, Serialize, Clone, Debug)]
+#[derive(Default, Deserialize, Serialize, JsonSchema, Clone, Debug)]
 struct RegressionConfigProject {
     ci: RegressionConfigGenericCi,
     data: String,
@@ -489,7 +537,7 @@ struct RegressionConfigProject {
     module: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
 pub struct RegressionConfig {
     ci: RegressionConfigCi,
     eut: RegressionConfigEut,
@@ -498,14 +546,79 @@ pub struct RegressionConfig {
     project: RegressionConfigProject,
     reports: RegressionConfigReports,
     features: RegressionConfigFeatures,
+    #[schemars(skip)]
     root_path: String,
+    #[serde(skip)]
+    #[schemars(skip)]
+    overlay: Overlay,
     dashboard: RegressionConfigDashboard,
     collectors: RegressionConfigCollectors,
     applications: RegressionConfigApplications,
     verifications: RegressionConfigVerifications,
+    /// EUT name jobs and stages are namespaced with if the project holds more than one EUT
+    #[serde(skip)]
+    #[schemars(skip)]
+    namespace: Option<String>,
 }
 
-#[derive(Serialize, Debug)]
+impl RegressionConfig {
+    /// Regression configuration scoped to each EUT of the project in configuration order. If the
+    /// project holds more than one EUT, stages of EUT scoped objects, job names and artifacts
+    /// dirs are namespaced with the EUT name.
+    pub(crate) fn euts(&self) -> Vec<RegressionConfig> {
+        let variants = match self.eut.variants.is_empty() {
+            true => vec![RegressionConfigEutVariant { name: self.eut.module.clone(), module: self.eut.module.clone(), config: None }],
+            false => self.eut.variants.clone(),
+        };
+        let namespaced = variants.len() > 1;
+
+        variants.iter()
+            .map(|v| {
+                let mut config = self.clone();
+                config.eut.name = v.name.clone();
+                config.eut.module = v.module.clone();
+                config.eut.config = v.config.clone().or_else(|| self.eut.config.clone());
+                config.eut.variants = vec![];
+                if namespaced {
+                    config.set_namespace(&v.name);
+                }
+                config
+            })
+            .collect()
+    }
+
+    fn set_namespace(&mut self, name: &str) {
+        for ci in [&mut self.rte.ci, &mut self.features.ci, &mut self.eut.ci, &mut self.applications.ci,
+            &mut self.tests.ci, &mut self.verifications.ci, &mut self.reports.ci] {
+            for stage in ci.stages.deploy.iter_mut().chain(ci.stages.destroy.iter_mut()) {
+                *stage = format!("{name}-{stage}");
+            }
+        }
+        for dir in [&mut self.eut.artifacts_dir, &mut self.rte.artifacts_dir, &mut self.features.artifacts_dir,
+            &mut self.applications.artifacts_dir, &mut self.tests.artifacts_dir, &mut self.collectors.artifacts_dir] {
+            *dir = format!("{dir}/{name}");
+        }
+        self.namespace = Some(name.to_string());
+    }
+
+    /// Stage or job `name` prefixed with EUT name if namespaced.
+    pub(crate) fn namespaced(&self, name: &str) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{ns}-{name}"),
+            None => name.to_string(),
+        }
+    }
+
+    /// First part of job names. Project module, followed by EUT name if namespaced.
+    pub(crate) fn job_prefix(&self) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{}_{ns}", self.project.module),
+            None => self.project.module.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
 struct ActionsRenderContext {
     rtes: Vec<String>,
     sites: Vec<String>,
@@ -514,6 +627,23 @@ struct ActionsRenderContext {
     features: Vec<String>,
     applications: Vec<String>,
     verifications: Vec<String>,
+    /// Test jobs grouped by test tag
+    tags: BTreeMap<String, Vec<String>>,
+}
+
+impl ActionsRenderContext {
+    fn extend(&mut self, other: &ActionsRenderContext) {
+        self.rtes.extend(other.rtes.iter().cloned());
+        self.sites.extend(other.sites.iter().cloned());
+        self.tests.extend(other.tests.iter().cloned());
+        self.reports.extend(other.reports.iter().cloned());
+        self.features.extend(other.features.iter().cloned());
+        self.applications.extend(other.applications.iter().cloned());
+        self.verifications.extend(other.verifications.iter().cloned());
+        for (tag, jobs) in other.tags.iter() {
+            self.tags.entry(tag.to_string()).or_default().extend(jobs.iter().cloned());
+        }
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -590,6 +720,34 @@ struct EutRenderContext {
     provider: Vec<String>,
 }
 
+/// Render context of one EUT with the objects it holds and the jobs to trigger it
+#[derive(Serialize)]
+struct EutGroupRenderContext {
+    name: String,
+    /// Prefix stages of this EUT are namespaced with. Empty if the project holds one EUT
+    prefix: String,
+    stages: EutStagesRenderContext,
+    eut: EutRenderContext,
+    rtes: Vec<RteRenderContext>,
+    features: Vec<Box<dyn RenderContext>>,
+    applications: Vec<Box<dyn RenderContext>>,
+    collectors: Vec<Box<dyn RenderContext>>,
+    reports: Vec<Box<dyn RenderContext>>,
+    actions: ActionsRenderContext,
+}
+
+/// Stages of objects held by one EUT as placed by its scoped configuration
+#[derive(Serialize)]
+struct EutStagesRenderContext {
+    rte: RegressionConfigGenericCiStages,
+    features: RegressionConfigGenericCiStages,
+    eut: RegressionConfigGenericCiStages,
+    applications: RegressionConfigGenericCiStages,
+    tests: RegressionConfigGenericCiStages,
+    verifications: RegressionConfigGenericCiStages,
+    reports: RegressionConfigGenericCiStages,
+}
+
 #[derive(Serialize, Clone, Debug)]
 struct RteCiRenderContext {
     timeout: Value,
@@ -606,11 +764,16 @@ struct RteVerificationRenderContext {
     name: String,
     module: String,
     data: String,
+    tags: Vec<String>,
+    /// Deploy stage of verification job
+    stage: String,
     scripts: Vec<HashMap<String, Vec<String>>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 struct RteTestRenderContext {
+    #[serde(skip)]
+    id: Uuid,
     ci: Map<String, Value>,
     rte: String,
     job: String,
@@ -618,6 +781,15 @@ struct RteTestRenderContext {
     data: String,
     module: String,
     provider: String,
+    tags: Vec<String>,
+    /// Deploy stage of test job
+    stage: String,
+    /// Test jobs this test job depends on. Holds test ids until resolved to job names by
+    /// `Regression::build_context`
+    needs: Vec<String>,
+    /// Test jobs to finish before this test job starts only to keep test order, e.g. previous
+    /// sequential test or concurrency limit. Resolved like `needs`
+    after: Vec<String>,
     scripts: Vec<HashMap<String, Vec<String>>>,
     verifications: Vec<RteVerificationRenderContext>,
 }
@@ -773,7 +945,7 @@ pub struct ObjRefs {
     id: Uuid, // obj to build rel with
 }
 
-fn build_refs_map(refs: &mut HashMap<String, Vec<String>>, r#type: &str, path: &str) {
+fn build_refs_map(refs: &mut BTreeMap<String, Vec<String>>, r#type: &str, path: &str) {
     if refs.get(&r#type.to_string()).is_none() {
         refs.insert(r#type.to_string(), vec!(path.to_string()));
     } else {
@@ -784,14 +956,64 @@ fn build_refs_map(refs: &mut HashMap<String, Vec<String>>, r#type: &str, path: &
     }
 }
 
+//Test with everything needed to place it into a stage
+struct TestStage {
+    test: VertexProperties,
+    rte: String,
+    src: String,
+    scope: Uuid, // rte or connection execution config is taken from
+    sites: BTreeSet<Uuid>,
+    execution: ExecutionConfig,
+    depends_on: Vec<Uuid>,
+}
+
+/// Order `tests` so every test comes after the tests it depends on. Independent tests keep their
+/// declaration order. Dependencies on tests not in `tests`, e.g. pruned ones, are ignored.
+fn sort_by_dependencies(mut tests: Vec<TestStage>) -> Result<Vec<TestStage>> {
+    let ids: BTreeSet<Uuid> = tests.iter().map(|t| t.test.vertex.id).collect();
+    let mut done: BTreeSet<Uuid> = BTreeSet::new();
+    let mut sorted: Vec<TestStage> = Vec::with_capacity(tests.len());
+
+    while !tests.is_empty() {
+        match tests.iter().position(|t| t.depends_on.iter().all(|d| done.contains(d) || !ids.contains(d))) {
+            Some(i) => {
+                let t = tests.remove(i);
+                done.insert(t.test.vertex.id);
+                sorted.push(t);
+            }
+            None => {
+                return Err(Sense8Error::DependencyCycle(tests.iter().map(|t| object_id_path(&t.test)).collect()));
+            }
+        }
+    }
+
+    Ok(sorted)
+}
+
+/// Id path of `object` joined the way error messages print it.
+fn object_id_path(object: &VertexProperties) -> String {
+    object.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_ID_PATH)
+        .and_then(|p| p.as_array())
+        .map(|p| p.iter().filter_map(|c| c.as_str()).collect::<Vec<_>>().join("__"))
+        .unwrap_or_default()
+}
+
+/// Execution config of rte or connection object. None if object does not set one.
+fn execution_config(object: &VertexProperties) -> Option<ExecutionConfig> {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(KEY_EXECUTION))
+        .filter(|e| !e.is_null())
+        .and_then(|e| serde_json::from_value(e.clone()).ok())
+}
+
 #[typetag::serialize(tag = "type")]
 pub trait RenderContext {
     fn as_any(&self) -> &dyn Any;
 }
 
 pub trait Renderer<'a> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, ctx: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext>;
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>>;
+    fn gen_render_ctx(&self, config: &RegressionConfig, ctx: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>>;
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>>;
 }
 
 pub trait ScriptRenderContext {}
@@ -818,19 +1040,40 @@ impl ScriptRenderContext for ScriptVerificationRenderContext {}
 
 //impl ScriptRenderContext for ScriptRteProviderShareRenderContext {}
 
-pub fn render_script(context: &(impl ScriptRenderContext + serde::Serialize), input: &str) -> String {
+pub fn render_script(context: &(impl ScriptRenderContext + serde::Serialize), input: &str, path: &str) -> Result<String> {
     info!("Render script context...");
-    let ctx = Context::from_serialize(context);
-    let rendered = Tera::one_off(input, &ctx.unwrap(), false).unwrap();
+    let ctx = Context::from_serialize(context).map_err(|e| Sense8Error::template(path, e))?;
+    let rendered = Tera::one_off(input, &ctx, false).map_err(|e| Sense8Error::template(path, e))?;
     info!("Render script context -> Done.");
-    rendered
+    Ok(rendered)
 }
 
 pub fn merge_json(a: &mut Value, b: &Value) {
+    merge_json_with(a, b, ArrayMerge::Replace)
+}
+
+/// Deep merge `b` into `a`. Objects are merged key by key, arrays according to `arrays` and any
+/// other value of `b` replaces the one in `a`.
+pub fn merge_json_with(a: &mut Value, b: &Value, arrays: ArrayMerge) {
     match (a, b) {
-        (&mut Value::Object(ref mut a), &Value::Object(ref b)) => {
+        (Value::Object(a), Value::Object(b)) => {
             for (k, v) in b {
-                merge_json(a.entry(k.clone()).or_insert(Null), v);
+                merge_json_with(a.entry(k.clone()).or_insert(Null), v, arrays);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if arrays != ArrayMerge::Replace => {
+            for v in b {
+                let name = v.get(KEY_NAME).and_then(|n| n.as_str());
+                let item = match (arrays, name) {
+                    (ArrayMerge::MergeByName, Some(name)) => {
+                        a.iter_mut().find(|i| i.get(KEY_NAME).and_then(|n| n.as_str()) == Some(name))
+                    }
+                    _ => None,
+                };
+                match item {
+                    Some(item) => merge_json_with(item, v, arrays),
+                    None => a.push(v.clone()),
+                }
             }
         }
         (a, b) => {
@@ -839,11 +1082,12 @@ pub fn merge_json(a: &mut Value, b: &Value) {
     }
 }
 
-struct RteCtxParameters<'a> {
+/// Objects and render context of one rte handed to [`objects::RteCharacteristics`] to build
+/// connection render contexts.
+pub struct RteCtxParameters<'a> {
     rte: &'a VertexProperties,
     eut: &'a VertexProperties,
     config: &'a RegressionConfig,
-    project_config: RegressionConfigProject,
     project: &'a Vertex,
     rte_name: String,
     rte_crcs: &'a mut RteRenderContext,
@@ -855,59 +1099,112 @@ pub struct Regression<'a> {
     pub config: RegressionConfig,
     pub template: String,
     pub root_path: String,
+    /// Render context of a graph loaded with [`Regression::open`]
+    context: Option<Context>,
 }
 
 impl<'a> Regression<'a> {
-    pub fn new(db: &'a Db, path: &str, file: &str, template: &str, eut_file: &Option<String>) -> Self {
-        Regression {
+    pub fn new(db: &'a Db, path: &str, file: &str, template: &str, eut_file: &Option<String>, overlay: &Overlay) -> Result<Self> {
+        Ok(Regression {
             db,
-            config: Regression::load_regression_config(path, file, eut_file.clone()),
+            config: Regression::load_regression_config(path, file, eut_file.clone(), overlay)?,
             template: String::from(template),
             root_path: path.to_string(),
-        }
+            context: None,
+        })
+    }
+
+    /// Regression of a graph loaded with [`Db::open`] and saved after [`Regression::save`].
+    /// Configuration and render context are taken from the graph, so neither configuration nor
+    /// script files are read again. Replaces `init` and follow-up init steps which already ran
+    /// before the graph was saved. Returns regression and project object id.
+    pub fn open(db: &'a Db, template: &str) -> Result<(Self, Uuid)> {
+        let p = db.get_object_by_type(VertexTypes::Project)
+            .map(|p| p.id)
+            .ok_or_else(|| Sense8Error::Db("graph does not contain a project object".to_string()))?;
+        let snapshot = db.get_snapshot(&p)
+            .ok_or_else(|| Sense8Error::Db("graph does not contain a regression snapshot. Save it again".to_string()))?;
+        let config = serde_json::from_value(snapshot[KEY_CONFIG].clone())
+            .map_err(|e| Sense8Error::Db(format!("regression snapshot <{KEY_CONFIG}>: {e}")))?;
+        let context = Context::from_value(snapshot[KEY_CONTEXT].clone())
+            .map_err(|e| Sense8Error::Db(format!("regression snapshot <{KEY_CONTEXT}>: {e}")))?;
+
+        Ok((Regression {
+            db,
+            config,
+            template: String::from(template),
+            root_path: snapshot[KEY_ROOT_PATH].as_str().unwrap_or_default().to_string(),
+            context: Some(context),
+        }, p))
+    }
+
+    /// Store configuration and render context with the graph of project `id`, so it can be
+    /// rendered after [`Regression::open`] without its source files. Call after all init steps.
+    pub fn save(&self, id: Uuid) -> Result<()> {
+        let context = self.build_context(id)?;
+        self.db.set_snapshot(&id, &json!({
+            KEY_CONFIG: &self.config,
+            KEY_CONTEXT: context.into_json(),
+            KEY_ROOT_PATH: &self.root_path,
+        }))?;
+        self.db.save()
     }
 
-    pub fn init(&self) -> (Uuid, Vec<ObjRefs>) {
+    pub fn init(&self) -> Result<(Uuid, Vec<ObjRefs>)> {
         //Stores object refs statements for later refs creation
         let mut object_refs: Vec<ObjRefs> = Vec::new();
 
         // Project
-        let project = Project::init(self.db, &self.config, &mut vec![], &self.config.project.module, 0);
+        let project = Project::init(self.db, &self.config, &mut vec![], &self.config.project.module, 0)?;
 
         // Dashboard
         let dashboard = Dashboard::init(self.db, &self.config,
-                                        &mut project.get_id_path().get_vec(), "", 0);
-        self.db.create_relationship(&project.get_object(), &dashboard.get_object());
+                                        &mut project.get_id_path().get_vec(), "", 0)?;
+        self.db.create_relationship(&project.get_object(), &dashboard.get_object())?;
 
         // Ci
         let ci = Ci::init(self.db, &self.config, &json!(&self.config.ci),
-                          &mut project.get_id_path().get_vec(), "", 0);
-        self.db.create_relationship(&project.get_object(), &ci.get_object());
+                          &mut project.get_id_path().get_vec(), "", 0)?;
+        self.db.create_relationship(&project.get_object(), &ci.get_object())?;
 
-        // Eut
-        let eut = Eut::init(self.db, &self.config,
-                            &mut project.get_id_path().get_vec(), &self.config.eut.module, 0);
+        // Euts
+        for config in self.config.euts().iter() {
+            self.init_eut(config, project.as_ref(), &mut object_refs)?;
+        }
+
+        Ok((project.get_id(), object_refs))
+    }
+
+    fn init_eut(&self, config: &RegressionConfig, project: &(dyn ObjectExt + 'a), object_refs: &mut Vec<ObjRefs>) -> Result<()> {
+        let eut = Eut::init(self.db, config,
+                            &mut project.get_id_path().get_vec(), &config.eut.name, 0)?;
         let eut_module_cfg = eut.get_module_cfg();
-        self.db.create_relationship(&project.get_object(), &eut.get_object());
-        let eut_providers = Providers::init(self.db, &self.config,
-                                            &mut eut.get_id_path().get_vec(), "", 0);
-        self.db.create_relationship(&eut.get_object(), &eut_providers.get_object());
+        let eut_id_path = eut.get_id_path().get_str();
+        self.db.create_relationship(&project.get_object(), &eut.get_object())?;
+        let eut_providers = Providers::init(self.db, config,
+                                            &mut eut.get_id_path().get_vec(), "", 0)?;
+        self.db.create_relationship(&eut.get_object(), &eut_providers.get_object())?;
 
         for k in EUT_KEY_ORDER.iter() {
-            let obj = eut_module_cfg.get(*k).unwrap();
+            //Every key is mandatory even if not turned into objects
+            eut_module_cfg.get_value(k, &eut_id_path)?;
             match *k {
                 k if k == KEY_PROVIDER => {
-                    for p in obj.as_array().unwrap().iter() {
-                        let eut_provider = EutProvider::init(self.db, &self.config,
+                    for p in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        let eut_provider = EutProvider::init(self.db, config,
                                                              &mut eut.get_id_path().get_vec(),
-                                                             &p.as_str().unwrap(), 0);
-                        self.db.create_relationship(&eut_providers.get_object(), &eut_provider.get_object());
+                                                             p.as_str().ok_or_else(|| Sense8Error::InvalidType {
+                                                                 key: KEY_PROVIDER.to_string(),
+                                                                 expected: "a string",
+                                                                 id_path: eut_id_path.clone(),
+                                                             })?, 0)?;
+                        self.db.create_relationship(&eut_providers.get_object(), &eut_provider.get_object())?;
                     }
                 }
                 k if k == KEY_SITES => {
-                    let o = Sites::init(self.db, &self.config,
-                                        &mut eut.get_id_path().get_vec(), "", 2);
-                    self.db.create_relationship(&eut.get_object(), &o.get_object());
+                    let o = Sites::init(self.db, config,
+                                        &mut eut.get_id_path().get_vec(), "", 2)?;
+                    self.db.create_relationship(&eut.get_object(), &o.get_object())?;
                     let _p = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasProviders);
                     let provider = self.
                         db.get_object_neighbours_with_properties_out(&_p.unwrap().id,
@@ -920,39 +1217,49 @@ impl<'a> Regression<'a> {
                             get(KEY_NAME).unwrap().as_str().unwrap(), p.vertex.id);
                     }
 
-                    for (site_name, site_attr) in obj.as_object().unwrap().iter() {
-                        let site_count = site_attr.as_object().unwrap().get(KEY_COUNT).unwrap().as_i64().unwrap();
+                    for (site_name, site_attr) in eut_module_cfg.get_object_value(k, &eut_id_path)?.iter() {
+                        let site_count = site_attr.get_i64_value(KEY_COUNT, &eut_id_path)?;
 
                         match site_count.cmp(&1i64) {
                             Ordering::Equal => {
                                 let s_o = Site::init(&self.db,
-                                                     &self.config,
+                                                     config,
                                                      site_attr,
                                                      &mut o.get_id_path().get_vec(),
                                                      site_name,
-                                                     0);
-                                self.db.create_relationship(&o.get_object(), &s_o.get_object());
-                                let provider = &site_attr.as_object().unwrap().get(KEY_PROVIDER).unwrap().as_str().unwrap();
-                                let p_o = self.db.get_object(id_name_map.get(provider).unwrap());
-                                self.db.create_relationship(&s_o.get_object(), &p_o);
-                                self.db.add_object_property(&s_o.get_object(), &json!({KEY_NAME: site_name}), PropertyType::Base);
+                                                     0)?;
+                                self.db.create_relationship(&o.get_object(), &s_o.get_object())?;
+                                let provider = &site_attr.get_str_value(KEY_PROVIDER, &eut_id_path)?;
+                                let p_id = id_name_map.get(provider).ok_or_else(|| Sense8Error::DanglingReference {
+                                    id_path: s_o.get_id_path().get_str(),
+                                    r#type: KEY_PROVIDER.to_string(),
+                                    name: provider.to_string(),
+                                })?;
+                                let p_o = self.db.get_object(p_id);
+                                self.db.create_relationship(&s_o.get_object(), &p_o)?;
+                                self.db.add_object_property(&s_o.get_object(), &json!({KEY_NAME: site_name}), PropertyType::Base)?;
                             }
                             Ordering::Greater => {
                                 for c in 1..=site_count {
                                     let s_o = Site::init(&self.db,
-                                                         &self.config,
+                                                         config,
                                                          site_attr,
                                                          &mut o.get_id_path().get_vec(),
                                                          &*format!("{}_{}", site_name, c),
-                                                         0);
-                                    self.db.create_relationship(&o.get_object(), &s_o.get_object());
-                                    let provider = &site_attr.as_object().unwrap().get(KEY_PROVIDER).unwrap().as_str().unwrap();
-                                    let p_o = self.db.get_object(id_name_map.get(provider).unwrap());
-                                    self.db.create_relationship(&s_o.get_object(), &p_o);
+                                                         0)?;
+                                    self.db.create_relationship(&o.get_object(), &s_o.get_object())?;
+                                    let provider = &site_attr.get_str_value(KEY_PROVIDER, &eut_id_path)?;
+                                    let p_id = id_name_map.get(provider).ok_or_else(|| Sense8Error::DanglingReference {
+                                        id_path: s_o.get_id_path().get_str(),
+                                        r#type: KEY_PROVIDER.to_string(),
+                                        name: provider.to_string(),
+                                    })?;
+                                    let p_o = self.db.get_object(p_id);
+                                    self.db.create_relationship(&s_o.get_object(), &p_o)?;
                                     self.db.add_object_property(&s_o.get_object(),
                                                                 &json!({KEY_NAME: format!("{}_{}",
                                                                       site_name, c)}),
-                                                                PropertyType::Base);
+                                                                PropertyType::Base)?;
                                 }
                             }
                             _ => {}
@@ -960,432 +1267,632 @@ impl<'a> Regression<'a> {
                     }
                 }
                 k if k == KEY_FEATURES => {
-                    let o = Features::init(self.db, &self.config, &mut eut.get_id_path().get_vec(), "", 2);
-                    self.db.create_relationship(&eut.get_object(), &o.get_object());
+                    let o = Features::init(self.db, config, &mut eut.get_id_path().get_vec(), "", 2)?;
+                    self.db.create_relationship(&eut.get_object(), &o.get_object())?;
 
-                    for f in obj.as_array().unwrap().iter() {
-                        let f_module = f.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-                        //let f_sites = f.as_object().unwrap().get(KEY_SITES).unwrap().as_array().unwrap();
+                    for f in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        let f_module = f.get_str_value(KEY_MODULE, &eut_id_path)?;
+                        //let f_sites = f.get_array_value(KEY_SITES, &eut_id_path)?;
                         //let _sites = self.db.get_object_neighbour_out(&&eut.get_id(), EdgeTypes::HasSites);
                         //let sites = self.db.get_object_neighbours_with_properties_out(&_sites.unwrap().id, EdgeTypes::HasSite);
-                        let f_o = Feature::init(self.db, &self.config, f,
+                        let f_o = Feature::init(self.db, config, f,
                                                 &mut o.get_id_path().get_vec(), &o.get_object(),
-                                                f_module, 0);
+                                                f_module, 0)?;
 
                         //Feature -> Site
                         /*for f_site in f_sites {
                             let re = Regex::new(f_site.as_str().unwrap()).unwrap();
                             for site in sites.iter() {
                                 let site_name = site.props.get(PropertyType::Base.index()).unwrap().value.as_object().
-                                    unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                                    unwrap().get_str_value(KEY_NAME, &eut_id_path)?;
 
                                 if let Some(_t) = re.captures(site_name) {
-                                    self.db.create_relationship(&f_o.get_object(), &site.vertex);
+                                    self.db.create_relationship(&f_o.get_object(), &site.vertex)?;
                                 }
                             }
                         }*/
                     }
                 }
                 k if k == KEY_COLLECTORS => {
-                    let o = Collectors::init(&self.db, &self.config,
-                                             &mut eut.get_id_path().get_vec(), "", 2);
-                    self.db.create_relationship(&eut.get_object(), &o.get_object());
-                    for c in obj.as_array().unwrap().iter() {
-                        let c_o = Collector::init(&self.db, &self.config, c,
-                                                  &mut o.get_id_path().get_vec(), "", 0);
-                        self.db.create_relationship(&o.get_object(), &c_o.get_object());
+                    let o = Collectors::init(&self.db, config,
+                                             &mut eut.get_id_path().get_vec(), "", 2)?;
+                    self.db.create_relationship(&eut.get_object(), &o.get_object())?;
+                    for c in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        let c_o = Collector::init(&self.db, config, c,
+                                                  &mut o.get_id_path().get_vec(), "", 0)?;
+                        self.db.create_relationship(&o.get_object(), &c_o.get_object())?;
                         let props = c_o.get_base_properties();
 
                         object_refs.push(ObjRefs {
-                            refs: props.get(KEY_REFS).unwrap().as_array().unwrap().clone(),
+                            refs: props.get_array_value(KEY_REFS, &eut_id_path)?.clone(),
                             id: c_o.get_id(),
                         })
                     }
                 }
                 k if k == KEY_REPORTS => {
-                    let o = Reports::init(&self.db, &self.config,
-                                          &mut eut.get_id_path().get_vec(), "", 2);
-                    self.db.create_relationship(&eut.get_object(), &o.get_object());
-
-                    for r in obj.as_array().unwrap().iter() {
-                        let label = r.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                        let report = Report::init(&self.db, &self.config, r,
-                                                  &mut o.get_id_path().get_vec(), label, 0);
-                        self.db.create_relationship(&o.get_object(), &report.get_object());
+                    let o = Reports::init(&self.db, config,
+                                          &mut eut.get_id_path().get_vec(), "", 2)?;
+                    self.db.create_relationship(&eut.get_object(), &o.get_object())?;
+
+                    for r in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        let label = r.get_str_value(KEY_NAME, &eut_id_path)?;
+                        let report = Report::init(&self.db, config, r,
+                                                  &mut o.get_id_path().get_vec(), label, 0)?;
+                        self.db.create_relationship(&o.get_object(), &report.get_object())?;
                         let props = report.get_base_properties();
 
                         object_refs.push(ObjRefs {
-                            refs: props.get(KEY_REFS).unwrap().as_array().unwrap().clone(),
+                            refs: props.get_array_value(KEY_REFS, &eut_id_path)?.clone(),
                             id: report.get_id(),
                         })
                     }
                 }
                 k if k == KEY_APPLICATIONS => {
-                    let o = Applications::init(self.db, &self.config,
-                                               &mut eut.get_id_path().get_vec(), "", 2);
-                    self.db.create_relationship(&eut.get_object(), &o.get_object());
+                    let o = Applications::init(self.db, config,
+                                               &mut eut.get_id_path().get_vec(), "", 2)?;
+                    self.db.create_relationship(&eut.get_object(), &o.get_object())?;
 
-                    for a in obj.as_array().unwrap().iter() {
-                        let a_module = a.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-                        let a_o = Application::init(self.db, &self.config, a,
+                    for a in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        let a_module = a.get_str_value(KEY_MODULE, &eut_id_path)?;
+                        let a_o = Application::init(self.db, config, a,
                                                     &mut o.get_id_path().get_vec(), &o.get_object(),
-                                                    a_module, 0);
+                                                    a_module, 0)?;
                         let props = a_o.get_base_properties();
 
                         object_refs.push(ObjRefs {
-                            refs: props.get(KEY_REFS).unwrap().as_array().unwrap().clone(),
+                            refs: props.get_array_value(KEY_REFS, &eut_id_path)?.clone(),
                             id: a_o.get_id(),
                         })
                     }
                 }
                 k if k == KEY_RTES => {
-                    let o_rtes = Rtes::init(&self.db, &self.config,
-                                            &mut eut.get_id_path().get_vec(), "", 1);
-                    self.db.create_relationship(&eut.get_object(), &o_rtes.get_object());
-                    for rte in obj.as_array().unwrap().iter() {
-                        Rte::init(&self.db, &self.config, rte, &mut o_rtes.get_id_path().get_vec(),
-                                  &o_rtes, &mut object_refs,
-                                  &rte.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap(),
-                                  0);
+                    let o_rtes = Rtes::init(&self.db, config,
+                                            &mut eut.get_id_path().get_vec(), "", 1)?;
+                    self.db.create_relationship(&eut.get_object(), &o_rtes.get_object())?;
+                    for rte in eut_module_cfg.get_array_value(k, &eut_id_path)?.iter() {
+                        Rte::init(&self.db, config, rte, &mut o_rtes.get_id_path().get_vec(),
+                                  &o_rtes, object_refs,
+                                  &rte.get_str_value(KEY_MODULE, &eut_id_path)?,
+                                  0)?;
                     }
                 }
                 _ => {}
             }
         }
 
-        (project.get_id(), object_refs)
+        Ok(())
     }
 
     //Create refs from refs config stmts
-    pub fn init_refs(&self, id: Uuid, obj_refs: &Vec<ObjRefs>) {
-        let project = Project::load(&self.db, &id, &self.config);
-        let eut = Eut::load(&self.db, &project, &self.config);
-
-        for obj in obj_refs {
-            for r in &obj.refs {
-                let v_type = VertexTypes::get_type_by_key(r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap());
-                let ref_name = r.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-
-                match v_type {
-                    //Build rel obj --> Application
-                    VertexTypes::Application => {
-                        error!("Building rel between obj and application");
-                        let applications = Applications::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let application = Applications::load_application(&self.db, &applications.get_object(), ref_name, &self.config);
-
-                        match application {
-                            Some(a) => {
-                                self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object());
+    pub fn init_refs(&self, id: Uuid, obj_refs: &[ObjRefs]) -> Result<()> {
+        let project = Project::load(&self.db, &id, &self.config)?;
+
+        for config in self.config.euts().iter() {
+            let eut = Eut::load(&self.db, project.as_ref(), config)?;
+            let eut_id_path = eut.get_id_path().get_str();
+
+            for obj in self.eut_refs(&eut.get_id(), obj_refs) {
+                let obj_id_path = object_id_path(&self.db.get_object_with_properties(&obj.id));
+
+                for r in &obj.refs {
+                    let ref_type = r.get_str_value(KEY_TYPE, &eut_id_path)?;
+                    let v_type = VertexTypes::get_type_by_key(ref_type);
+                    let ref_name = r.get_str_value(KEY_NAME, &eut_id_path)?;
+                    let dangling = || Sense8Error::DanglingReference {
+                        id_path: obj_id_path.clone(),
+                        r#type: ref_type.to_string(),
+                        name: ref_name.to_string(),
+                    };
+
+                    match v_type {
+                        //Build rel obj --> Application
+                        VertexTypes::Application => {
+                            error!("Building rel between obj and application");
+                            let applications = Applications::load_collection(&self.db, &eut.get_object(), config);
+                            let application = Applications::load_application(&self.db, &applications.get_object(), ref_name, config)?;
+
+                            match application {
+                                Some(a) => {
+                                    self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object())?;
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("application object not found")
                         }
-                    }
-                    //Build rel obj --> Collector
-                    VertexTypes::Collector => {
-                        error!("Building rel between obj and collector");
-                        let collectors = Collectors::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let collector = Collectors::load_collector(&self.db, &collectors.get_object(), ref_name, &self.config);
-
-                        match collector {
-                            Some(c) => {
-                                self.db.create_relationship(&self.db.get_object(&obj.id), &c.get_object());
+                        //Build rel obj --> Collector
+                        VertexTypes::Collector => {
+                            error!("Building rel between obj and collector");
+                            let collectors = Collectors::load_collection(&self.db, &eut.get_object(), config);
+                            let collector = Collectors::load_collector(&self.db, &collectors.get_object(), ref_name, config)?;
+
+                            match collector {
+                                Some(c) => {
+                                    self.db.create_relationship(&self.db.get_object(&obj.id), &c.get_object())?;
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("collector object not found")
                         }
-                    }
-                    //Build rel obj --> Eut site
-                    VertexTypes::Site => {
-                        error!("Building rel between obj and eut site");
-                        let sites = Sites::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let site = Sites::load_site(&self.db, &sites.get_object(), ref_name, &self.config);
-
-                        match site {
-                            Some(a) => {
-                                self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object());
+                        //Build rel obj --> Eut site
+                        VertexTypes::Site => {
+                            error!("Building rel between obj and eut site");
+                            let sites = Sites::load_collection(&self.db, &eut.get_object(), config);
+                            let site = Sites::load_site(&self.db, &sites.get_object(), ref_name, config)?;
+
+                            match site {
+                                Some(a) => {
+                                    self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object())?;
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("site object not found")
                         }
-                    }
-                    //Build rel obj --> Feature
-                    VertexTypes::Feature => {
-                        error!("Building rel between obj and feature");
-                        let features = Features::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let feature = Features::load_feature(&self.db, &features.get_object(), ref_name, &self.config);
-
-                        match feature {
-                            Some(a) => {
-                                self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object());
+                        //Build rel obj --> Feature
+                        VertexTypes::Feature => {
+                            error!("Building rel between obj and feature");
+                            let features = Features::load_collection(&self.db, &eut.get_object(), config);
+                            let feature = Features::load_feature(&self.db, &features.get_object(), ref_name, config)?;
+
+                            match feature {
+                                Some(a) => {
+                                    self.db.create_relationship(&self.db.get_object(&obj.id), &a.get_object())?;
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("feature object not found")
                         }
-                    }
-                    //Build rel obj --> Rte
-                    VertexTypes::Rte => {
-                        error!("Building rel between obj and rte");
-                        let rtes = Rtes::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let rte = Rtes::load_rte(&self.db, &rtes.get_object(), ref_name, &self.config);
-
-                        match rte {
-                            Some(r) => {
-                                self.db.create_relationship(&self.db.get_object(&obj.id), &r.get_object());
+                        //Build rel obj --> Rte
+                        VertexTypes::Rte => {
+                            error!("Building rel between obj and rte");
+                            let rtes = Rtes::load_collection(&self.db, &eut.get_object(), config);
+                            let rte = Rtes::load_rte(&self.db, &rtes.get_object(), ref_name, config)?;
+
+                            match rte {
+                                Some(r) => {
+                                    self.db.create_relationship(&self.db.get_object(&obj.id), &r.get_object())?;
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("rte object not found")
                         }
-                    }
-                    //Build rel obj --> Test
-                    VertexTypes::Test => {
-                        match VertexTypes::get_name_by_object(&self.db.get_object(&obj.id)) {
-                            VERTEX_TYPE_COLLECTOR => {
-                                error!("Building rel between collector and test");
-                                let test_name = r.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                                let rte_name = r.as_object().unwrap().get(KEY_RTE).unwrap().as_str().unwrap();
-                                let connection = r.as_object().unwrap().get(KEY_CONNECTION).unwrap().as_str().unwrap();
-                                let rtes = Rtes::load_collection(&self.db, &eut.get_object(), &self.config);
-                                let rte = Rtes::load_rte(&self.db, &rtes.get_object(), rte_name, &self.config);
-                                let connections = Connections::load_collection(&self.db, &rte.unwrap().get_object(), &self.config);
-                                let connection = Connections::load_connection(&self.db, &connections.get_object(), connection, &self.config);
-                                let c_src = ConnectionSource::load(&self.db, &connection.unwrap().get_object(), &self.config);
-                                let test = ConnectionSource::load_test(&self.db, &c_src.get_object(), &test_name, &self.config);
-
-                                match test {
-                                    Some(t) => {
-                                        self.db.create_relationship(&self.db.get_object(&obj.id), &t.get_object());
+                        //Build rel obj --> Test
+                        VertexTypes::Test => {
+                            match VertexTypes::get_name_by_object(&self.db.get_object(&obj.id)) {
+                                VERTEX_TYPE_COLLECTOR | VERTEX_TYPE_TEST => {
+                                    error!("Building rel between obj and test");
+                                    let test_name = r.get_str_value(KEY_NAME, &eut_id_path)?;
+                                    let rte_name = r.get_str_value(KEY_RTE, &eut_id_path)?;
+                                    let connection_name = r.get_str_value(KEY_CONNECTION, &eut_id_path)?;
+                                    let rtes = Rtes::load_collection(&self.db, &eut.get_object(), config);
+                                    let rte = Rtes::load_rte(&self.db, &rtes.get_object(), rte_name, config)?;
+                                    let rte = rte.ok_or_else(|| Sense8Error::DanglingReference {
+                                        id_path: eut_id_path.clone(),
+                                        r#type: KEY_RTE.to_string(),
+                                        name: rte_name.to_string(),
+                                    })?;
+                                    let connections = Connections::load_collection(&self.db, &rte.get_object(), config);
+                                    let connection = Connections::load_connection(&self.db, &connections.get_object(), connection_name, config)?;
+                                    let connection = connection.ok_or_else(|| Sense8Error::DanglingReference {
+                                        id_path: eut_id_path.clone(),
+                                        r#type: KEY_CONNECTION.to_string(),
+                                        name: connection_name.to_string(),
+                                    })?;
+                                    let c_src = ConnectionSource::load(&self.db, &connection.get_object(), config)?;
+                                    let test = ConnectionSource::load_test(&self.db, &c_src.get_object(), &test_name, config)?;
+
+                                    match test {
+                                        Some(t) => {
+                                            self.db.create_relationship(&self.db.get_object(&obj.id), &t.get_object())?;
+                                        }
+                                        None => return Err(dangling())
                                     }
-                                    None => error!("test object not found")
                                 }
-                            }
-                            _ => {
-                                error!("No identifier found for: {:?}", VertexTypes::get_name_by_object(&self.db.get_object(&obj.id)));
+                                _ => {
+                                    error!("No identifier found for: {:?}", VertexTypes::get_name_by_object(&self.db.get_object(&obj.id)));
+                                }
                             }
                         }
+                        _ => {}
                     }
-                    _ => {}
                 }
             }
         }
+
+        Ok(())
     }
 
-    pub fn init_artifacts(&self, id: Uuid, obj_refs: &Vec<ObjRefs>) {
-        let project = Project::load(&self.db, &id, &self.config);
-        let eut = Eut::load(&self.db, &project, &self.config);
-
-        for obj in obj_refs {
-            let mut refs: HashMap<String, Vec<String>> = Default::default();
-
-            for r in &obj.refs {
-                let v_type = VertexTypes::get_type_by_key(r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap());
-                let ref_name = r.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-
-                match v_type {
-                    VertexTypes::Application => {
-                        error!("Init additional properties for application object");
-                        let applications = Applications::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let application = Applications::load_application(&self.db, &applications.get_object(), ref_name, &self.config);
-
-                        match application {
-                            Some(a) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap(),
-                                               &a.get_base_properties().
-                                                   get(KEY_ARTIFACTS_PATH).
-                                                   unwrap().as_str().
-                                                   unwrap().to_string());
+    //Refs of objects reachable from EUT vertex
+    fn eut_refs<'r>(&self, eut: &Uuid, obj_refs: &'r [ObjRefs]) -> Vec<&'r ObjRefs> {
+        let mut seen: BTreeSet<Uuid> = BTreeSet::from([*eut]);
+        let mut next = vec![*eut];
+
+        while let Some(id) = next.pop() {
+            for e in self.db.get_object_edges_out(&id) {
+                if seen.insert(e.inbound_id) {
+                    next.push(e.inbound_id);
+                }
+            }
+        }
+
+        obj_refs.iter().filter(|o| seen.contains(&o.id)).collect()
+    }
+
+    //Remove objects not selected by filters or tags. Returns refs of remaining objects
+    pub fn prune(&self, filters: &[Filter], tags: &TagFilter, obj_refs: Vec<ObjRefs>) -> Result<Vec<ObjRefs>> {
+        let obj_refs = filter::prune(self.db, filters, obj_refs)?;
+        filter::prune_tags(self.db, tags, obj_refs)
+    }
+
+    //Check object graph for dangling refs, orphans, stage cycles and incomplete sites or sources
+    pub fn check_integrity(&self, obj_refs: &[ObjRefs]) -> IntegrityReport {
+        integrity::check(self.db, obj_refs)
+    }
+
+    pub fn init_artifacts(&self, id: Uuid, obj_refs: &[ObjRefs]) -> Result<()> {
+        let project = Project::load(&self.db, &id, &self.config)?;
+
+        for config in self.config.euts().iter() {
+            let eut = Eut::load(&self.db, project.as_ref(), config)?;
+            let eut_id_path = eut.get_id_path().get_str();
+
+            for obj in self.eut_refs(&eut.get_id(), obj_refs) {
+                let obj_id_path = object_id_path(&self.db.get_object_with_properties(&obj.id));
+                let mut refs: BTreeMap<String, Vec<String>> = Default::default();
+
+                for r in &obj.refs {
+                    let ref_type = r.get_str_value(KEY_TYPE, &eut_id_path)?;
+                    let v_type = VertexTypes::get_type_by_key(ref_type);
+                    let ref_name = r.get_str_value(KEY_NAME, &eut_id_path)?;
+                    let dangling = || Sense8Error::DanglingReference {
+                        id_path: obj_id_path.clone(),
+                        r#type: ref_type.to_string(),
+                        name: ref_name.to_string(),
+                    };
+
+                    match v_type {
+                        VertexTypes::Application => {
+                            error!("Init additional properties for application object");
+                            let applications = Applications::load_collection(&self.db, &eut.get_object(), config);
+                            let application = Applications::load_application(&self.db, &applications.get_object(), ref_name, config)?;
+
+                            match application {
+                                Some(a) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &a.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("application object not found")
                         }
-                    }
-                    VertexTypes::Collector => {
-                        error!("Init additional properties for collector object");
-                        let collectors = Collectors::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let collector = Collectors::load_collector(&self.db, &collectors.get_object(), ref_name, &self.config);
-
-                        match collector {
-                            Some(a) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap(),
-                                               &a.get_base_properties().get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string());
+                        VertexTypes::Collector => {
+                            error!("Init additional properties for collector object");
+                            let collectors = Collectors::load_collection(&self.db, &eut.get_object(), config);
+                            let collector = Collectors::load_collector(&self.db, &collectors.get_object(), ref_name, config)?;
+
+                            match collector {
+                                Some(a) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &a.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("collector object not found")
                         }
-                    }
-                    VertexTypes::Feature => {
-                        error!("Init additional properties for feature object");
-                        let features = Features::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let feature = Features::load_feature(&self.db, &features.get_object(), ref_name, &self.config);
-
-                        match feature {
-                            Some(a) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap(),
-                                               &a.get_base_properties().get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string());
+                        VertexTypes::Feature => {
+                            error!("Init additional properties for feature object");
+                            let features = Features::load_collection(&self.db, &eut.get_object(), config);
+                            let feature = Features::load_feature(&self.db, &features.get_object(), ref_name, config)?;
+
+                            match feature {
+                                Some(a) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &a.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("feature object not found")
                         }
-                    }
-                    VertexTypes::Rte => {
-                        error!("Init additional properties for rte object");
-                        let rtes = Rtes::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let rte = Rtes::load_rte(&self.db, &rtes.get_object(), ref_name, &self.config);
-
-                        match rte {
-                            Some(a) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap(),
-                                               &a.get_base_properties().get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string());
+                        VertexTypes::Rte => {
+                            error!("Init additional properties for rte object");
+                            let rtes = Rtes::load_collection(&self.db, &eut.get_object(), config);
+                            let rte = Rtes::load_rte(&self.db, &rtes.get_object(), ref_name, config)?;
+
+                            match rte {
+                                Some(a) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &a.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("rte object not found")
                         }
-                    }
-                    VertexTypes::Site => {
-                        error!("Init additional properties for eut site object");
-                        let sites = Sites::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let site = Sites::load_site(&self.db, &sites.get_object(), ref_name, &self.config);
-
-                        match site {
-                            Some(a) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().unwrap().get(KEY_TYPE).unwrap().as_str().unwrap(),
-                                               &a.get_base_properties().get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string());
+                        VertexTypes::Site => {
+                            error!("Init additional properties for eut site object");
+                            let sites = Sites::load_collection(&self.db, &eut.get_object(), config);
+                            let site = Sites::load_site(&self.db, &sites.get_object(), ref_name, config)?;
+
+                            match site {
+                                Some(a) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &a.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("site object not found")
                         }
-                    }
-                    VertexTypes::Test => {
-                        error!("Init additional properties for test object");
-                        let rte_name = r.as_object().unwrap().get(KEY_RTE).unwrap().as_str().unwrap();
-                        let connection = r.as_object().unwrap().get(KEY_CONNECTION).unwrap().as_str().unwrap();
-                        let rtes = Rtes::load_collection(&self.db, &eut.get_object(), &self.config);
-                        let rte = Rtes::load_rte(&self.db, &rtes.get_object(), rte_name, &self.config);
-                        let connections = Connections::load_collection(&self.db, &rte.unwrap().get_object(), &self.config);
-                        let connection = Connections::load_connection(&self.db, &connections.get_object(), connection, &self.config);
-                        let c_src = ConnectionSource::load(&self.db, &connection.unwrap().get_object(), &self.config);
-                        let test = ConnectionSource::load_test(&self.db, &c_src.get_object(), &ref_name, &self.config);
-
-                        match test {
-                            Some(t) => {
-                                build_refs_map(&mut refs,
-                                               r.as_object().
-                                                   unwrap().
-                                                   get(KEY_TYPE).
-                                                   unwrap().
-                                                   as_str().
-                                                   unwrap(),
-                                               &t.get_base_properties().
-                                                   get(KEY_ARTIFACTS_PATH).
-                                                   unwrap().as_str().
-                                                   unwrap().
-                                                   to_string());
+                        VertexTypes::Test => {
+                            error!("Init additional properties for test object");
+                            let rte_name = r.get_str_value(KEY_RTE, &eut_id_path)?;
+                            let connection_name = r.get_str_value(KEY_CONNECTION, &eut_id_path)?;
+                            let rtes = Rtes::load_collection(&self.db, &eut.get_object(), config);
+                            let rte = Rtes::load_rte(&self.db, &rtes.get_object(), rte_name, config)?;
+                            let rte = rte.ok_or_else(|| Sense8Error::DanglingReference {
+                                id_path: eut_id_path.clone(),
+                                r#type: KEY_RTE.to_string(),
+                                name: rte_name.to_string(),
+                            })?;
+                            let connections = Connections::load_collection(&self.db, &rte.get_object(), config);
+                            let connection = Connections::load_connection(&self.db, &connections.get_object(), connection_name, config)?;
+                            let connection = connection.ok_or_else(|| Sense8Error::DanglingReference {
+                                id_path: eut_id_path.clone(),
+                                r#type: KEY_CONNECTION.to_string(),
+                                name: connection_name.to_string(),
+                            })?;
+                            let c_src = ConnectionSource::load(&self.db, &connection.get_object(), config)?;
+                            let test = ConnectionSource::load_test(&self.db, &c_src.get_object(), &ref_name, config)?;
+
+                            match test {
+                                Some(t) => {
+                                    build_refs_map(&mut refs,
+                                                   ref_type,
+                                                   &t.get_base_properties().get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string());
+                                }
+                                None => return Err(dangling())
                             }
-                            None => error!("test object not found")
                         }
-                    }
-                    _ => {
-                        error!("Failed to init additional properties for unknown <{:?}> object", v_type);
+                        _ => {
+                            error!("Failed to init additional properties for unknown <{:?}> object", v_type);
+                        }
                     }
                 }
-            }
 
-            let o = self.db.get_object(&obj.id);
-            let o_p = self.db.get_object_properties(&o).unwrap();
+                let o = self.db.get_object(&obj.id);
+                let o_p = self.db.get_object_properties(&o).unwrap();
 
-            match VertexTypes::get_name_by_object(&o) {
-                KEY_APPLICATION => {
-                    error!("Adding artifact refs to application object...");
-                    let a = Application::load(&self.db, &o_p, &self.config);
-                    a.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs));
-                }
-                KEY_COLLECTOR => {
-                    error!("Adding artifact refs to collector object...");
-                    let c = Collector::load(&self.db, &o_p, &self.config);
-                    c.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs));
-                }
-                KEY_REPORT => {
-                    error!("Adding artifact refs to report object...");
-                    let r = Report::load(&self.db, &o_p, &self.config);
-                    r.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs));
-                }
-                KEY_TEST => {
-                    error!("Adding artifact refs to test object...");
-                    let t = Test::load(&self.db, &o_p.vertex.id, &self.config);
-                    t.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs));
+                match VertexTypes::get_name_by_object(&o) {
+                    KEY_APPLICATION => {
+                        error!("Adding artifact refs to application object...");
+                        let a = Application::load(&self.db, &o_p, config)?;
+                        a.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs))?;
+                    }
+                    KEY_COLLECTOR => {
+                        error!("Adding artifact refs to collector object...");
+                        let c = Collector::load(&self.db, &o_p, config)?;
+                        c.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs))?;
+                    }
+                    KEY_REPORT => {
+                        error!("Adding artifact refs to report object...");
+                        let r = Report::load(&self.db, &o_p, config)?;
+                        r.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs))?;
+                    }
+                    KEY_TEST => {
+                        error!("Adding artifact refs to test object...");
+                        let t = Test::load(&self.db, &o_p.vertex.id, config)?;
+                        t.insert_base_property(KEY_REF_ARTIFACTS_PATH.to_string(), json!(refs))?;
+                    }
+                    &_ => {}
                 }
-                &_ => {}
             }
         }
+
+        Ok(())
     }
 
-    pub fn init_stages(&self, id: Uuid) {
-        let project = Project::load(&self.db, &id, &self.config);
-        let eut = Eut::load(&self.db, &project, &self.config);
+    pub fn init_stages(&self, id: Uuid) -> Result<()> {
+        let project = Project::load(&self.db, &id, &self.config)?;
         let ci = Ci::load(&self.db, &project.get_object(), &self.config);
         let ci_o_p_base = ci.get_base_properties();
         let _ci_id_path = ci_o_p_base.get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let mut ci_id_path: Vec<String> = _ci_id_path.iter().map(|c| c.as_str().unwrap().to_string()).collect();
 
         //Project Stages Deploy
-        let project_stage_deploy = self.add_ci_stages(&mut ci_id_path, &ci.get_object(), &self.config.project.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let project_stage_deploy = self.add_ci_stages(&mut ci_id_path, &ci.get_object(), &self.config.project.ci.stages.deploy, &VertexTypes::StageDeploy)?;
         //Dashboard Stages Deploy
-        let dashboard_stage_deploy = self.add_ci_stages(&mut ci_id_path, &project_stage_deploy.unwrap(), &self.config.dashboard.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let dashboard_stage_deploy = self.add_ci_stages(&mut ci_id_path, &project_stage_deploy.unwrap(), &self.config.dashboard.ci.stages.deploy, &VertexTypes::StageDeploy)?;
+        let euts = self.config.euts();
+        let mut stage_deploy = dashboard_stage_deploy;
+
+        //Eut scoped Stages Deploy chained per EUT
+        for config in euts.iter() {
+            stage_deploy = self.init_eut_deploy_stages(config, project.as_ref(), &mut ci_id_path, &stage_deploy.unwrap())?;
+        }
+
+        //Eut scoped Stages Destroy chained per EUT
+        let mut stage_destroy: Option<Vertex> = None;
+        for config in euts.iter() {
+            stage_destroy = self.init_eut_destroy_stages(config, project.as_ref(), &ci.get_object(), &mut ci_id_path, stage_destroy)?;
+        }
+
+        //Dashboard Stages Destroy
+        stage_destroy = self.add_ci_stages(&mut ci_id_path, &stage_destroy.unwrap(), &self.config.dashboard.ci.stages.destroy, &VertexTypes::StageDestroy)?;
+
+        //Project Stages Destroy
+        self.add_ci_stages(&mut ci_id_path, &stage_destroy.unwrap(), &self.config.project.ci.stages.destroy, &VertexTypes::StageDestroy)?;
+
+        Ok(())
+    }
+
+    fn init_eut_deploy_stages(&self, config: &RegressionConfig, project: &(dyn ProjectExt<'a> + 'a), ci_id_path: &mut Vec<String>, ancestor: &Vertex) -> Result<Option<Vertex>> {
+        let eut = Eut::load(&self.db, project, config)?;
+
         //Rte Stages Deploy
-        let rte_stage_deploy = self.add_ci_stages(&mut ci_id_path, &dashboard_stage_deploy.unwrap(), &self.config.rte.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let rte_stage_deploy = self.add_ci_stages(ci_id_path, ancestor, &config.rte.ci.stages.deploy, &VertexTypes::StageDeploy)?;
         //Feature Stages Deploy
-        let feature_stage_deploy = self.add_ci_stages(&mut ci_id_path, &rte_stage_deploy.unwrap(), &self.config.features.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let feature_stage_deploy = self.add_ci_stages(ci_id_path, &rte_stage_deploy.unwrap(), &config.features.ci.stages.deploy, &VertexTypes::StageDeploy)?;
         //Eut Stages Deploy
-        let eut_stage_deploy = self.add_ci_stages(&mut ci_id_path, &feature_stage_deploy.unwrap(), &self.config.eut.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let eut_stage_deploy = self.add_ci_stages(ci_id_path, &feature_stage_deploy.unwrap(), &config.eut.ci.stages.deploy, &VertexTypes::StageDeploy)?;
         //Application Stages Deploy
-        let application_stage_deploy = self.add_ci_stages(&mut ci_id_path, &eut_stage_deploy.unwrap(), &self.config.applications.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let application_stage_deploy = self.add_ci_stages(ci_id_path, &eut_stage_deploy.unwrap(), &config.applications.ci.stages.deploy, &VertexTypes::StageDeploy)?;
         //Test Stages Deploy
-        let test_stage_deploy = self.add_ci_stages(&mut ci_id_path, &application_stage_deploy.unwrap(), &self.config.tests.ci.stages.deploy, &VertexTypes::StageDeploy);
+        let test_stage_deploy = self.add_ci_stages(ci_id_path, &application_stage_deploy.unwrap(), &config.tests.ci.stages.deploy, &VertexTypes::StageDeploy)?;
 
-        //Test and Verification sequential job stages
+        //Tests in declaration order
         let _rtes = self.db.get_object_neighbour_out(&&eut.get_id(), EdgeTypes::UsesRtes);
         let rtes = self.db.get_object_neighbours_with_properties_out(&_rtes.unwrap().id, EdgeTypes::ProvidesRte);
-        let mut _test_stages_seq: Vec<String> = Vec::new();
-        let mut _verification_stages_seq: Vec<String> = Vec::new();
+        let mut tests: Vec<TestStage> = Vec::new();
 
         for rte in rtes.iter() {
-            let _c = self.db.get_object_neighbour_out(&rte.vertex.id, EdgeTypes::HasConnections);
-            let _conns = self.db.get_object_neighbours_out(&_c.unwrap().id, EdgeTypes::HasConnection);
+            let rte_execution = execution_config(rte).unwrap_or_default();
+            let rte_id_path = object_id_path(rte);
+            let rte_name = rte.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &rte_id_path)?;
+            let _c = self.db.get_object_neighbour_out(&rte.vertex.id, EdgeTypes::HasConnections)
+                .ok_or_else(|| Sense8Error::Db(format!("rte <{rte_id_path}> has no connections object")))?;
+            let _conns = self.db.get_object_neighbours_out(&_c.id, EdgeTypes::HasConnection);
             for conn in _conns.iter() {
-                let c_src = self.db.get_object_neighbour_with_properties_out(&conn.id, EdgeTypes::HasConnectionSrc).unwrap();
-                let tests = self.db.get_object_neighbours_with_properties_out(&c_src.vertex.id, EdgeTypes::Runs);
-                for t in tests.iter() {
-                    let t_stage_name = format!("{}-{}-{}-{}-{}-{}",
-                                               KEY_TEST,
-                                               rte.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                               c_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                               &t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap(),
-                                               &t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                               KEY_DEPLOY
-                    ).replace('_', "-");
-                    _test_stages_seq.push(t_stage_name);
-
-                    //Verification stages
-                    let verifications = self.db.get_object_neighbours_with_properties_out(&t.vertex.id, EdgeTypes::Needs);
-
-                    for v in verifications.iter() {
-                        let v_stage_name = format!("{}-{}-{}-{}-{}-{}-{}",
-                                                   KEY_VERIFICATION,
-                                                   rte.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                   c_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                   &t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                   &v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap(),
-                                                   &v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                   KEY_DEPLOY
-                        ).replace('_', "-");
-                        _verification_stages_seq.push(v_stage_name);
+                //Connection execution overrides rte execution
+                let (scope, execution) = match execution_config(&self.db.get_object_with_properties(&conn.id)) {
+                    Some(e) => (conn.id, e),
+                    None => (rte.vertex.id, rte_execution.clone()),
+                };
+                let c_src = self.db.get_object_neighbour_with_properties_out(&conn.id, EdgeTypes::HasConnectionSrc)
+                    .ok_or_else(|| Sense8Error::Db(format!("connection of rte <{rte_id_path}> has no source object")))?;
+                let c_src_name = c_src.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &rte_id_path)?.to_string();
+
+                //Sites tests of this connection run against
+                let mut sites: BTreeSet<Uuid> = BTreeSet::new();
+                let mut endpoints = vec![c_src.vertex.id];
+                endpoints.extend(self.db.get_object_neighbours_out(&c_src.vertex.id, EdgeTypes::HasConnectionDst).iter().map(|d| d.id));
+                for e in endpoints.iter() {
+                    sites.extend(self.db.get_object_neighbours_out(e, EdgeTypes::RefersSite).iter().map(|s| s.id));
+                }
+
+                for t in self.db.get_object_neighbours_with_properties_out(&c_src.vertex.id, EdgeTypes::Runs) {
+                    tests.push(TestStage {
+                        rte: rte_name.to_string(),
+                        src: c_src_name.clone(),
+                        depends_on: self.db.get_object_neighbours_out(&t.vertex.id, EdgeTypes::DependsOn).iter().map(|d| d.id).collect(),
+                        test: t,
+                        scope,
+                        sites: sites.clone(),
+                        execution: execution.clone(),
+                    });
+                }
+            }
+        }
+
+        //Test and Verification job stages
+        let tests = sort_by_dependencies(tests)?;
+        let mut _test_stages_seq: Vec<String> = Vec::new();
+        let mut _verification_stages_seq: Vec<String> = Vec::new();
+        let mut parallel_tests: Vec<&TestStage> = Vec::new();
+        let mut parallel_verifications = false;
+        let mut prev_seq: Option<Uuid> = None;
+
+        for t in tests.iter() {
+            let t_id_path = object_id_path(&t.test);
+            let t_base_p = &t.test.props.get(PropertyType::Base.index()).unwrap().value;
+            let t_module = t_base_p.get_str_value(KEY_MODULE, &t_id_path)?;
+            let t_name = t_base_p.get_str_value(KEY_NAME, &t_id_path)?;
+            //Declared dependencies. Tests waited for only to keep order go to after
+            let needs: Vec<String> = t.depends_on.iter().map(|d| d.to_string()).collect();
+            let mut after: Vec<String> = Vec::new();
+            let t_stage_name = match t.execution.strategy {
+                ExecutionStrategy::Sequential => {
+                    let name = config.namespaced(&format!("{}-{}-{}-{}-{}-{}",
+                                       KEY_TEST,
+                                       t.rte,
+                                       t.src,
+                                       t_module,
+                                       t_name,
+                                       KEY_DEPLOY
+                    ).replace('_', "-"));
+                    _test_stages_seq.push(name.clone());
+
+                    //Needs let a job skip stage order. Keep waiting for previous test of the chain
+                    if !needs.is_empty() {
+                        after.extend(prev_seq.map(|p| p.to_string()));
+                    }
+                    prev_seq = Some(t.test.vertex.id);
+                    name
+                }
+                ExecutionStrategy::Parallel => {
+                    if let Some(d) = tests.iter().find(|d| t.depends_on.contains(&d.test.vertex.id) && d.execution.strategy == ExecutionStrategy::Sequential) {
+                        return Err(Sense8Error::ConfigInvalid {
+                            path: object_id_path(&t.test),
+                            msg: format!("parallel test depends on sequential test <{}> running after it", object_id_path(&d.test)),
+                        });
                     }
+                    let same_scope: Vec<&&TestStage> = parallel_tests.iter().filter(|p| p.scope == t.scope).collect();
+
+                    if let Some(max) = t.execution.max_parallel {
+                        if same_scope.len() >= max.max(1) {
+                            after.push(same_scope[same_scope.len() - max.max(1)].test.vertex.id.to_string());
+                        }
+                    }
+
+                    if t.execution.serialize_sites {
+                        if let Some(p) = parallel_tests.iter().rev().find(|p| !p.sites.is_disjoint(&t.sites)) {
+                            after.push(p.test.vertex.id.to_string());
+                        }
+                    }
+
+                    parallel_tests.push(t);
+                    config.namespaced(STAGE_TEST_PARALLEL)
                 }
+            };
+            after.sort();
+            after.dedup();
+            after.retain(|a| !needs.contains(a));
+            self.db.add_object_property(&t.test.vertex, &json!({KEY_STAGE: t_stage_name, KEY_NEEDS: needs, KEY_AFTER: after}), PropertyType::Base)?;
+
+            //Verification stages
+            let verifications = self.db.get_object_neighbours_with_properties_out(&t.test.vertex.id, EdgeTypes::Needs);
+
+            for v in verifications.iter() {
+                let v_id_path = object_id_path(v);
+                let v_base_p = &v.props.get(PropertyType::Base.index()).unwrap().value;
+                let v_stage_name = match t.execution.strategy {
+                    ExecutionStrategy::Sequential => {
+                        let name = config.namespaced(&format!("{}-{}-{}-{}-{}-{}-{}",
+                                           KEY_VERIFICATION,
+                                           t.rte,
+                                           t.src,
+                                           t_name,
+                                           v_base_p.get_str_value(KEY_MODULE, &v_id_path)?,
+                                           v_base_p.get_str_value(KEY_NAME, &v_id_path)?,
+                                           KEY_DEPLOY
+                        ).replace('_', "-"));
+                        _verification_stages_seq.push(name.clone());
+                        name
+                    }
+                    ExecutionStrategy::Parallel => {
+                        parallel_verifications = true;
+                        config.namespaced(STAGE_VERIFICATION_PARALLEL)
+                    }
+                };
+                self.db.add_object_property(&v.vertex, &json!({KEY_STAGE: v_stage_name}), PropertyType::Base)?;
             }
         }
-        let test_stage_deploy_seq = self.add_ci_stages(&mut ci_id_path, &test_stage_deploy.unwrap(), &_test_stages_seq, &VertexTypes::StageDeploy);
+
+        //Parallel tests share one stage placed before sequential test stages. Concurrency limits,
+        //site serialization and dependencies are expressed as tests to wait for
+        if !parallel_tests.is_empty() {
+            _test_stages_seq.insert(0, config.namespaced(STAGE_TEST_PARALLEL));
+        }
+        if parallel_verifications {
+            _verification_stages_seq.insert(0, config.namespaced(STAGE_VERIFICATION_PARALLEL));
+        }
+
+        let test_stage_deploy_seq = self.add_ci_stages(ci_id_path, &test_stage_deploy.unwrap(), &_test_stages_seq, &VertexTypes::StageDeploy)?;
 
         //Test Collector Stages Deploy
         let mut _test_collector_stages: Vec<String> = Vec::new();
-        let collectors = Collectors::load(&self.db, &eut.get_object(), &self.config);
+        let collectors = Collectors::load(&self.db, &eut.get_object(), config)?;
         for c in collectors {
-            for item in c.get_module_properties().get(KEY_STAGES).unwrap().as_object().unwrap().get(KEY_DEPLOY).unwrap().as_array().unwrap() {
-                _test_collector_stages.push(item.as_str().unwrap().to_string());
+            let c_id_path = c.get_id_path().get_str();
+            let c_stages = c.get_module_properties().get_object_value(KEY_STAGES, &c_id_path)?.clone();
+            for item in c_stages.get_array_value(KEY_DEPLOY, &c_id_path)? {
+                let stage = item.as_str().ok_or_else(|| Sense8Error::InvalidType {
+                    key: KEY_DEPLOY.to_string(),
+                    expected: "an array of strings",
+                    id_path: c_id_path.clone(),
+                })?;
+                _test_collector_stages.push(config.namespaced(stage));
             }
             /*let edges = self.db.get_object_edges_in(&c.get_id());
 
@@ -1402,36 +1909,39 @@ impl<'a> Regression<'a> {
         }
 
         if _test_collector_stages.len() > 0 {
-            let test_collector_stage_deploy = self.add_ci_stages(&mut ci_id_path, &test_stage_deploy_seq.unwrap(), _test_collector_stages.as_slice(), &VertexTypes::StageDeploy);
+            let test_collector_stage_deploy = self.add_ci_stages(ci_id_path, &test_stage_deploy_seq.unwrap(), _test_collector_stages.as_slice(), &VertexTypes::StageDeploy)?;
 
             //Verification Stages Deploy
-            let verification_stage_deploy = self.add_ci_stages(&mut ci_id_path, &test_collector_stage_deploy.unwrap(), &self.config.verifications.ci.stages.deploy, &VertexTypes::StageDeploy);
-            let verification_stages_seq = self.add_ci_stages(&mut ci_id_path, &verification_stage_deploy.unwrap(), &_verification_stages_seq, &VertexTypes::StageDeploy);
+            let verification_stage_deploy = self.add_ci_stages(ci_id_path, &test_collector_stage_deploy.unwrap(), &config.verifications.ci.stages.deploy, &VertexTypes::StageDeploy)?;
+            let verification_stages_seq = self.add_ci_stages(ci_id_path, &verification_stage_deploy.unwrap(), &_verification_stages_seq, &VertexTypes::StageDeploy)?;
             //Reports Stages Deploy
-            self.add_ci_stages(&mut ci_id_path, &verification_stages_seq.unwrap(), &self.config.reports.ci.stages.deploy, &VertexTypes::StageDeploy);
+            self.add_ci_stages(ci_id_path, &verification_stages_seq.unwrap(), &config.reports.ci.stages.deploy, &VertexTypes::StageDeploy)
         } else {
             //Verification Stages Deploy
-            let verification_stage_deploy = self.add_ci_stages(&mut ci_id_path, &test_stage_deploy_seq.unwrap(), &self.config.verifications.ci.stages.deploy, &VertexTypes::StageDeploy);
-            let verification_stages_seq = self.add_ci_stages(&mut ci_id_path, &verification_stage_deploy.unwrap(), &_verification_stages_seq, &VertexTypes::StageDeploy);
+            let verification_stage_deploy = self.add_ci_stages(ci_id_path, &test_stage_deploy_seq.unwrap(), &config.verifications.ci.stages.deploy, &VertexTypes::StageDeploy)?;
+            let verification_stages_seq = self.add_ci_stages(ci_id_path, &verification_stage_deploy.unwrap(), &_verification_stages_seq, &VertexTypes::StageDeploy)?;
             //Reports Stages Deploy
-            self.add_ci_stages(&mut ci_id_path, &verification_stages_seq.unwrap(), &self.config.reports.ci.stages.deploy, &VertexTypes::StageDeploy);
+            self.add_ci_stages(ci_id_path, &verification_stages_seq.unwrap(), &config.reports.ci.stages.deploy, &VertexTypes::StageDeploy)
         }
+    }
+
+    fn init_eut_destroy_stages(&self, config: &RegressionConfig, project: &(dyn ProjectExt<'a> + 'a), ci: &Vertex, ci_id_path: &mut Vec<String>, mut stage_destroy: Option<Vertex>) -> Result<Option<Vertex>> {
+        let eut = Eut::load(&self.db, project, config)?;
 
         //Feature Stages Destroy
-        let mut stage_destroy: Option<Vertex> = None;
         let _features = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasFeatures);
         let features = self.db.get_object_neighbours_out(&_features.unwrap().id, EdgeTypes::HasFeature);
 
         if !features.is_empty() {
-            stage_destroy = self.add_ci_stages(&mut ci_id_path, &ci.get_object(), &self.config.features.ci.stages.destroy, &VertexTypes::StageDestroy);
+            stage_destroy = self.add_ci_stages(ci_id_path, &stage_destroy.unwrap_or(ci.clone()), &config.features.ci.stages.destroy, &VertexTypes::StageDestroy)?;
         }
 
         ci_id_path.truncate(2);
 
         //Eut Stages Destroy
         match stage_destroy {
-            Some(f) => stage_destroy = self.add_ci_stages(&mut ci_id_path, &f, &self.config.eut.ci.stages.destroy, &VertexTypes::StageDestroy),
-            None => stage_destroy = self.add_ci_stages(&mut ci_id_path, &ci.get_object(), &self.config.eut.ci.stages.destroy, &VertexTypes::StageDestroy)
+            Some(f) => stage_destroy = self.add_ci_stages(ci_id_path, &f, &config.eut.ci.stages.destroy, &VertexTypes::StageDestroy)?,
+            None => stage_destroy = self.add_ci_stages(ci_id_path, ci, &config.eut.ci.stages.destroy, &VertexTypes::StageDestroy)?
         }
 
         //Application Stages Destroy
@@ -1439,37 +1949,38 @@ impl<'a> Regression<'a> {
         let applications = self.db.get_object_neighbours_out(&_applications.unwrap().id, EdgeTypes::ProvidesApplication);
 
         if !applications.is_empty() {
-            stage_destroy = self.add_ci_stages(&mut ci_id_path, &stage_destroy.unwrap(), &self.config.applications.ci.stages.destroy, &VertexTypes::StageDestroy);
+            stage_destroy = self.add_ci_stages(ci_id_path, &stage_destroy.unwrap(), &config.applications.ci.stages.destroy, &VertexTypes::StageDestroy)?;
         }
 
         //Rte Stages Destroy
         match stage_destroy {
-            Some(a) => stage_destroy = self.add_ci_stages(&mut ci_id_path, &a, &self.config.rte.ci.stages.destroy, &VertexTypes::StageDestroy),
-            None => stage_destroy = self.add_ci_stages(&mut ci_id_path, &eut.get_object(), &self.config.rte.ci.stages.destroy, &VertexTypes::StageDestroy)
+            Some(a) => stage_destroy = self.add_ci_stages(ci_id_path, &a, &config.rte.ci.stages.destroy, &VertexTypes::StageDestroy)?,
+            None => stage_destroy = self.add_ci_stages(ci_id_path, &eut.get_object(), &config.rte.ci.stages.destroy, &VertexTypes::StageDestroy)?
         }
 
-        //Dashboard Stages Destroy
-        stage_destroy = self.add_ci_stages(&mut ci_id_path, &stage_destroy.unwrap(), &self.config.dashboard.ci.stages.destroy, &VertexTypes::StageDestroy);
-
-        //Project Stages Destroy
-        self.add_ci_stages(&mut ci_id_path, &stage_destroy.unwrap(), &self.config.project.ci.stages.destroy, &VertexTypes::StageDestroy);
+        Ok(stage_destroy)
     }
 
-    fn load_regression_config(path: &str, file: &str, eut_config: Option<String>) -> RegressionConfig {
+    fn load_regression_config(path: &str, file: &str, eut_config: Option<String>, overlay: &Overlay) -> Result<RegressionConfig> {
         info!("Loading regression configuration data...");
         error!("EUT_CONFIG: {:?}", eut_config);
         let data: String = format!("{path}/{CONFIG_FILE_PATH}/{file}");
         error!("Sense8 config file: {}", &data);
-        let raw = std::fs::read_to_string(data).unwrap();
-        let _tmp: Value = serde_json::from_str(&raw).unwrap();
-        let mut _cfg = _tmp.as_object().unwrap().clone();
+        let raw = read_file(&data)?;
+        let mut _tmp: Value = format::parse(&data, &raw)?;
+        overlay.apply(&mut _tmp)?;
+        let mut _cfg = _tmp.as_object().ok_or_else(|| Sense8Error::ConfigInvalid {
+            path: data.clone(),
+            msg: format!("expected a {} object", ConfigFormat::from_path(&data).name()),
+        })?.clone();
         _cfg.insert("root_path".to_string(), Value::from(path.to_string()));
-        let mut cfg = serde_json::from_value::<RegressionConfig>(to_value(&_cfg).unwrap()).unwrap();
+        let mut cfg = serde_json::from_value::<RegressionConfig>(Value::Object(_cfg)).map_err(|e| Sense8Error::json(&data, e))?;
         let _ = cfg.eut.config.insert(eut_config.clone().unwrap_or_default());
 
         info!("Loading regression configuration data -> Done.");
         info!("Render regression configuration file...");
-        let mut _tera = Tera::new(&*format!("{path}/{CONFIG_FILE_PATH}/*")).unwrap();
+        let mut _tera = Tera::new(&*format!("{path}/{CONFIG_FILE_PATH}/*")).map_err(|e| Sense8Error::template(&data, e))?;
+        _tera.register_function(TERA_FN_ENV, crate::overlay::env);
         let mut context = Context::new();
         context.insert(KEY_EUT, &cfg.eut);
         context.insert(KEY_RTE, &cfg.rte);
@@ -1481,97 +1992,152 @@ impl<'a> Regression<'a> {
         context.insert(KEY_COLLECTORS, &cfg.collectors);
         context.insert(KEY_APPLICATIONS, &cfg.applications);
         context.insert(KEY_VERIFICATIONS, &cfg.verifications);
-        let eutc = _tera.render(file, &context).unwrap();
+        context.insert(KEY_VARS, overlay.vars());
+        let eutc = _tera.render(file, &context).map_err(|e| Sense8Error::template(&data, e))?;
         info!("Render regression configuration file -> Done.");
 
         info!("Loading regression configuration data...");
-        let _tmp: Value = serde_json::from_str(&eutc).unwrap();
-        let mut _cfg = _tmp.as_object().unwrap().clone();
+        let mut _tmp: Value = format::parse(&data, &eutc)?;
+        overlay.apply(&mut _tmp)?;
+        let mut _cfg = _tmp.as_object().ok_or_else(|| Sense8Error::ConfigInvalid {
+            path: data.clone(),
+            msg: format!("expected a {} object", ConfigFormat::from_path(&data).name()),
+        })?.clone();
         _cfg.insert("root_path".to_string(), Value::from(path.to_string()));
-        let mut cfg = serde_json::from_value::<RegressionConfig>(to_value(&_cfg).unwrap()).unwrap();
+        let mut cfg = serde_json::from_value::<RegressionConfig>(Value::Object(_cfg)).map_err(|e| Sense8Error::json(&data, e))?;
         let _ = cfg.eut.config.insert(eut_config.unwrap_or_default());
+        cfg.overlay = overlay.clone();
         info!("Loading regression configuration data -> Done.");
 
-        cfg
+        Ok(cfg)
     }
 
-    fn add_ci_stages(&self, id_path: &mut Vec<String>, ancestor: &Vertex, stages: &[String], object_type: &VertexTypes) -> Option<Vertex> {
-        let mut curr = Vertex { id: Default::default(), t: Default::default() };
+    //Chain stages after ancestor. Returns last stage or ancestor if stages is empty
+    fn add_ci_stages(&self, id_path: &mut Vec<String>, ancestor: &Vertex, stages: &[String], object_type: &VertexTypes) -> Result<Option<Vertex>> {
+        let mut curr = ancestor.clone();
 
         for (i, stage) in stages.iter().enumerate() {
-            let (new, _id_path) = self.db.create_object_and_init(object_type.clone(), id_path, stage, 0);
-            self.db.add_object_property(&new, &json!({KEY_NAME: stage}), PropertyType::Base);
+            let (new, _id_path) = self.db.create_object_and_init(object_type.clone(), id_path, stage, 0)?;
+            self.db.add_object_property(&new, &json!({KEY_NAME: stage}), PropertyType::Base)?;
 
             if i == 0 {
-                self.db.create_relationship(ancestor, &new);
+                self.db.create_relationship(ancestor, &new)?;
                 curr = new.clone();
             } else {
-                self.db.create_relationship(&curr, &new);
+                self.db.create_relationship(&curr, &new)?;
                 curr = new.clone();
             }
         }
-        Some(curr)
+        Ok(Some(curr))
     }
 
-    pub fn build_context(&self, id: Uuid) -> Context {
+    /// Render context of project `id`. Taken from the graph if regression was opened with
+    /// [`Regression::open`].
+    pub fn build_context(&self, id: Uuid) -> Result<Context> {
+        if let Some(context) = &self.context {
+            return Ok(context.clone());
+        }
         info!("Build render context...");
-        let mut actions: ActionsRenderContext = ActionsRenderContext {
-            rtes: vec![],
-            sites: vec![],
-            tests: vec![],
-            reports: vec![],
-            features: vec![],
-            applications: vec![],
-            verifications: vec![],
-        };
 
         //Project
-        let project = Project::load(&self.db, &id, &self.config);
-        let project_p_base = project.get_base_properties();
-        let project_module = project_p_base.get(KEY_MODULE).unwrap().as_str().unwrap();
-        let scripts = project.gen_script_render_ctx(&self.config);
-        let project_rc = project.gen_render_ctx(&self.config, scripts.clone());
+        let project = Project::load(&self.db, &id, &self.config)?;
+        let scripts = project.gen_script_render_ctx(&self.config)?;
+        let project_rc = project.gen_render_ctx(&self.config, scripts.clone())?;
 
         //Dashboard
-        let dashboard = Dashboard::load(self.db, &project.get_object(), &self.config);
-        let scripts = dashboard.gen_script_render_ctx(&self.config);
-        let dashboard_rc = dashboard.gen_render_ctx(&self.config, scripts.clone());
+        let dashboard = Dashboard::load(self.db, &project.get_object(), &self.config)?;
+        let scripts = dashboard.gen_script_render_ctx(&self.config)?;
+        let dashboard_rc = dashboard.gen_render_ctx(&self.config, scripts.clone())?;
+
+        //Euts
+        let mut euts: Vec<EutGroupRenderContext> = Vec::new();
+        for config in self.config.euts().iter() {
+            euts.push(self.build_eut_context(config, project.as_ref())?);
+        }
+        let mut actions = ActionsRenderContext::default();
+        for eut in euts.iter() {
+            actions.extend(&eut.actions);
+        }
+
+        let mut stages: Vec<String> = Vec::new();
+        let mut deploy_stages: Vec<String> = Vec::new();
+        let mut destroy_stages: Vec<String> = Vec::new();
+
+        let project_id_path = project.get_id_path().get_str();
+        let project_ci = self.db.get_object_neighbour_out(&project.get_id(), EdgeTypes::HasCi)
+            .ok_or_else(|| Sense8Error::Db(format!("project <{project_id_path}> has no ci object")))?;
+        let s_deploy = self.db.get_object_neighbour_with_properties_out(&project_ci.id, EdgeTypes::HasDeployStages)
+            .ok_or_else(|| Sense8Error::Db(format!("project <{project_id_path}> has no deploy stages")))?;
+        let s_destroy = self.db.get_object_neighbour_with_properties_out(&project_ci.id, EdgeTypes::HasDestroyStages)
+            .ok_or_else(|| Sense8Error::Db(format!("project <{project_id_path}> has no destroy stages")))?;
+        deploy_stages.push(s_deploy.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &project_id_path)?.to_string());
+        self.get_next_stage(&s_deploy.vertex.id, &mut deploy_stages)?;
+        deploy_stages.push(s_destroy.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &project_id_path)?.to_string());
+        self.get_next_stage(&s_destroy.vertex.id, &mut destroy_stages)?;
 
-        // Eut
-        let eut = Eut::load(&self.db, &project, &self.config);
+        stages.append(&mut deploy_stages);
+        stages.append(&mut destroy_stages);
+
+        //Objects of all EUTs are kept flat next to the grouped ones
+        let mut context = Context::new();
+        context.insert(KEY_EUT, &euts.first().map(|e| &e.eut));
+        context.insert(KEY_EUTS, &euts);
+        context.insert(KEY_RTES, &euts.iter().flat_map(|e| e.rtes.iter()).collect::<Vec<_>>());
+        context.insert(KEY_CONFIG, &self.config);
+        context.insert(KEY_STAGES, &stages);
+        context.insert(KEY_REPORTS, &euts.iter().flat_map(|e| e.reports.iter()).collect::<Vec<_>>());
+        context.insert(KEY_ACTIONS, &actions);
+        context.insert(KEY_PROJECT, &project_rc);
+        context.insert(KEY_FEATURES, &euts.iter().flat_map(|e| e.features.iter()).collect::<Vec<_>>());
+        context.insert(KEY_DASHBOARD, &dashboard_rc);
+        context.insert(KEY_COLLECTORS, &euts.iter().flat_map(|e| e.collectors.iter()).collect::<Vec<_>>());
+        context.insert(KEY_APPLICATIONS, &euts.iter().flat_map(|e| e.applications.iter()).collect::<Vec<_>>());
+
+        //error!("{:#?}", context.get(KEY_APPLICATIONS));
+        info!("Build render context -> Done.");
+        Ok(context)
+    }
+
+    /// Render context of EUT held by `project` and scoped by `config`.
+    fn build_eut_context(&self, config: &RegressionConfig, project: &(dyn ProjectExt<'a> + 'a)) -> Result<EutGroupRenderContext> {
+        let mut actions = ActionsRenderContext::default();
+        let eut = Eut::load(&self.db, project, config)?;
         let eut_p_base = eut.get_base_properties();
         let eut_p_module = eut.get_module_properties();
-        let eut_name = eut_p_module.get(KEY_NAME).unwrap().as_str().unwrap().to_string();
+        let eut_id_path = eut.get_id_path().get_str();
+        let eut_name = eut_p_module.get_str_value(KEY_NAME, &eut_id_path)?.to_string();
 
         //Process eut provider
-        let _eut_providers = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasProviders);
-        let eut_provider = self.db.get_object_neighbours_with_properties_out(&_eut_providers.unwrap().id, EdgeTypes::ProvidesProvider);
+        let _eut_providers = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasProviders)
+            .ok_or_else(|| Sense8Error::Db(format!("eut <{eut_id_path}> has no providers object")))?;
+        let eut_provider = self.db.get_object_neighbours_with_properties_out(&_eut_providers.id, EdgeTypes::ProvidesProvider);
 
         let mut eut_provider_p_base = Vec::new();
         for p in eut_provider.iter() {
-            let name = p.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+            let name = p.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &object_id_path(p))?;
             eut_provider_p_base.push(String::from(name));
         }
 
         //Process features
-        let features_rc: Vec<Box<dyn RenderContext>> = Features::gen_render_ctx(self.db, &eut.get_object(), &self.config);
+        let features_rc: Vec<Box<dyn RenderContext>> = Features::gen_render_ctx(self.db, &eut.get_object(), config)?;
 
         //Process applications
-        let applications_rc: Vec<Box<dyn RenderContext>> = Applications::gen_render_ctx(self.db, &eut.get_object(), &self.config);
+        let applications_rc: Vec<Box<dyn RenderContext>> = Applications::gen_render_ctx(self.db, &eut.get_object(), config)?;
 
         //Process collectors
-        let collectors_rc: Vec<Box<dyn RenderContext>> = Collectors::gen_render_ctx(self.db, &eut.get_object(), &self.config);
+        let collectors_rc: Vec<Box<dyn RenderContext>> = Collectors::gen_render_ctx(self.db, &eut.get_object(), config)?;
 
         //Process reports
-        let reports_rc: Vec<Box<dyn RenderContext>> = Reports::gen_render_ctx(self.db, &eut.get_object(), &self.config);
+        let reports_rc: Vec<Box<dyn RenderContext>> = Reports::gen_render_ctx(self.db, &eut.get_object(), config)?;
 
         //Get EUT sites
-        let _sites = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasSites);
-        let sites = self.db.get_object_neighbours_with_properties_out(&_sites.unwrap().id, EdgeTypes::HasSite);
+        let _sites = self.db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasSites)
+            .ok_or_else(|| Sense8Error::Db(format!("eut <{eut_id_path}> has no sites object")))?;
+        let sites = self.db.get_object_neighbours_with_properties_out(&_sites.id, EdgeTypes::HasSite);
 
         //Get EUT rtes
-        let _rtes = Rtes::load_collection(&self.db, &eut.get_object(), &self.config);
-        let rtes = Rtes::load(&self.db, &_rtes.get_object(), &self.config);
+        let _rtes = Rtes::load_collection(&self.db, &eut.get_object(), config);
+        let rtes = Rtes::load(&self.db, &_rtes.get_object(), config)?;
 
         //Process eut rtes
         let mut rtes_rc: Vec<RteRenderContext> = Vec::new();
@@ -1579,11 +2145,11 @@ impl<'a> Regression<'a> {
 
         for rte in rtes {
             let rte_base_p = rte.get_base_properties();
-            let rte_name = rte_base_p.get(KEY_NAME).unwrap().as_str().unwrap();
+            let rte_name = rte_base_p.get_str_value(KEY_NAME, &rte.get_id_path().get_str())?;
             rte_names.push(rte_name.to_string());
 
-            let scripts = rte.gen_script_render_ctx(&self.config);
-            let _rte_crcs = rte.gen_render_ctx(&self.config, scripts.clone());
+            let scripts = rte.gen_script_render_ctx(config)?;
+            let _rte_crcs = rte.gen_render_ctx(config, scripts.clone())?;
 
             let rte_crcs: &RteRenderContext = match _rte_crcs.as_any().downcast_ref::<RteRenderContext>() {
                 Some(r) => r,
@@ -1596,6 +2162,9 @@ impl<'a> Regression<'a> {
 
             for test in rte_crcs.tests.iter() {
                 actions.tests.push(test.job.clone());
+                for tag in test.tags.iter() {
+                    actions.tags.entry(tag.to_string()).or_default().push(test.job.clone());
+                }
                 for verification in test.verifications.iter() {
                     actions.verifications.push(verification.job.clone());
                 }
@@ -1604,54 +2173,69 @@ impl<'a> Regression<'a> {
             rtes_rc.push(rte_crcs.clone());
         }
 
+        //Resolve tests to wait for to their job names
+        let test_jobs: HashMap<String, String> = rtes_rc.iter()
+            .flat_map(|r| r.tests.iter())
+            .map(|t| (t.id.to_string(), t.job.clone()))
+            .collect();
+        for test in rtes_rc.iter_mut().flat_map(|r| r.tests.iter_mut()) {
+            test.needs = test.needs.iter().filter_map(|id| test_jobs.get(id).cloned()).collect();
+            test.after = test.after.iter().filter_map(|id| test_jobs.get(id).cloned()).collect();
+        }
+
         //Process eut sites
         let mut eut_sites: Vec<EutSiteRenderContext> = vec![];
         for (i, s) in sites.iter().enumerate() {
-            let site_name = s.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-            let s_p = self.db.get_object_neighbour_with_properties_out(&s.vertex.id, EdgeTypes::UsesProvider).unwrap();
-            let provider_name = s_p.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+            let site_name = s.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &object_id_path(s))?;
+            let s_p = self.db.get_object_neighbour_with_properties_out(&s.vertex.id, EdgeTypes::UsesProvider)
+                .ok_or_else(|| Sense8Error::DanglingReference {
+                    id_path: eut_id_path.clone(),
+                    r#type: KEY_PROVIDER.to_string(),
+                    name: site_name.to_string(),
+                })?;
+            let provider_name = s_p.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &object_id_path(&s_p))?;
             let r_p = self.db.get_object_neighbour_with_properties_out(&s.vertex.id, EdgeTypes::SiteRefersRte);
             let mut rte_name: String = Default::default();
 
             if let Some(v) = r_p {
-                rte_name = v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+                rte_name = v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_MODULE, &object_id_path(&v))?.to_string();
             }
 
             //Process eut site scripts
-            let scripts_path = eut_p_module.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+            let scripts_path = eut_p_module.get_str_value(KEY_SCRIPTS_PATH, &eut_id_path)?;
             let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-            for script in eut_p_module.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+            for script in eut_p_module.get_array_value(KEY_SCRIPTS, &eut_id_path)?.iter() {
                 let path = format!("{}/{}/{}/{}/{}",
-                                   self.config.root_path,
-                                   self.config.eut.path,
+                                   config.root_path,
+                                   config.eut.path,
                                    eut_name, scripts_path,
-                                   script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                let contents = std::fs::read_to_string(path).expect("panic while opening eut site script file");
+                                   script.get_str_value(KEY_FILE, &eut_id_path)?);
+                let contents = read_file(&path)?;
                 let ctx = ScriptEutRenderContext {
-                    project: self.config.project.clone(),
+                    project: config.project.clone(),
                     rte: rte_name.to_string(),
                     rtes: rte_names.clone(),
                     name: eut_name.to_string(),
                     site: site_name.to_string(),
                     index: i,
                     counter: sites.len(),
-                    release: eut_p_module.get(KEY_RELEASE).unwrap().as_str().unwrap().to_string(),
+                    release: eut_p_module.get_str_value(KEY_RELEASE, &eut_id_path)?.to_string(),
                     provider: provider_name.to_string(),
-                    artifacts_path: eut_p_base.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
+                    artifacts_path: eut_p_base.get_str_value(KEY_ARTIFACTS_PATH, &eut_id_path)?.to_string(),
                 };
 
                 let mut commands: Vec<String> = Vec::new();
-                for command in render_script(&ctx, &contents).lines() {
+                for command in render_script(&ctx, &contents, &path)?.lines() {
                     commands.push(format!("{:indent$}{}", "", command, indent = 0));
                 }
 
                 let data: HashMap<String, Vec<String>> = [
-                    (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                    (script.get_str_value(KEY_SCRIPT, &eut_id_path)?.to_string(), commands),
                 ].into_iter().collect();
                 scripts.push(data);
             }
             let eut_s_rc = EutSiteRenderContext {
-                job: format!("{}_{}_{}_{}", project_module, KEY_EUT, &eut_name, &site_name).replace('_', "-"),
+                job: format!("{}_{}_{}_{}", config.job_prefix(), KEY_EUT, &eut_name, &site_name).replace('_', "-"),
                 name: site_name.to_string(),
                 index: i,
                 scripts,
@@ -1666,182 +2250,215 @@ impl<'a> Regression<'a> {
             sites: eut_sites,
             module: eut_p_module.clone(),
             provider: eut_provider_p_base.clone(),
-            project: self.config.project.clone(),
+            project: config.project.clone(),
         };
 
-        let mut stages: Vec<String> = Vec::new();
-        let mut deploy_stages: Vec<String> = Vec::new();
-        let mut destroy_stages: Vec<String> = Vec::new();
-
-        let project_ci = self.db.get_object_neighbour_out(&project.get_id(), EdgeTypes::HasCi);
-        let s_deploy = self.db.get_object_neighbour_with_properties_out(&project_ci.clone().unwrap().id, EdgeTypes::HasDeployStages).unwrap();
-        let s_destroy = self.db.get_object_neighbour_with_properties_out(&project_ci.unwrap().id, EdgeTypes::HasDestroyStages).unwrap();
-        deploy_stages.push(s_deploy.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string());
-        self.get_next_stage(&s_deploy.vertex.id, &mut deploy_stages);
-        deploy_stages.push(s_destroy.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string());
-        self.get_next_stage(&s_destroy.vertex.id, &mut destroy_stages);
-
-        stages.append(&mut deploy_stages);
-        stages.append(&mut destroy_stages);
-
-        let mut context = Context::new();
-        context.insert(KEY_EUT, &eut_rc);
-        context.insert(KEY_RTES, &rtes_rc);
-        context.insert(KEY_CONFIG, &self.config);
-        context.insert(KEY_STAGES, &stages);
-        context.insert(KEY_REPORTS, &reports_rc);
-        context.insert(KEY_ACTIONS, &actions);
-        context.insert(KEY_PROJECT, &project_rc);
-        context.insert(KEY_FEATURES, &features_rc);
-        context.insert(KEY_DASHBOARD, &dashboard_rc);
-        context.insert(KEY_COLLECTORS, &collectors_rc);
-        context.insert(KEY_APPLICATIONS, &applications_rc);
-
-        //error!("{:#?}", context.get(KEY_APPLICATIONS));
-        info!("Build render context -> Done.");
-        context
+        Ok(EutGroupRenderContext {
+            name: config.eut.name.clone(),
+            prefix: config.namespaced(""),
+            stages: EutStagesRenderContext {
+                rte: config.rte.ci.stages.clone(),
+                features: config.features.ci.stages.clone(),
+                eut: config.eut.ci.stages.clone(),
+                applications: config.applications.ci.stages.clone(),
+                tests: config.tests.ci.stages.clone(),
+                verifications: config.verifications.ci.stages.clone(),
+                reports: config.reports.ci.stages.clone(),
+            },
+            eut: eut_rc,
+            rtes: rtes_rc,
+            features: features_rc,
+            applications: applications_rc,
+            collectors: collectors_rc,
+            reports: reports_rc,
+            actions,
+        })
     }
 
-    fn get_next_stage(&self, id: &Uuid, data: &mut Vec<String>) {
+    fn get_next_stage(&self, id: &Uuid, data: &mut Vec<String>) -> Result<()> {
         for stage in self.db.get_object_neighbours_with_properties_out(id, EdgeTypes::NextStage).iter() {
-            data.push(stage.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string());
-            self.get_next_stage(&stage.vertex.id, data);
+            data.push(stage.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &object_id_path(stage))?.to_string());
+            self.get_next_stage(&stage.vertex.id, data)?;
         }
+        Ok(())
     }
 
-    pub fn render(&self, context: &Context) -> String {
+    pub fn render(&self, backend: &dyn PipelineBackend, context: &Context) -> Result<String> {
         info!("Render regression pipeline file first step...");
         info!("Render first step template file {}", self.template);
-        let mut _tera = Tera::new(&self.template).unwrap();
-        let rendered = _tera.render(PIPELINE_TEMPLATE_FILE_NAME, context).unwrap();
+        let mut context = context.clone();
+        backend.extend_context(&self.config, &mut context)?;
+
+        let mut _tera = match self.template.is_empty() {
+            true => Tera::default(),
+            false => Tera::new(&self.template).map_err(|e| Sense8Error::template(&self.template, e))?,
+        };
+        if !_tera.get_template_names().any(|t| t == backend.template()) {
+            if let Some(default) = backend.default_template() {
+                info!("Template {} not found. Using bundled default", backend.template());
+                _tera.add_raw_template(backend.template(), default).map_err(|e| Sense8Error::template(backend.template(), e))?;
+            }
+        }
+
+        let rendered = _tera.render(backend.template(), &context).map_err(|e| Sense8Error::template(backend.template(), e))?;
         info!("Render regression pipeline file first step -> Done.");
-        rendered
+        Ok(rendered)
     }
 
-    #[allow(dead_code)]
+    /// Render pipeline file from native pipeline model of `backend` instead of its template.
+    pub fn render_native(&self, backend: &dyn PipelineBackend, context: &Context) -> Result<String> {
+        info!("Render regression pipeline file from native pipeline model...");
+        let mut context = context.clone();
+        backend.extend_context(&self.config, &mut context)?;
+
+        let rendered = backend.render_native(&self.config, &context)?
+            .ok_or_else(|| Sense8Error::Pipeline(format!("no native pipeline model for <{}>", backend.file_name(&self.config))))?;
+        info!("Render regression pipeline file from native pipeline model -> Done.");
+        Ok(rendered)
+    }
+
+    /// Render `template` from template directory. Falls back to the bundled template of the same
+    /// name if no template directory is given or it does not provide `template`.
+    fn render_template(&self, template: &str, context: &Context) -> Result<String> {
+        let mut _tera = match self.template.is_empty() {
+            true => Tera::default(),
+            false => Tera::new(&self.template).map_err(|e| Sense8Error::template(&self.template, e))?,
+        };
+        if !_tera.get_template_names().any(|t| t == template) {
+            if let Some((_, raw)) = BUNDLED_TEMPLATES.iter().find(|(name, _)| *name == template) {
+                info!("Template {template} not found. Using bundled default");
+                _tera.add_raw_template(template, raw).map_err(|e| Sense8Error::template(template, e))?;
+            }
+        }
+        _tera.render(template, context).map_err(|e| Sense8Error::template(template, e))
+    }
+
+    /// Export regression configuration and object graph as JSON string. See [`Regression::export_graph`].
     pub fn to_json(&self) -> String {
-        let j = json!({KEY_CONFIG: &self.config,});
-        j.to_string()
+        serde_json::to_string_pretty(&self.export_graph()).unwrap()
+    }
+
+    /// Export regression configuration and object graph. Every vertex is exported with its type,
+    /// id_path and base, gv and module properties. Every edge is exported with its type and the
+    /// id_paths of both ends. Vertices are sorted by id_path and edges by source, destination and
+    /// type so exports do not depend on generated vertex ids.
+    pub fn export_graph(&self) -> Value {
+        let mut id_paths: HashMap<Uuid, String> = HashMap::new();
+        let mut vertices: Vec<(String, Value)> = Vec::new();
+
+        for v in self.db.get_all_objects().unwrap_or_default().iter() {
+            let props = self.db.get_object_properties(v);
+            let layer = |t: PropertyType| -> Value {
+                props.as_ref()
+                    .and_then(|p| p.props.get(t.index()))
+                    .map(|p| (*p.value).clone())
+                    .unwrap_or(Null)
+            };
+            let base = layer(PropertyType::Base);
+            let id_path = match base.get(KEY_ID_PATH).and_then(|p| p.as_array()) {
+                Some(p) => p.iter().filter_map(|p| p.as_str()).collect::<Vec<&str>>().join("__"),
+                None => v.t.as_str().to_string(),
+            };
+            id_paths.insert(v.id, id_path.clone());
+            vertices.push((id_path.clone(), json!({
+                KEY_ID_PATH: id_path,
+                KEY_TYPE: v.t.as_str(),
+                PROPERTY_TYPE_BASE: base,
+                PROPERTY_TYPE_GV: layer(PropertyType::Gv),
+                PROPERTY_TYPE_MODULE: layer(PropertyType::Module),
+            })));
+        }
+        vertices.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut edges: Vec<(String, String, String)> = self.db.get_all_edges().unwrap_or_default().iter()
+            .map(|e| (
+                id_paths.get(&e.outbound_id).cloned().unwrap_or_default(),
+                id_paths.get(&e.inbound_id).cloned().unwrap_or_default(),
+                e.t.as_str().to_string(),
+            ))
+            .collect();
+        edges.sort();
+
+        json!({
+            KEY_CONFIG: &self.config,
+            KEY_VERTICES: vertices.into_iter().map(|(_, v)| v).collect::<Vec<Value>>(),
+            KEY_EDGES: edges.into_iter().map(|(src, dst, t)| json!({KEY_SRC: src, KEY_DST: dst, KEY_TYPE: t})).collect::<Vec<Value>>(),
+        })
     }
 
-    pub fn to_file(&self, data: &str, path: &str, file: &str) {
+    pub fn to_file(&self, data: impl AsRef<[u8]>, path: &str, file: &str) -> Result<()> {
+        let out = format!("{path}/{file}");
+        if let Some(parent) = std::path::Path::new(&out).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| Sense8Error::Io { path: out.clone(), source: e })?;
+        }
         let mut f = std::fs::OpenOptions::new()
             .write(true)
             .truncate(true)
             .create(true)
-            .open(format!("{path}/{file}"))
-            .expect("Couldn't open output file");
+            .open(&out)
+            .map_err(|e| Sense8Error::Io { path: out.clone(), source: e })?;
 
-        f.write_all(data.as_bytes()).expect("panic while writing to file");
+        f.write_all(data.as_ref()).map_err(|e| Sense8Error::Io { path: out, source: e })
     }
 
-    pub fn to_gv(&self) -> String {
-        let mut context = Context::new();
-        let _nodes = self.db.get_all_objects();
-
-        match &_nodes {
-            Some(nodes) => {
-                let mut items = Vec::new();
-
-                for n in nodes.iter() {
-                    let n_p = self.db.get_object_properties(&self.db.get_object(&n.id));
-                    match n_p {
-                        Some(p) => {
-                            let gv_id = p.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GVID].as_str();
-                            let gv_label = p.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GV_LABEL].as_str();
-                            match gv_id {
-                                Some(_) => items.push(json!({"id": &gv_id.unwrap(), "label": &gv_label, "shape": "circle"})),
-                                None => items.push(json!({"id": &n.t.as_str(), "label": &n.t.as_str(), "shape": "circle"}))
-                            }
-                        }
-                        None => {
-                            items.push(json!({"id": &n.t.as_str(), "name": &n.t.as_str(), "shape": "circle"}))
-                        }
-                    }
-                };
-                context.insert("nodes", &items);
-            }
-            None => {}
-        }
+    /// Render object graph as GraphViz DOT. Nodes are styled per object type and edges labeled with
+    /// their edge type. With `clusters` objects are grouped by EUT, RTE and connection. `view`
+    /// restricts rendered objects, see [`gv`].
+    pub fn to_gv(&self, clusters: bool, view: &str) -> Result<String> {
+        let context = gv::build_context(self.db, clusters, view);
+        let data = self.render_template(GRAPH_TEMPLATE_FILE_NAME, &context)?;
+        gv::parse(&data)?;
+        Ok(data)
+    }
 
-        //Edges
-        let _edges = self.db.get_all_edges();
-
-        match &_edges {
-            Some(edge) => {
-                let mut items = Vec::new();
-
-                for e in edge.iter() {
-                    let o_a = self.db.get_object(&e.outbound_id);
-                    let o_b = self.db.get_object(&e.inbound_id);
-                    let a_id = self.db.get_object(&e.outbound_id).t.to_string();
-                    let b_id = self.db.get_object(&e.inbound_id).t.to_string();
-                    let a_p = self.db.get_object_properties(&self.db.get_object(&o_a.id));
-                    let b_p = self.db.get_object_properties(&self.db.get_object(&o_b.id));
-
-                    match a_p {
-                        Some(ap) => {
-                            match b_p {
-                                Some(bp) => {
-                                    let a_p_name = &ap.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GVID].as_str();
-                                    let b_p_name = &bp.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GVID].as_str();
-
-                                    match a_p_name {
-                                        Some(ap) => {
-                                            match b_p_name {
-                                                Some(bp) => items.push(json!({KEY_SRC: &ap, KEY_DST: &bp})),
-                                                None => items.push(json!({KEY_SRC: &ap, KEY_DST: &b_id}))
-                                            }
-                                        }
-                                        None => {
-                                            match b_p_name {
-                                                Some(bp) => items.push(json!({KEY_SRC: &a_id, KEY_DST: &bp})),
-                                                None => items.push(json!({KEY_SRC: &a_id, KEY_DST: &b_id}))
-                                            }
-                                        }
-                                    }
-                                }
-                                None => {
-                                    let a_p_name = &ap.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GVID].as_str();
-                                    match a_p_name {
-                                        Some(ap) => items.push(json!({KEY_SRC: &ap, KEY_DST: &b_id})),
-                                        None => items.push(json!({KEY_SRC: &a_id, KEY_DST: &b_id}))
-                                    }
-                                }
-                            }
-                        }
-                        None => {
-                            match b_p {
-                                Some(bp) => {
-                                    let b_p_name = &bp.props.get(PropertyType::Gv.index()).unwrap().value[KEY_GVID].as_str();
-                                    match b_p_name {
-                                        Some(bp) => items.push(json!({KEY_SRC: &a_id, KEY_DST: &bp})),
-                                        None => items.push(json!({KEY_SRC: &a_id, KEY_DST: &b_id}))
-                                    }
-                                }
-                                None => items.push(json!({KEY_SRC: &a_id, KEY_DST: &b_id}))
-                            }
-                        }
-                    }
-                };
-                context.insert("edges", &items);
+    /// Export stage DAG of pipeline built from `context` as DOT or Mermaid flowchart. See
+    /// [`Pipeline::stages_to_dot`] and [`Pipeline::stages_to_mermaid`].
+    pub fn stages_graph(&self, context: &Context, format: &str) -> Result<String> {
+        let pipeline = Pipeline::build(&self.config, context)?;
+        match format {
+            GV_FORMAT_MERMAID => Ok(pipeline.stages_to_mermaid()),
+            _ => {
+                let data = pipeline.stages_to_dot();
+                gv::parse(&data)?;
+                Ok(data)
             }
-            None => {}
         }
+    }
+
+    /// Export connection topology of every RTE as Mermaid flowchart or PlantUML deployment diagram.
+    /// Returns file name and content per RTE. See [`RteTopology`].
+    pub fn topology(&self, format: &str) -> Vec<(String, String)> {
+        RteTopology::load(self.db).iter()
+            .map(|t| (t.file_name(format), t.render(format)))
+            .collect()
+    }
 
-        let mut _tera = Tera::new(&self.template).unwrap();
-        _tera.render("graph.tpl", &context).unwrap()
+    /// Objects whose id_path starts with `id_path` and optionally of type `vertex_type` with their
+    /// incoming and outgoing edges. Format follows [`Regression::export_graph`].
+    pub fn inspect(&self, id_path: &str, vertex_type: Option<&str>) -> Value {
+        let graph = self.export_graph();
+        let vertices: Vec<&Value> = graph[KEY_VERTICES].as_array().into_iter().flatten()
+            .filter(|v| v[KEY_ID_PATH].as_str().unwrap_or_default().starts_with(id_path))
+            .filter(|v| vertex_type.is_none_or(|t| v[KEY_TYPE] == t))
+            .collect();
+        let selected: Vec<&Value> = vertices.iter().map(|v| &v[KEY_ID_PATH]).collect();
+        let edges: Vec<&Value> = graph[KEY_EDGES].as_array().into_iter().flatten()
+            .filter(|e| selected.contains(&&e[KEY_SRC]) || selected.contains(&&e[KEY_DST]))
+            .collect();
+
+        json!({KEY_VERTICES: vertices, KEY_EDGES: edges})
     }
 
-    pub fn render_entry_page(&self, context: &Context) -> Result<String, Box<dyn Error>> {
+    /// Render entry page. Stage DAG is embedded as Mermaid flowchart and available as `stages_mermaid`.
+    pub fn render_entry_page(&self, context: &Context) -> Result<String> {
         error!("Render entry page..");
-        let mut _tera = Tera::new(&self.template).unwrap();
-        Ok(_tera.render("entry.tpl", context).unwrap())
+        let mut context = context.clone();
+        context.insert("stages_mermaid", &self.stages_graph(&context, GV_FORMAT_MERMAID)?);
+        self.render_template(ENTRY_TEMPLATE_FILE_NAME, &context)
     }
 
-    pub fn render_actions_json_file(&self, context: &Context) -> Result<String, Box<dyn Error>> {
+    pub fn render_actions_json_file(&self, context: &Context) -> Result<String> {
         error!("Render actions json file..");
-        let mut _tera = Tera::new(&self.template).unwrap();
-        Ok(_tera.render("actions.tpl", context).unwrap())
+        self.render_template(ACTIONS_TEMPLATE_FILE_NAME, context)
     }
 }
\ No newline at end of file
@@ -1,103 +1,628 @@
 /*!
 F5 XC regression test CI pipeline file generator.
-Provides command line tool to generate Gitlab CI pipeline file.
+Provides command line tool to generate Gitlab CI pipeline or GitHub Actions workflow file.
 
 Consumes input from regression configuration file provided as command line argument.
 Template file relays on tool provided data structure to render stage, job or variables sections.
 Tool supports direct rendering of given template file or generates JSON output which could be
 used as input for another program or workflow.
+
+Every task is a subcommand. Regression configuration is selected with the global options
+`--root-path`, `--config-file` and `--eut-config-file`. Output files are written below `--out-dir`
+unless a subcommand is given an explicit `--output` path. Environment specific settings are
+layered on top of the regression configuration with one or more `--overlay` files and single
+values with `--set`. `--var` and `--vars-file` parameterize the configuration template per run.
  */
 
-use clap::Parser;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap::builder::PossibleValuesParser;
+use clap::error::ErrorKind;
 use log::{error, info};
+use uuid::Uuid;
 
-use sense8_ci_generator::constants::{ACTIONS_FILE_NAME, ENTRY_FILE_NAME, PIPELINE_FILE_NAME};
+use sense8_ci_generator::backend::{new_backend, BACKENDS};
+use sense8_ci_generator::constants::{ACTIONS_FILE_NAME, ARRAY_MERGE_REPLACE, BACKEND_GITLAB, ENTRY_FILE_NAME, GRAPH_JSON_FILE_NAME, GV_FORMAT_DOT,
+    GV_FORMAT_MERMAID, GV_VIEW_ALL, KEY_DST, KEY_EDGES, KEY_ID_PATH, KEY_SRC, KEY_TYPE, KEY_VERTICES, OUT_DIR,
+    STAGES_GV_FILE_NAME, STAGES_MERMAID_FILE_NAME};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::diff::Source;
+use sense8_ci_generator::error::{Result, Sense8Error};
+use sense8_ci_generator::filter::{Filter, TagFilter};
+use sense8_ci_generator::gv::{self, GV_FORMATS, GV_VIEWS};
+use sense8_ci_generator::objects::config::{schema, SCHEMA_KINDS};
+use sense8_ci_generator::overlay::{ArrayMerge, Assignment, Overlay, ARRAY_MERGE_STRATEGIES};
+use sense8_ci_generator::pipeline::STAGES_FORMATS;
+use sense8_ci_generator::topology::TOPOLOGY_FORMATS;
+use sense8_ci_generator::Regression;
 
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(name = "sense8", author, version, about, long_about = None)]
 struct Cli {
+    #[command(flatten)]
+    global: GlobalArgs,
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "Global options")]
+struct GlobalArgs {
+    /// Regression root path
+    #[arg(long, global = true)]
+    root_path: Option<String>,
     /// Regression configuration file
-    #[arg(long)]
-    root_path: String,
-    #[arg(long)]
-    config_file: String,
+    #[arg(long, global = true)]
+    config_file: Option<String>,
     /// Regression EUT configuration file
-    #[arg(long)]
+    #[arg(long, global = true)]
     eut_config_file: Option<String>,
-    /// Regression CI template file
-    #[arg(long)]
-    template: String,
-    /// Write CI pipeline file
-    #[arg(long)]
-    write_ci: bool,
-    ci_file_path: String,
-    /// Export data to json file
-    #[arg(long)]
-    write_json: bool,
+    /// Directory output files are written to if no --output is given
+    #[arg(long, global = true, default_value = OUT_DIR)]
+    out_dir: String,
+    /// Configuration overlay deep-merged on top of regression and module configuration.
+    /// May be given multiple times. Overlays are applied in the order given
+    #[arg(long, global = true)]
+    overlay: Vec<String>,
+    /// How arrays of an overlay are merged into the configuration
+    #[arg(long, global = true, value_parser = PossibleValuesParser::new(ARRAY_MERGE_STRATEGIES), default_value = ARRAY_MERGE_REPLACE)]
+    array_merge: String,
+    /// Override regression configuration value after overlays. Value is parsed as JSON or taken
+    /// as string. May be given multiple times
+    #[arg(long, global = true, value_name = "PATH.TO.KEY=VALUE", value_parser = Assignment::parse)]
+    set: Vec<Assignment>,
+    /// Variable available as vars.<KEY> in regression configuration template. May be given
+    /// multiple times and wins over --vars-file
+    #[arg(long, global = true, value_name = "KEY=VALUE", value_parser = Assignment::parse)]
+    var: Vec<Assignment>,
+    /// JSON, YAML or TOML file with variables available as vars in regression configuration template
+    #[arg(long, global = true)]
+    vars_file: Option<String>,
+    /// Print regression configuration with overlays applied and exit
+    #[arg(long, global = true)]
+    show_effective_config: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
     /// Render CI pipeline file
-    #[arg(short, long)]
-    render_ci: bool,
-    /// Write to GraphViz file
-    #[arg(long)]
-    write_gv: bool,
-    /// Generate action names
-    #[arg(long)]
-    gen_actions: bool,
-    entry_file_path: Option<String>,
+    Generate {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Regression CI template files glob. Bundled template is used if omitted or if it does not
+        /// match the backend template
+        #[arg(long, conflicts_with = "native")]
+        template: Option<String>,
+        /// Build pipeline file from native pipeline model instead of rendering template
+        #[arg(long)]
+        native: bool,
+        /// CI backend the pipeline file is rendered for
+        #[arg(long, value_parser = PossibleValuesParser::new(BACKENDS), default_value = BACKEND_GITLAB)]
+        backend: String,
+        /// Print pipeline file instead of writing it
+        #[arg(long, conflicts_with = "output")]
+        stdout: bool,
+        /// Pipeline file path. Defaults to backend pipeline file name in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Export object graph, stage DAG or RTE connection topology
+    Graph {
+        #[command(subcommand)]
+        kind: GraphCommands,
+    },
+    /// Render actions JSON file listing deploy and destroy actions per object
+    Actions {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Template files glob. Bundled template is used if omitted
+        #[arg(long)]
+        template: Option<String>,
+        /// Actions file path. Defaults to actions.json in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Render project entry page with pipeline shape and actions
+    Entry {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Template files glob. Bundled template is used if omitted
+        #[arg(long)]
+        template: Option<String>,
+        /// Entry page path. Defaults to entry.md in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Export configuration and object graph as JSON
+    Export {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Export file path. Defaults to graph.json in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Validate regression configuration tree without rendering
+    Validate {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Check populated object graph for dangling refs, orphans, stage cycles, sites without
+    /// provider and connection sources without site
+    Check {
+        /// Check object graph saved with --save-graph instead of building it. Refs are not checked.
+        /// Configuration is taken from the graph, configuration options may not be given
+        #[arg(long)]
+        load_graph: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Print objects of the object graph with their edges
+    Inspect {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Only print objects whose id_path starts with this prefix
+        id_path: Option<String>,
+        /// Only print objects of this type
+        #[arg(long = "type")]
+        vertex_type: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Compare stages, jobs, scripts, artifacts paths and refs of two regression configurations.
+    /// Global configuration options select the old side
+    Diff {
+        /// Regression root path of new side. Defaults to --root-path
+        #[arg(long)]
+        new_root_path: Option<String>,
+        /// Regression configuration file of new side. Defaults to --config-file
+        #[arg(long)]
+        new_config_file: Option<String>,
+        /// Regression EUT configuration file of new side. Defaults to --eut-config-file
+        #[arg(long)]
+        new_eut_config_file: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Print JSON Schema of regression or module configuration
+    Schema {
+        /// Configuration kind. Prints all kinds if omitted
+        #[arg(value_parser = PossibleValuesParser::new(SCHEMA_KINDS))]
+        kind: Option<String>,
+        /// Write one <KIND>.schema.json file per kind to --out-dir instead of printing
+        #[arg(long)]
+        write: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum GraphCommands {
+    /// Object graph with per type node styles and edge labels
+    Objects {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Template files glob. Bundled graph template is used if omitted
+        #[arg(long)]
+        template: Option<String>,
+        /// Output format. svg and png require GraphViz dot executable in PATH
+        #[arg(long, value_parser = PossibleValuesParser::new(GV_FORMATS), default_value = GV_FORMAT_DOT)]
+        format: String,
+        /// Restrict output to stages, RTE connection topology or resolved refs
+        #[arg(long, value_parser = PossibleValuesParser::new(GV_VIEWS), default_value = GV_VIEW_ALL)]
+        view: String,
+        /// Group objects into clusters per EUT, RTE and connection
+        #[arg(long)]
+        clusters: bool,
+        /// Graph file path. Defaults to graph.<gv|svg|png> in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Stage DAG with jobs and needs
+    Stages {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Output format
+        #[arg(long, value_parser = PossibleValuesParser::new(STAGES_FORMATS), default_value = GV_FORMAT_DOT)]
+        format: String,
+        /// Stage DAG file path. Defaults to stages.<gv|mmd> in --out-dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Connection topology of every RTE written to one topology-<rte> file per RTE in --out-dir.
+    /// RTE files of a project holding multiple EUTs are named topology-<eut>-<rte>
+    Topology {
+        #[command(flatten)]
+        build: BuildArgs,
+        /// Output format
+        #[arg(long, value_parser = PossibleValuesParser::new(TOPOLOGY_FORMATS), default_value = GV_FORMAT_MERMAID)]
+        format: String,
+    },
+}
+
+/// Options controlling how the object graph is built.
+#[derive(Args, Debug)]
+struct BuildArgs {
+    /// Only render objects selected by <KIND>=<VALUE> with KIND one of rte, site (regex) or test.
+    /// May be given multiple times
+    #[arg(long, value_parser = Filter::parse, conflicts_with = "load_graph")]
+    only: Vec<Filter>,
+    /// Only render tests and verifications carrying any of these comma separated tags
+    #[arg(long, value_delimiter = ',', conflicts_with = "load_graph")]
+    include_tags: Vec<String>,
+    /// Do not render tests and verifications carrying any of these comma separated tags
+    #[arg(long, value_delimiter = ',', conflicts_with = "load_graph")]
+    exclude_tags: Vec<String>,
+    /// Save populated object graph to file together with configuration and rendered scripts
+    #[arg(long, conflicts_with = "load_graph")]
+    save_graph: Option<String>,
+    /// Load object graph saved with --save-graph instead of building it. Configuration and
+    /// rendered scripts are taken from the graph, configuration options may not be given
     #[arg(long)]
-    gen_actions_json: bool,
-    json_file_path: Option<String>,
+    load_graph: Option<String>,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl GlobalArgs {
+    /// Root path and configuration file. Exits with usage error if either is missing.
+    fn config(&self) -> (&str, &str) {
+        match (&self.root_path, &self.config_file) {
+            (Some(root_path), Some(config_file)) => (root_path, config_file),
+            _ => Cli::command()
+                .error(ErrorKind::MissingRequiredArgument, "--root-path and --config-file are required by this command")
+                .exit(),
+        }
+    }
+
+    /// Graphs loaded with --load-graph carry the configuration they were built with. Fails if
+    /// any option changing the configuration is given.
+    fn reject_config(&self) -> Result<()> {
+        let given: Vec<&str> = [
+            ("--root-path", self.root_path.is_some()),
+            ("--config-file", self.config_file.is_some()),
+            ("--eut-config-file", self.eut_config_file.is_some()),
+            ("--overlay", !self.overlay.is_empty()),
+            ("--set", !self.set.is_empty()),
+            ("--var", !self.var.is_empty()),
+            ("--vars-file", self.vars_file.is_some()),
+        ].into_iter().filter(|(_, given)| *given).map(|(option, _)| option).collect();
+
+        match given.is_empty() {
+            true => Ok(()),
+            false => Err(Sense8Error::Override(format!("{} can not be used with --load-graph. Graph holds the configuration it was built with",
+                                                       given.join(", ")))),
+        }
+    }
+
+    /// Overlay files loaded with selected array merge strategy, overrides and variables.
+    fn overlay(&self) -> Result<Overlay> {
+        Overlay::load(&self.overlay, ArrayMerge::from_name(&self.array_merge).unwrap_or_default())?
+            .with_sets(&self.set)
+            .with_vars(self.vars_file.as_deref(), &self.var)
+    }
+
+    /// Output file path split into directory and file name. `output` wins over `file` in --out-dir.
+    fn output(&self, output: &Option<String>, file: &str) -> (String, String) {
+        match output {
+            Some(path) => {
+                let path = std::path::Path::new(path);
+                let dir = path.parent().and_then(|p| p.to_str()).filter(|p| !p.is_empty()).unwrap_or(".");
+                let file = path.file_name().and_then(|f| f.to_str()).unwrap_or_default();
+                (dir.to_string(), file.to_string())
+            }
+            None => (self.out_dir.clone(), file.to_string()),
+        }
+    }
 }
 
 fn main() {
     env_logger::init();
     let cli = Cli::parse();
-    let db = sense8_ci_generator::db::Db::new();
-    let r = sense8_ci_generator::Regression::new(&db,
-                                                 &cli.root_path,
-                                                 &cli.config_file,
-                                                 &cli.template, &cli.eut_config_file);
-    let (p, refs) = r.init();
-    r.init_refs(p, &refs);
-    r.init_artifacts(p, &refs);
-    r.init_stages(p);
-    let ctx = r.build_context(p);
-
-    if cli.write_ci {
-        r.to_file(&r.render(&ctx), cli.ci_file_path.as_str(), PIPELINE_FILE_NAME);
-    }
-    if cli.write_json {
-        r.to_json();
-        info!("{}", r.to_json());
-    }
-    if cli.render_ci {
-        info!("{}", r.render(&ctx));
-    }
-    if cli.write_gv {
-        r.to_file(&r.to_gv(), "./out", &"graph.gv");
-    }
-    if cli.gen_actions_json {
-        let a = r.render_actions_json_file(&ctx);
-        match a {
-            Ok(data) => {
-                error!("Render actions json -> Done");
-                r.to_file(&data, cli.json_file_path.unwrap().as_str(), ACTIONS_FILE_NAME);
+    let global = &cli.global;
+
+    let command = match (cli.command, global.show_effective_config) {
+        (_, true) => {
+            if let Err(err) = show_effective_config(global) {
+                eprintln!("sense8: error: {err}");
+                std::process::exit(1);
             }
-            Err(err) => {
-                error!("ERR: {}", err)
+            return;
+        }
+        (Some(command), false) => command,
+        (None, false) => Cli::command()
+            .error(ErrorKind::MissingSubcommand, "a subcommand or --show-effective-config is required")
+            .exit(),
+    };
+
+    let result = match command {
+        Commands::Generate { build, template, native, backend, stdout, output } => {
+            generate(global, &build, &template, native, &backend, stdout, &output)
+        }
+        Commands::Graph { kind } => graph(global, kind),
+        Commands::Actions { build, template, output } => actions(global, &build, &template, &output),
+        Commands::Entry { build, template, output } => entry(global, &build, &template, &output),
+        Commands::Export { build, output } => export(global, &build, &output),
+        Commands::Validate { format } => validate(global, format),
+        Commands::Check { load_graph, format } => check(global, &load_graph, format),
+        Commands::Inspect { build, id_path, vertex_type, format } => {
+            inspect(global, &build, id_path.as_deref().unwrap_or_default(), vertex_type.as_deref(), format)
+        }
+        Commands::Diff { new_root_path, new_config_file, new_eut_config_file, format } => {
+            let (root_path, config_file) = global.config();
+            let overlay = match global.overlay() {
+                Ok(overlay) => overlay,
+                Err(err) => {
+                    eprintln!("sense8: error: {err}");
+                    std::process::exit(1);
+                }
+            };
+            let old = Source { path: root_path, file: config_file, eut_file: &global.eut_config_file, overlay: &overlay };
+            let new_eut_config_file = new_eut_config_file.or(global.eut_config_file.clone());
+            let new = Source {
+                path: new_root_path.as_deref().unwrap_or(root_path),
+                file: new_config_file.as_deref().unwrap_or(config_file),
+                eut_file: &new_eut_config_file,
+                overlay: &overlay,
+            };
+            diff(&old, &new, format)
+        }
+        Commands::Schema { kind, write } => print_schema(kind, write.then(|| global.out_dir.clone())),
+    };
+
+    if let Err(err) = result {
+        eprintln!("sense8: error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn open_db(build: &BuildArgs) -> Result<Db> {
+    match (&build.load_graph, &build.save_graph) {
+        (Some(path), _) => Db::open(path),
+        (None, Some(path)) => Ok(Db::create(path)),
+        (None, None) => Ok(Db::new()),
+    }
+}
+
+/// Build object graph or load it from --load-graph. Returns regression and project object id.
+fn build_graph<'a>(db: &'a Db, global: &GlobalArgs, build: &BuildArgs, template: &Option<String>) -> Result<(Regression<'a>, Uuid)> {
+    if build.load_graph.is_some() {
+        global.reject_config()?;
+        return Regression::open(db, template.as_deref().unwrap_or_default());
+    }
+
+    let (root_path, config_file) = global.config();
+    let r = Regression::new(db, root_path, config_file, template.as_deref().unwrap_or_default(), &global.eut_config_file, &global.overlay()?)?;
+    let (p, refs) = r.init()?;
+    r.init_refs(p, &refs)?;
+    let tags = TagFilter { include: build.include_tags.clone(), exclude: build.exclude_tags.clone() };
+    let refs = r.prune(&build.only, &tags, refs)?;
+    r.init_artifacts(p, &refs)?;
+    r.init_stages(p)?;
+    if build.save_graph.is_some() {
+        r.save(p)?;
+    }
+
+    Ok((r, p))
+}
+
+fn generate(global: &GlobalArgs, build: &BuildArgs, template: &Option<String>, native: bool, backend: &str, stdout: bool,
+            output: &Option<String>) -> Result<()> {
+    let backend = new_backend(backend)
+        .ok_or_else(|| Sense8Error::Override(format!("unknown backend <{backend}>, expected one of {}", BACKENDS.join(", "))))?;
+    let db = open_db(build)?;
+    let (r, p) = build_graph(&db, global, build, template)?;
+    let ctx = r.build_context(p)?;
+    let data = match native {
+        true => r.render_native(backend.as_ref(), &ctx)?,
+        false => r.render(backend.as_ref(), &ctx)?,
+    };
+    backend.verify(&data)?;
+
+    match stdout {
+        true => println!("{data}"),
+        false => {
+            let (dir, file) = global.output(output, &backend.file_name(&r.config));
+            r.to_file(&data, &dir, &file)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn graph(global: &GlobalArgs, kind: GraphCommands) -> Result<()> {
+    match kind {
+        GraphCommands::Objects { build, template, format, view, clusters, output } => {
+            let db = open_db(&build)?;
+            let (r, _) = build_graph(&db, global, &build, &template)?;
+            let data = gv::render(&r.to_gv(clusters, &view)?, &format)?;
+            let (dir, file) = global.output(&output, &gv::file_name(&format));
+            r.to_file(&data, &dir, &file)
+        }
+        GraphCommands::Stages { build, format, output } => {
+            let db = open_db(&build)?;
+            let (r, p) = build_graph(&db, global, &build, &None)?;
+            let data = r.stages_graph(&r.build_context(p)?, &format)?;
+            let file = match format.as_str() {
+                GV_FORMAT_MERMAID => STAGES_MERMAID_FILE_NAME,
+                _ => STAGES_GV_FILE_NAME,
+            };
+            let (dir, file) = global.output(&output, file);
+            r.to_file(&data, &dir, &file)
+        }
+        GraphCommands::Topology { build, format } => {
+            let db = open_db(&build)?;
+            let (r, _) = build_graph(&db, global, &build, &None)?;
+            for (file, data) in r.topology(&format).iter() {
+                r.to_file(data, &global.out_dir, file)?;
             }
+            Ok(())
         }
     }
-    if cli.gen_actions {
-        let a = r.render_entry_page(&ctx);
-        match a {
-            Ok(data) => {
-                error!("Render entry page -> Done");
-                r.to_file(&data, cli.entry_file_path.unwrap().as_str(), ENTRY_FILE_NAME);
+}
+
+fn actions(global: &GlobalArgs, build: &BuildArgs, template: &Option<String>, output: &Option<String>) -> Result<()> {
+    let db = open_db(build)?;
+    let (r, p) = build_graph(&db, global, build, template)?;
+    let data = r.render_actions_json_file(&r.build_context(p)?)?;
+    error!("Render actions json -> Done");
+    let (dir, file) = global.output(output, ACTIONS_FILE_NAME);
+    r.to_file(&data, &dir, &file)
+}
+
+fn entry(global: &GlobalArgs, build: &BuildArgs, template: &Option<String>, output: &Option<String>) -> Result<()> {
+    let db = open_db(build)?;
+    let (r, p) = build_graph(&db, global, build, template)?;
+    let data = r.render_entry_page(&r.build_context(p)?)?;
+    error!("Render entry page -> Done");
+    let (dir, file) = global.output(output, ENTRY_FILE_NAME);
+    r.to_file(&data, &dir, &file)
+}
+
+fn export(global: &GlobalArgs, build: &BuildArgs, output: &Option<String>) -> Result<()> {
+    let db = open_db(build)?;
+    let (r, _) = build_graph(&db, global, build, &None)?;
+    let (dir, file) = global.output(output, GRAPH_JSON_FILE_NAME);
+    r.to_file(r.to_json(), &dir, &file)
+}
+
+fn validate(global: &GlobalArgs, format: OutputFormat) -> Result<()> {
+    let (root_path, config_file) = global.config();
+    let db = Db::new();
+    let report = sense8_ci_generator::validate::validate(&db, root_path, config_file, &global.eut_config_file, &global.overlay()?);
+
+    match format {
+        OutputFormat::Text => println!("{report}"),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+
+    if !report.is_ok() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn check(global: &GlobalArgs, load_graph: &Option<String>, format: OutputFormat) -> Result<()> {
+    let report = match load_graph {
+        Some(path) => {
+            global.reject_config()?;
+            let db = Db::open(path)?;
+            let (r, _) = Regression::open(&db, "")?;
+            r.check_integrity(&[])
+        }
+        None => {
+            let (root_path, config_file) = global.config();
+            let db = Db::new();
+            let r = Regression::new(&db, root_path, config_file, "", &global.eut_config_file, &global.overlay()?)?;
+            let (p, refs) = r.init()?;
+            r.init_refs(p, &refs)?;
+            r.init_artifacts(p, &refs)?;
+            r.init_stages(p)?;
+            r.check_integrity(&refs)
+        }
+    };
+
+    match format {
+        OutputFormat::Text => println!("{report}"),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+
+    if !report.is_ok() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn inspect(global: &GlobalArgs, build: &BuildArgs, id_path: &str, vertex_type: Option<&str>, format: OutputFormat) -> Result<()> {
+    let db = open_db(build)?;
+    let (r, _) = build_graph(&db, global, build, &None)?;
+    let objects = r.inspect(id_path, vertex_type);
+
+    match format {
+        OutputFormat::Json => println!("{}", to_json(&objects, id_path)?),
+        OutputFormat::Text => {
+            let edges = objects[KEY_EDGES].as_array().cloned().unwrap_or_default();
+            for v in objects[KEY_VERTICES].as_array().into_iter().flatten() {
+                let id_path = &v[KEY_ID_PATH];
+                println!("{} {}", v[KEY_TYPE].as_str().unwrap_or_default(), id_path.as_str().unwrap_or_default());
+                for e in edges.iter().filter(|e| e[KEY_SRC] == *id_path) {
+                    println!("  -> {} {}", e[KEY_TYPE].as_str().unwrap_or_default(), e[KEY_DST].as_str().unwrap_or_default());
+                }
+                for e in edges.iter().filter(|e| e[KEY_DST] == *id_path) {
+                    println!("  <- {} {}", e[KEY_TYPE].as_str().unwrap_or_default(), e[KEY_SRC].as_str().unwrap_or_default());
+                }
             }
-            Err(err) => {
-                error!("ERR: {}", err)
+        }
+    }
+
+    Ok(())
+}
+
+fn show_effective_config(global: &GlobalArgs) -> Result<()> {
+    let (root_path, config_file) = global.config();
+    let db = Db::new();
+    let r = Regression::new(&db, root_path, config_file, "", &global.eut_config_file, &global.overlay()?)?;
+    println!("{}", to_json(&r.config, config_file)?);
+
+    Ok(())
+}
+
+fn diff(old: &Source, new: &Source, format: OutputFormat) -> Result<()> {
+    let report = sense8_ci_generator::diff::diff(old, new)?;
+
+    match format {
+        OutputFormat::Text => println!("{report}"),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+
+    Ok(())
+}
+
+fn print_schema(kind: Option<String>, out_dir: Option<String>) -> Result<()> {
+    let kinds: Vec<&str> = match &kind {
+        Some(k) => vec![k.as_str()],
+        None => SCHEMA_KINDS.to_vec(),
+    };
+
+    match out_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(&dir).map_err(|e| Sense8Error::Io { path: dir.clone(), source: e })?;
+            for k in kinds {
+                let path = format!("{dir}/{k}.schema.json");
+                let data = to_json(&kind_schema(k)?, k)?;
+                std::fs::write(&path, data).map_err(|e| Sense8Error::Io { path: path.clone(), source: e })?;
+                info!("Write schema file <{path}> -> Done");
             }
         }
+        None if kind.is_some() => println!("{}", to_json(&kind_schema(kinds[0])?, kinds[0])?),
+        None => {
+            let schemas = kinds.iter()
+                .map(|k| Ok((k.to_string(), kind_schema(k)?)))
+                .collect::<Result<serde_json::Map<String, serde_json::Value>>>()?;
+            println!("{}", to_json(&schemas, "schemas")?);
+        }
     }
-}
\ No newline at end of file
+
+    Ok(())
+}
+
+/// JSON schema of configuration file `kind`.
+fn kind_schema(kind: &str) -> Result<serde_json::Value> {
+    let schema = schema(kind).ok_or_else(|| Sense8Error::ConfigInvalid {
+        path: kind.to_string(),
+        msg: format!("unknown schema kind, expected one of {}", SCHEMA_KINDS.join(", ")),
+    })?;
+    serde_json::to_value(schema).map_err(|e| Sense8Error::ConfigInvalid { path: kind.to_string(), msg: e.to_string() })
+}
+
+/// Pretty printed JSON of `value`. `what` names the value in errors.
+fn to_json<T: serde::Serialize>(value: &T, what: &str) -> Result<String> {
+    serde_json::to_string_pretty(value).map_err(|e| Sense8Error::ConfigInvalid { path: what.to_string(), msg: e.to_string() })
+}
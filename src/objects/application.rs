@@ -9,6 +9,7 @@ use crate::{ApplicationRenderContext, EdgeTypes, PropertyType, RegressionConfig,
             render_script, RenderContext, Renderer, ScriptApplicationRenderContext};
 use crate::constants::{KEY_APPLICATION, KEY_APPLICATIONS, KEY_ARTIFACTS_PATH, KEY_DATA, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_PROVIDER, KEY_REF_ARTIFACTS_PATH, KEY_RELEASE, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config};
@@ -24,13 +25,11 @@ pub struct Application<'a> {
 }
 
 impl<'a> Application<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new application object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Application,
-                                                     &mut path,
-                                                     base_cfg.get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                     pop);
-        db.create_relationship(parent, &o);
+        let name = base_cfg.get_str_value(KEY_NAME, &path.join("__"))?;
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Application, &mut path, name, pop)?;
+        db.create_relationship(parent, &o)?;
         let applications = db.get_object_neighbour_in_out_id(&o.id,
                                                              EdgeTypes::ProvidesApplication,
                                                              VertexTypes::Applications).unwrap();
@@ -43,9 +42,9 @@ impl<'a> Application<'a> {
             unwrap().get(KEY_MODULE).
             unwrap().as_str().
             unwrap().to_string();
-        let a_name = base_cfg.get(KEY_NAME).unwrap().as_str().unwrap().to_string();
-        let a_module = base_cfg.get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let a_provider = base_cfg.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
+        let a_name = base_cfg.get_str_value(KEY_NAME, &id_path.get_str())?.to_string();
+        let a_module = base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?.to_string();
+        let a_provider = base_cfg.get_str_value(KEY_PROVIDER, &id_path.get_str())?.to_string();
         let artifacts_path = format!("{}/{}/{}/{}/{}/{}/{}",
                                      config.applications.artifacts_dir,
                                      eut_name, KEY_APPLICATIONS.to_string(),
@@ -55,11 +54,11 @@ impl<'a> Application<'a> {
                                      config.applications.artifacts_file);
         let mut _base_cfg = base_cfg.as_object().unwrap().clone();
         _base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &_base_cfg, PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        db.add_object_property(&o, &_base_cfg, PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Application {
+        Ok(Box::new(Application {
             object: Object {
                 db,
                 id: o.id,
@@ -67,17 +66,17 @@ impl<'a> Application<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Box<(dyn ApplicationExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Result<Box<(dyn ApplicationExt<'a> + 'a)>> {
         error!("Loading application object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config)?;
 
-        Box::new(Application {
+        Ok(Box::new(Application {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -85,7 +84,7 @@ impl<'a> Application<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
@@ -102,71 +101,73 @@ impl RenderContext for Application<'_> {
 }
 
 impl Renderer<'_> for Application<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
         let job: String;
-        let provider: String = self.get_base_properties().get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
+        let id_path = &self.get_id_path().get_str();
+        let provider: String = self.get_base_properties().get_str_value(KEY_PROVIDER, id_path)?.to_string();
 
         if provider.len() > 0 {
-            job = format!("{}_{}_{}_{}_{}", config.project.module, KEY_APPLICATION, self.get_module_properties().get(KEY_NAME).unwrap().as_str().unwrap(), provider, self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-")
+            job = format!("{}_{}_{}_{}_{}", config.job_prefix(), KEY_APPLICATION, self.get_module_properties().get_str_value(KEY_NAME, id_path)?, provider, self.get_base_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-")
         } else {
-            job = format!("{}_{}_{}", config.project.module, KEY_APPLICATION, self.get_module_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-")
+            job = format!("{}_{}_{}", config.job_prefix(), KEY_APPLICATION, self.get_module_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-")
         }
 
-        Box::new(ApplicationRenderContext {
+        Ok(Box::new(ApplicationRenderContext {
             job,
             base: self.get_base_properties(),
             module: self.get_module_properties(),
             project: config.project.clone(),
             scripts: scripts.clone(),
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let id_path = &self.get_id_path().get_str();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let m_props: Map<String, Value> = self.get_module_properties();
         let base_props: Map<String, Value> = self.get_base_properties();
-        let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}/{}",
                                config.root_path,
                                config.applications.path,
                                module,
                                scripts_path,
-                               base_props.get(KEY_PROVIDER).unwrap().as_str().unwrap(),
-                               script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening application script file");
+                               base_props.get_str_value(KEY_PROVIDER, id_path)?,
+                               script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let data_dir = format!("{}/{}/{}",
-                                   base_props.get(KEY_MODULE).unwrap().as_str().unwrap(),
-                                   base_props.get(KEY_PROVIDER).unwrap().as_str().unwrap(),
-                                   base_props.get(KEY_DATA).unwrap().as_str().unwrap(),
+                                   base_props.get_str_value(KEY_MODULE, id_path)?,
+                                   base_props.get_str_value(KEY_PROVIDER, id_path)?,
+                                   base_props.get_str_value(KEY_DATA, id_path)?,
             );
             let ctx = ScriptApplicationRenderContext {
                 eut: config.eut.module.to_string(),
-                name: base_props.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                data: base_props.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                refs: base_props.get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                name: base_props.get_str_value(KEY_NAME, id_path)?.to_string(),
+                data: base_props.get_str_value(KEY_DATA, id_path)?.to_string(),
+                refs: base_props.get_object_value(KEY_REF_ARTIFACTS_PATH, id_path)?.clone(),
                 module: module.to_string(),
                 project: config.project.clone(),
-                release: m_props.get(KEY_RELEASE).unwrap().as_str().unwrap().to_string(),
-                provider: base_props.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string(),
+                release: m_props.get_str_value(KEY_RELEASE, id_path)?.to_string(),
+                provider: base_props.get_str_value(KEY_PROVIDER, id_path)?.to_string(),
                 data_dir,
-                artifacts_path: base_props.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
+                artifacts_path: base_props.get_str_value(KEY_ARTIFACTS_PATH, id_path)?.to_string(),
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::{EdgeTypes, PropertyType, RegressionConfig};
 use crate::constants::{KEY_ID_PATH};
 use crate::db::Db;
+use crate::error::Result;
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext};
@@ -21,12 +22,12 @@ pub struct Ci<'a> {
 }
 
 impl<'a> Ci<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new ci object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Ci, &mut path, label, pop);
-        db.add_object_property(&o, &base_cfg, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Ci, &mut path, label, pop)?;
+        db.add_object_property(&o, &base_cfg, PropertyType::Base)?;
 
-        Box::new(Ci {
+        Ok(Box::new(Ci {
             object: Object {
                 db,
                 id: o.id,
@@ -34,7 +35,7 @@ impl<'a> Ci<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
     pub fn load(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn CiExt<'a> + 'a)> {
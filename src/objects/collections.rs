@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::{EdgeTypes, PropertyType, RegressionConfig, RenderContext};
 use crate::constants::{KEY_APPLICATION, KEY_DEPLOY, KEY_DESTROY, KEY_ID_PATH, KEY_NAME, KEY_REPORT};
 use crate::db::Db;
+use crate::error::Result;
 use crate::objects::application::ApplicationExt;
 use crate::objects::feature::FeatureExt;
 use crate::objects::rte::RteExt;
@@ -57,12 +58,12 @@ pub struct Reports<'a> {
 }
 
 impl<'a> Collectors<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new collectors collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Collectors, &mut path, label, pop);
-        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Collectors, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base)?;
 
-        Box::new(Collectors {
+        Ok(Box::new(Collectors {
             object: Object {
                 db,
                 id: o.id,
@@ -70,20 +71,20 @@ impl<'a> Collectors<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn CollectorExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn CollectorExt<'a> + 'a)>>> {
         error!("Loading collector objects");
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasCollectors).unwrap();
         let mut collectors: Vec<Box<(dyn CollectorExt + 'a)>> = Vec::new();
         let _collectors = db.get_object_neighbours_with_properties_out(&o.vertex.id, EdgeTypes::ProvidesCollector);
 
         for app in _collectors {
-            collectors.push(Collector::load(db, &app, config));
+            collectors.push(Collector::load(db, &app, config)?);
         }
 
-        collectors
+        Ok(collectors)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -105,7 +106,7 @@ impl<'a> Collectors<'a> {
         })
     }
 
-    pub fn load_collector(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn CollectorExt<'a> + 'a)>> {
+    pub fn load_collector(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn CollectorExt<'a> + 'a)>>> {
         error!("Loading specific collector object");
         let collectors = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::ProvidesCollector);
 
@@ -113,33 +114,33 @@ impl<'a> Collectors<'a> {
             let c = collector.props.get(PropertyType::Base.index()).unwrap().value.as_object()
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
             if name == c {
-                return Some(Collector::load(db, &collector, config));
+                return Ok(Some(Collector::load(db, &collector, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 
-    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<dyn RenderContext>> {
+    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<dyn RenderContext>>> {
         let mut collectors_rc: Vec<Box<dyn RenderContext>> = Vec::new();
-        let collectors = Self::load(db, object, config);
+        let collectors = Self::load(db, object, config)?;
 
         for c in collectors {
-            let collector_rc = c.gen_render_ctx(config, c.gen_script_render_ctx(config));
+            let collector_rc = c.gen_render_ctx(config, c.gen_script_render_ctx(config)?)?;
             collectors_rc.push(collector_rc);
         }
 
-        collectors_rc
+        Ok(collectors_rc)
     }
 }
 
 impl<'a> Components<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new components collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Components, &mut path, label, pop);
-        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Components, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base)?;
 
-        Box::new(Components {
+        Ok(Box::new(Components {
             object: Object {
                 db,
                 id: o.id,
@@ -147,30 +148,30 @@ impl<'a> Components<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load_source_component(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Option<Box<(dyn ComponentSourceExt<'a> + 'a)>> {
+    pub fn load_source_component(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Option<Box<(dyn ComponentSourceExt<'a> + 'a)>>> {
         error!("Loading specific source component object");
         let src_component = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasComponentSrc);
 
         match src_component {
-            None => None,
+            None => Ok(None),
             Some(c) => {
-                Some(ComponentSource::load(db, &c, config))
+                Ok(Some(ComponentSource::load(db, &c, config)?))
             }
         }
     }
 
-    pub fn load_destination_component(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Option<Box<(dyn ComponentDestinationExt<'a> + 'a)>> {
+    pub fn load_destination_component(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Option<Box<(dyn ComponentDestinationExt<'a> + 'a)>>> {
         error!("Loading specific destination component object");
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasComponents).unwrap();
         let dst_component = db.get_object_neighbour_with_properties_out(&o.vertex.id, EdgeTypes::HasComponentDst);
 
         match dst_component {
-            None => None,
+            None => Ok(None),
             Some(c) => {
-                Some(ComponentDestination::load(db, &c, config))
+                Ok(Some(ComponentDestination::load(db, &c, config)?))
             }
         }
     }
@@ -196,12 +197,12 @@ impl<'a> Components<'a> {
 }
 
 impl<'a> Connections<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new connections collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Connections, &mut path, label, pop);
-        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Connections, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base)?;
 
-        Box::new(Connections {
+        Ok(Box::new(Connections {
             object: Object {
                 db,
                 id: o.id,
@@ -209,19 +210,19 @@ impl<'a> Connections<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn ConnectionExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn ConnectionExt<'a> + 'a)>>> {
         error!("Loading connection objects");
         let mut connections: Vec<Box<(dyn ConnectionExt + 'a)>> = Vec::new();
         let _connections = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::HasConnection);
 
         for c in _connections {
-            connections.push(Connection::load(db, &c, config));
+            connections.push(Connection::load(db, &c, config)?);
         }
 
-        connections
+        Ok(connections)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -243,7 +244,7 @@ impl<'a> Connections<'a> {
         })
     }
 
-    pub fn load_connection(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn ConnectionExt<'a> + 'a)>> {
+    pub fn load_connection(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn ConnectionExt<'a> + 'a)>>> {
         error!("Loading specific rte connection object");
         let connections = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::HasConnection);
 
@@ -251,21 +252,21 @@ impl<'a> Connections<'a> {
             let r = connection.props.get(PropertyType::Base.index()).unwrap().value.as_object()
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
             if name == r {
-                return Some(Connection::load(db, &connection, config));
+                return Ok(Some(Connection::load(db, &connection, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 }
 
 impl<'a> Features<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut features collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Features, &mut path, label, pop);
-        db.add_object_property(&o, &config.features, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Features, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.features, PropertyType::Base)?;
 
-        Box::new(Features {
+        Ok(Box::new(Features {
             object: Object {
                 db,
                 id: o.id,
@@ -273,20 +274,20 @@ impl<'a> Features<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn FeatureExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn FeatureExt<'a> + 'a)>>> {
         error!("Loading eut feature objects");
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasFeatures).unwrap();
         let mut features: Vec<Box<(dyn FeatureExt + 'a)>> = Vec::new();
         let _features = db.get_object_neighbours_with_properties_out(&o.vertex.id, EdgeTypes::HasFeature);
 
         for feature in _features {
-            features.push(Feature::load(db, &feature, config));
+            features.push(Feature::load(db, &feature, config)?);
         }
 
-        features
+        Ok(features)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -308,7 +309,7 @@ impl<'a> Features<'a> {
         })
     }
 
-    pub fn load_feature(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn FeatureExt<'a> + 'a)>> {
+    pub fn load_feature(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn FeatureExt<'a> + 'a)>>> {
         error!("Loading specific eut feature object");
         let features = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::HasFeature);
 
@@ -316,33 +317,33 @@ impl<'a> Features<'a> {
             let r = feature.props.get(PropertyType::Module.index()).unwrap().value.as_object()
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
             if name == r {
-                return Some(Feature::load(db, &feature, config));
+                return Ok(Some(Feature::load(db, &feature, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 
-    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<dyn RenderContext>> {
+    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<dyn RenderContext>>> {
         let mut features_rc: Vec<Box<dyn RenderContext>> = Vec::new();
-        let features = Self::load(db, object, config);
+        let features = Self::load(db, object, config)?;
 
         for f in features {
-            let feature_rc = f.gen_render_ctx(config, f.gen_script_render_ctx(config));
+            let feature_rc = f.gen_render_ctx(config, f.gen_script_render_ctx(config)?)?;
             features_rc.push(feature_rc);
         }
 
-        features_rc
+        Ok(features_rc)
     }
 }
 
 impl<'a> Providers<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new providers collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Providers, &mut path, label, pop);
-        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Providers, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({"": ""}), PropertyType::Base)?;
 
-        Box::new(Providers {
+        Ok(Box::new(Providers {
             object: Object {
                 db,
                 id: o.id,
@@ -350,17 +351,17 @@ impl<'a> Providers<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 }
 
 impl<'a> Rtes<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new rtes collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Rtes, &mut path, label, pop);
-        db.add_object_property(&o, &config.rte, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Rtes, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.rte, PropertyType::Base)?;
 
-        Box::new(Rtes {
+        Ok(Box::new(Rtes {
             object: Object {
                 db,
                 id: o.id,
@@ -368,19 +369,19 @@ impl<'a> Rtes<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn RteExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn RteExt<'a> + 'a)>>> {
         error!("Loading eut rte objects");
         let mut rtes: Vec<Box<(dyn RteExt + 'a)>> = Vec::new();
         let _rtes = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::ProvidesRte);
 
         for r in _rtes {
-            rtes.push(Rte::load(db, &r, config));
+            rtes.push(Rte::load(db, &r, config)?);
         }
 
-        rtes
+        Ok(rtes)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -402,7 +403,7 @@ impl<'a> Rtes<'a> {
         })
     }
 
-    pub fn load_rte(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn RteExt<'a> + 'a)>> {
+    pub fn load_rte(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn RteExt<'a> + 'a)>>> {
         error!("Loading specific eut rte object");
         let rtes = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::ProvidesRte);
 
@@ -411,33 +412,33 @@ impl<'a> Rtes<'a> {
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
 
             if name == r {
-                return Some(Rte::load(db, &rte, config));
+                return Ok(Some(Rte::load(db, &rte, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 
-    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<dyn RenderContext>> {
+    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<dyn RenderContext>>> {
         let mut rtes_rc: Vec<Box<dyn RenderContext>> = Vec::new();
-        let rtes = Self::load(db, object, config);
+        let rtes = Self::load(db, object, config)?;
 
         for r in rtes {
-            let feature_rc = r.gen_render_ctx(config, r.gen_script_render_ctx(config));
+            let feature_rc = r.gen_render_ctx(config, r.gen_script_render_ctx(config)?)?;
             rtes_rc.push(feature_rc);
         }
 
-        rtes_rc
+        Ok(rtes_rc)
     }
 }
 
 impl<'a> Sites<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut sites collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Sites, &mut path, label, pop);
-        db.add_object_property(&o, &config, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Sites, &mut path, label, pop)?;
+        db.add_object_property(&o, &config, PropertyType::Base)?;
 
-        Box::new(Sites {
+        Ok(Box::new(Sites {
             object: Object {
                 db,
                 id: o.id,
@@ -445,7 +446,7 @@ impl<'a> Sites<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -467,7 +468,7 @@ impl<'a> Sites<'a> {
         })
     }
 
-    pub fn load_site(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn SiteExt<'a> + 'a)>> {
+    pub fn load_site(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn SiteExt<'a> + 'a)>>> {
         error!("Loading specific eut site object");
         let sites = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::HasSite);
 
@@ -476,21 +477,21 @@ impl<'a> Sites<'a> {
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
 
             if name == r {
-                return Some(Site::load(db, &site, config));
+                return Ok(Some(Site::load(db, &site, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 }
 
 impl<'a> Applications<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut applications collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Applications, &mut path, label, pop);
-        db.add_object_property(&o, &config.applications, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Applications, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.applications, PropertyType::Base)?;
 
-        Box::new(Applications {
+        Ok(Box::new(Applications {
             object: Object {
                 db,
                 id: o.id,
@@ -498,20 +499,20 @@ impl<'a> Applications<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn ApplicationExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn ApplicationExt<'a> + 'a)>>> {
         error!("Loading eut application objects");
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasApplications).unwrap();
         let mut applications: Vec<Box<(dyn ApplicationExt + 'a)>> = Vec::new();
         let _applications = db.get_object_neighbours_with_properties_out(&o.vertex.id, EdgeTypes::ProvidesApplication);
 
         for app in _applications {
-            applications.push(Application::load(db, &app, config));
+            applications.push(Application::load(db, &app, config)?);
         }
 
-        applications
+        Ok(applications)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -533,24 +534,24 @@ impl<'a> Applications<'a> {
         })
     }
 
-    pub fn load_application(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn ApplicationExt<'a> + 'a)>> {
+    pub fn load_application(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn ApplicationExt<'a> + 'a)>>> {
         error!("Loading specific eut application object");
         let applications = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::ProvidesApplication);
         for app in applications {
             let a = app.props.get(PropertyType::Base.index()).unwrap().value.as_object()
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
             if name == a {
-                return Some(Application::load(db, &app, config));
+                return Ok(Some(Application::load(db, &app, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 
-    pub fn gen_deploy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<String> {
+    pub fn gen_deploy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<String>> {
         error!("Generating eut application deploy stages");
         let mut stages: Vec<String> = Vec::new();
-        let applications = Self::load(db, object, config);
+        let applications = Self::load(db, object, config)?;
 
         for a in applications {
             let name = format!("{}-{}-{}",
@@ -561,13 +562,13 @@ impl<'a> Applications<'a> {
             stages.push(name);
         }
 
-        stages
+        Ok(stages)
     }
 
-    pub fn gen_destroy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<String> {
+    pub fn gen_destroy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<String>> {
         error!("Generating eut application destroy stages");
         let mut stages: Vec<String> = Vec::new();
-        let applications = Self::load(db, object, config);
+        let applications = Self::load(db, object, config)?;
 
         for a in applications {
             let name = format!("{}-{}-{}",
@@ -578,31 +579,31 @@ impl<'a> Applications<'a> {
             stages.push(name);
         }
 
-        stages
+        Ok(stages)
     }
 
-    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<dyn RenderContext>> {
+    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<dyn RenderContext>>> {
         let mut applications_rc: Vec<Box<dyn RenderContext>> = Vec::new();
-        let applications = Self::load(db, object, config);
+        let applications = Self::load(db, object, config)?;
 
         for a in applications {
 
-            let scripts = a.gen_script_render_ctx(config);
-            let application_rc = a.gen_render_ctx(config, scripts.clone());
+            let scripts = a.gen_script_render_ctx(config)?;
+            let application_rc = a.gen_render_ctx(config, scripts.clone())?;
             applications_rc.push(application_rc);
         }
 
-        applications_rc
+        Ok(applications_rc)
     }
 }
 
 impl<'a> Reports<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new reports collection object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Reports, &mut path, label, pop);
-        db.add_object_property(&o, &config.reports, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Reports, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.reports, PropertyType::Base)?;
 
-        Box::new(Reports {
+        Ok(Box::new(Reports {
             object: Object {
                 db,
                 id: o.id,
@@ -610,20 +611,20 @@ impl<'a> Reports<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<(dyn ReportExt<'a> + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<(dyn ReportExt<'a> + 'a)>>> {
         error!("Loading report objects");
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::HasReports).unwrap();
         let mut reports: Vec<Box<(dyn ReportExt + 'a)>> = Vec::new();
         let _reports = db.get_object_neighbours_with_properties_out(&o.vertex.id, EdgeTypes::ProvidesReport);
 
         for report in _reports {
-            reports.push(Report::load(db, &report, config));
+            reports.push(Report::load(db, &report, config)?);
         }
 
-        reports
+        Ok(reports)
     }
 
     pub fn load_collection(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Box<(dyn ObjectExt + 'a)> {
@@ -645,24 +646,24 @@ impl<'a> Reports<'a> {
         })
     }
 
-    pub fn load_report(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Option<Box<(dyn ReportExt<'a> + 'a)>> {
+    pub fn load_report(db: &'a Db, object: &Vertex, name: &str, config: &RegressionConfig) -> Result<Option<Box<(dyn ReportExt<'a> + 'a)>>> {
         error!("Loading specific report object");
         let reports = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::ProvidesReport);
         for report in reports {
             let a = report.props.get(PropertyType::Module.index()).unwrap().value.as_object()
                 .unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
             if name == a {
-                return Some(Report::load(db, &report, config));
+                return Ok(Some(Report::load(db, &report, config)?));
             }
         }
 
-        None
+        Ok(None)
     }
 
-    pub fn gen_deploy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<String> {
+    pub fn gen_deploy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<String>> {
         error!("Generating report deploy stages");
         let mut stages: Vec<String> = Vec::new();
-        let reports = Self::load(db, object, config);
+        let reports = Self::load(db, object, config)?;
 
         for r in reports {
             let name = format!("{}-{}-{}",
@@ -673,13 +674,13 @@ impl<'a> Reports<'a> {
             stages.push(name);
         }
 
-        stages
+        Ok(stages)
     }
 
-    pub fn gen_destroy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<String> {
+    pub fn gen_destroy_stage(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<String>> {
         error!("Generating report destroy stages");
         let mut stages: Vec<String> = Vec::new();
-        let reports = Self::load(db, object, config);
+        let reports = Self::load(db, object, config)?;
 
         for r in reports {
             let name = format!("{}-{}-{}",
@@ -690,20 +691,20 @@ impl<'a> Reports<'a> {
             stages.push(name);
         }
 
-        stages
+        Ok(stages)
     }
 
-    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Vec<Box<dyn RenderContext>> {
+    pub fn gen_render_ctx(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Vec<Box<dyn RenderContext>>> {
         let mut reports_rc: Vec<Box<dyn RenderContext>> = Vec::new();
-        let reports = Self::load(db, object, config);
+        let reports = Self::load(db, object, config)?;
 
         for r in reports {
-            let scripts = r.gen_script_render_ctx(config);
-            let report_rc = r.gen_render_ctx(config, scripts.clone());
+            let scripts = r.gen_script_render_ctx(config)?;
+            let report_rc = r.gen_render_ctx(config, scripts.clone())?;
             reports_rc.push(report_rc);
         }
 
-        reports_rc
+        Ok(reports_rc)
     }
 }
 
@@ -10,6 +10,7 @@ use crate::{CollectorRenderContext, PropertyType, RegressionConfig, render_scrip
 use crate::constants::{KEY_ARTIFACTS_PATH, KEY_COLLECTOR, KEY_DATA, KEY_FILE, KEY_ID_PATH, KEY_MODULE,
                        KEY_NAME, KEY_REF_ARTIFACTS_PATH, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config};
@@ -25,23 +26,23 @@ pub struct Collector<'a> {
 }
 
 impl<'a> Collector<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new collector object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Collector, &mut path, label, pop);
-        let collector_name = base_cfg.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
-        let collector_module = base_cfg.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Collector, &mut path, label, pop)?;
+        let collector_name = base_cfg.get_str_value(KEY_NAME, &id_path.get_str())?.to_string();
+        let collector_module = base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?.to_string();
         let artifacts_path = format!("{}/{}/{}/{}", config.collectors.artifacts_dir,
                                      collector_module,
                                      collector_name,
                                      config.collectors.artifacts_file);
         let mut _base_cfg = base_cfg.as_object().unwrap().clone();
         _base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base);
-        let module_name = base_cfg.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), module_name, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base)?;
+        let module_name = base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), module_name, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Collector {
+        Ok(Box::new(Collector {
             object: Object {
                 db,
                 id: o.id,
@@ -49,19 +50,19 @@ impl<'a> Collector<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Box<(dyn CollectorExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Result<Box<(dyn CollectorExt<'a> + 'a)>> {
         error!("Loading collector object");
         let arr = object.props.get(PropertyType::Base.index()).
             unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = object.props.get(PropertyType::Base.index()).
             unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config)?;
 
-        Box::new(Collector {
+        Ok(Box::new(Collector {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -69,7 +70,7 @@ impl<'a> Collector<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
@@ -88,52 +89,54 @@ impl RenderContext for Collector<'_> {
 }
 
 impl Renderer<'_> for Collector<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        Box::new(CollectorRenderContext {
-            job: format!("{}_{}_{}", config.project.module, KEY_COLLECTOR,
-                         self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-"),
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
+        Ok(Box::new(CollectorRenderContext {
+            job: format!("{}_{}_{}", config.job_prefix(), KEY_COLLECTOR,
+                         self.get_base_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-"),
             eut: config.eut.module.to_string(),
             base: self.get_base_properties(),
             module: self.get_module_properties(),
             project: config.project.clone(),
             scripts: scripts.clone(),
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let props_base: Map<String, Value> = self.get_base_properties();
         let props_module: Map<String, Value> = self.get_module_properties();
-        let scripts_path = props_module.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = props_module.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in props_module.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        for script in props_module.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}", config.root_path,
                                config.collectors.path, module,
-                               scripts_path, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening collector script file");
+                               scripts_path, script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptCollectorRenderContext {
                 eut: config.eut.module.to_string(),
-                name: self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                data: props_base.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                refs: props_base.get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                name: self.get_base_properties().get_str_value(KEY_NAME, id_path)?.to_string(),
+                data: props_base.get_str_value(KEY_DATA, id_path)?.to_string(),
+                refs: props_base.get_object_value(KEY_REF_ARTIFACTS_PATH, id_path)?.clone(),
                 module: module.to_string(),
                 project: config.project.clone(),
-                artifacts_path: props_base.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string()
+                artifacts_path: props_base.get_str_value(KEY_ARTIFACTS_PATH, id_path)?.to_string()
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
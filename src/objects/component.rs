@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::{PropertyType, RegressionConfig};
 use crate::constants::{KEY_ID_PATH};
 use crate::db::Db;
+use crate::error::Result;
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext};
@@ -29,12 +30,12 @@ pub struct ComponentDestination<'a> {
 }
 
 impl<'a> ComponentSource<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new component source object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::ComponentSrc, &mut path, label, pop);
-        db.add_object_property(&o, &base_cfg, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::ComponentSrc, &mut path, label, pop)?;
+        db.add_object_property(&o, &base_cfg, PropertyType::Base)?;
 
-        Box::new(ComponentSource {
+        Ok(Box::new(ComponentSource {
             object: Object {
                 db,
                 id: o.id,
@@ -42,15 +43,15 @@ impl<'a> ComponentSource<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Box<(dyn ComponentSourceExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Result<Box<(dyn ComponentSourceExt<'a> + 'a)>> {
         error!("Loading component source object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
 
-        Box::new(ComponentSource {
+        Ok(Box::new(ComponentSource {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -58,7 +59,7 @@ impl<'a> ComponentSource<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 }
 
@@ -66,12 +67,12 @@ impl<'a> ComponentSource<'a> {
 impl ComponentSourceExt<'_> for ComponentSource<'_> {}
 
 impl<'a> ComponentDestination<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new component destination object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::ComponentDst, &mut path, label, pop);
-        db.add_object_property(&o, &base_cfg, PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::ComponentDst, &mut path, label, pop)?;
+        db.add_object_property(&o, &base_cfg, PropertyType::Base)?;
 
-        Box::new(ComponentDestination {
+        Ok(Box::new(ComponentDestination {
             object: Object {
                 db,
                 id: o.id,
@@ -79,15 +80,15 @@ impl<'a> ComponentDestination<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Box<(dyn ComponentDestinationExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Result<Box<(dyn ComponentDestinationExt<'a> + 'a)>> {
         error!("Loading component destination object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
 
-        Box::new(ComponentDestination {
+        Ok(Box::new(ComponentDestination {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -95,7 +96,7 @@ impl<'a> ComponentDestination<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 }
 
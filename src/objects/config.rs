@@ -0,0 +1,316 @@
+/*!
+Typed module configurations.
+
+Module configuration files are stored as untyped JSON properties in the object graph. The structs
+below describe the keys the objects rely on. Each loaded module configuration is checked against
+its struct so missing or wrongly typed keys are reported at load time with module name and file
+path. Keys not described here are kept and passed on to the templates untouched.
+ */
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use schemars::schema::RootSchema;
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::constants::*;
+use crate::error::{Result, Sense8Error};
+use crate::RegressionConfig;
+
+/// Schema kind of the regression configuration file itself.
+pub const SCHEMA_KIND_REGRESSION: &str = "regression";
+
+/// Kinds `sense8 schema` is able to emit a JSON Schema for.
+pub const SCHEMA_KINDS: &[&str] = &[SCHEMA_KIND_REGRESSION, KEY_EUT, KEY_RTE, KEY_TEST, KEY_VERIFICATION,
+    KEY_COLLECTOR, KEY_REPORT, KEY_FEATURE, KEY_APPLICATION, KEY_DASHBOARD];
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct ScriptConfig {
+    /// Script file name relative to scripts_path
+    pub file: String,
+    /// CI script section the rendered commands are assigned to
+    pub script: String,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RefConfig {
+    /// Referenced object type
+    pub r#type: String,
+    /// Referenced object name
+    pub name: String,
+    /// Rte providing referenced test
+    pub rte: Option<String>,
+    /// Connection providing referenced test
+    pub connection: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutSiteConfig {
+    /// Number of site instances
+    pub count: i64,
+    /// EUT provider hosting this site
+    pub provider: String,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutFeatureConfig {
+    pub name: String,
+    /// Feature module below features path
+    pub module: String,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutCollectorConfig {
+    pub name: String,
+    /// Collector module below collectors path
+    pub module: String,
+    pub refs: Vec<RefConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutReportConfig {
+    pub name: String,
+    /// Report module below reports path
+    pub module: String,
+    pub refs: Vec<RefConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutApplicationConfig {
+    pub name: String,
+    /// Application module below applications path
+    pub module: String,
+    /// EUT provider this application is deployed to
+    pub provider: String,
+    pub refs: Vec<RefConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteVerificationConfig {
+    /// Verification module below verifications path
+    pub module: String,
+    pub data: String,
+    pub ci: Map<String, Value>,
+    /// Tags selecting this verification with --include-tags / --exclude-tags. Verification
+    /// without tags follows its test
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct DependsOnConfig {
+    /// Upstream test name
+    pub name: String,
+    /// Rte providing upstream test. Defaults to rte of dependent test
+    pub rte: Option<String>,
+    /// Connection providing upstream test. Defaults to connection of dependent test
+    pub connection: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteTestConfig {
+    pub name: String,
+    /// Test module below tests path
+    pub module: String,
+    pub ci: Map<String, Value>,
+    pub refs: Vec<RefConfig>,
+    /// Tags selecting this test with --include-tags / --exclude-tags, e.g. smoke, nightly, perf
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Tests to finish before this test starts. Their artifacts paths are passed on as test refs
+    #[serde(default)]
+    pub depends_on: Vec<DependsOnConfig>,
+    #[serde(default)]
+    pub verifications: Vec<RteVerificationConfig>,
+}
+
+/// How tests of an rte or connection are placed into stages.
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionStrategy {
+    /// One stage per test and verification chained one after another
+    #[default]
+    Sequential,
+    /// One shared test stage. Ordering between tests is expressed with job needs
+    Parallel,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug, Default)]
+pub struct ExecutionConfig {
+    #[serde(default)]
+    pub strategy: ExecutionStrategy,
+    /// Maximum number of parallel tests running at the same time. Unlimited if not set
+    pub max_parallel: Option<usize>,
+    /// Run parallel tests referring to the same site one after another
+    #[serde(default)]
+    pub serialize_sites: bool,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteConnectionConfig {
+    pub name: String,
+    /// Source site name
+    pub source: String,
+    /// Destination site name regular expressions
+    pub destinations: Vec<String>,
+    pub tests: Vec<RteTestConfig>,
+    /// Test execution of this connection. Overrides rte execution
+    pub execution: Option<ExecutionConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutRteConfig {
+    pub name: String,
+    /// Rte module below rte path
+    pub module: String,
+    pub provider: String,
+    #[serde(default)]
+    pub connections: Vec<RteConnectionConfig>,
+    /// Test execution of all connections. Sequential if not set
+    pub execution: Option<ExecutionConfig>,
+}
+
+/// EUT module configuration. All keys of `EUT_KEY_ORDER` are mandatory.
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct EutModuleConfig {
+    pub ci: Map<String, Value>,
+    pub provider: Vec<String>,
+    pub sites: BTreeMap<String, EutSiteConfig>,
+    pub features: Vec<EutFeatureConfig>,
+    pub collectors: Vec<EutCollectorConfig>,
+    pub reports: Vec<EutReportConfig>,
+    pub name: String,
+    pub release: String,
+    pub rtes: Vec<EutRteConfig>,
+    pub applications: Vec<EutApplicationConfig>,
+    pub scripts: Vec<ScriptConfig>,
+    pub scripts_path: String,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteComponentConfig {
+    pub name: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteComponentsConfig {
+    pub src: RteComponentConfig,
+    /// Destination component. Mandatory for rte types with client / server layout, e.g. rte type a
+    pub dst: Option<RteComponentConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct RteModuleConfig {
+    /// Registered rte type implementation, e.g. rte_type_a, rte_type_b or rte_type_mesh
+    pub r#type: String,
+    pub components: RteComponentsConfig,
+    pub ci: Option<Map<String, Value>>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct TestModuleConfig {
+    pub name: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct VerificationModuleConfig {
+    pub name: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct CollectorModuleConfig {
+    pub name: Option<String>,
+    pub module: Option<String>,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct ReportModuleConfig {
+    pub name: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct FeatureModuleConfig {
+    pub name: String,
+    pub release: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct ApplicationModuleConfig {
+    pub name: String,
+    pub release: String,
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct DashboardProviderConfig {
+    pub scripts_path: String,
+    pub scripts: Vec<ScriptConfig>,
+}
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug)]
+pub struct DashboardModuleConfig {
+    /// Dashboard provider name to provider configuration map
+    #[serde(default)]
+    pub provider: BTreeMap<String, DashboardProviderConfig>,
+}
+
+/// Check module configuration `cfg` loaded from `path` against typed struct of module kind `_type`.
+/// Module kinds without typed struct are accepted as is.
+pub(crate) fn check_module_config(_type: &str, module: &str, path: &str, cfg: &Value) -> Result<()> {
+    match _type {
+        KEY_EUT => parse::<EutModuleConfig>(_type, module, path, cfg),
+        KEY_RTE => parse::<RteModuleConfig>(_type, module, path, cfg),
+        KEY_TEST => parse::<TestModuleConfig>(_type, module, path, cfg),
+        KEY_VERIFICATION => parse::<VerificationModuleConfig>(_type, module, path, cfg),
+        KEY_COLLECTOR => parse::<CollectorModuleConfig>(_type, module, path, cfg),
+        KEY_REPORT => parse::<ReportModuleConfig>(_type, module, path, cfg),
+        KEY_FEATURE => parse::<FeatureModuleConfig>(_type, module, path, cfg),
+        KEY_APPLICATION => parse::<ApplicationModuleConfig>(_type, module, path, cfg),
+        KEY_DASHBOARD => parse::<DashboardModuleConfig>(_type, module, path, cfg),
+        _ => Ok(()),
+    }
+}
+
+fn parse<T: DeserializeOwned>(_type: &str, module: &str, path: &str, cfg: &Value) -> Result<()> {
+    serde_json::from_value::<T>(cfg.clone()).map_err(|e| Sense8Error::ModuleConfigInvalid {
+        kind: _type.to_string(),
+        module: module.to_string(),
+        path: path.to_string(),
+        msg: e.to_string(),
+    })?;
+    Ok(())
+}
+
+/// JSON Schema of configuration kind. See [`SCHEMA_KINDS`] for supported kinds.
+pub fn schema(kind: &str) -> Option<RootSchema> {
+    let schema = match kind {
+        SCHEMA_KIND_REGRESSION => schemars::schema_for!(RegressionConfig),
+        KEY_EUT => schemars::schema_for!(EutModuleConfig),
+        KEY_RTE => schemars::schema_for!(RteModuleConfig),
+        KEY_TEST => schemars::schema_for!(TestModuleConfig),
+        KEY_VERIFICATION => schemars::schema_for!(VerificationModuleConfig),
+        KEY_COLLECTOR => schemars::schema_for!(CollectorModuleConfig),
+        KEY_REPORT => schemars::schema_for!(ReportModuleConfig),
+        KEY_FEATURE => schemars::schema_for!(FeatureModuleConfig),
+        KEY_APPLICATION => schemars::schema_for!(ApplicationModuleConfig),
+        KEY_DASHBOARD => schemars::schema_for!(DashboardModuleConfig),
+        _ => return None,
+    };
+
+    Some(schema)
+}
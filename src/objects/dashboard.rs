@@ -9,6 +9,7 @@ use uuid::Uuid;
 use crate::{DashboardRenderContext, EdgeTypes, PropertyType, RegressionConfig, render_script, RenderContext, Renderer, ScriptDashboardRenderContext};
 use crate::constants::{KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_PROVIDER, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 use crate::objects::provider::DashboardProvider;
 
@@ -25,11 +26,11 @@ pub struct Dashboard<'a> {
 }
 
 impl<'a> Dashboard<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new dashboard object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Dashboard, &mut path, label, pop);
-        db.add_object_property(&o, &config.dashboard, PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.dashboard.module, &config);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Dashboard, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.dashboard, PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.dashboard.module, &config)?;
 
         let dashboard = Box::new(Dashboard {
             object: Object {
@@ -49,8 +50,8 @@ impl<'a> Dashboard<'a> {
                         let mut cfg = q.as_object().unwrap().clone();
 
                         cfg.insert(KEY_NAME.to_string(), json!(p));
-                        let provider = DashboardProvider::init(db, &to_value(cfg).unwrap(), path, p, i);
-                        db.create_relationship(&dashboard.get_object(), &provider.get_object());
+                        let provider = DashboardProvider::init(db, &to_value(cfg).unwrap(), path, p, i)?;
+                        db.create_relationship(&dashboard.get_object(), &provider.get_object())?;
                         i = i + 1;
                     }
                 }
@@ -61,15 +62,15 @@ impl<'a> Dashboard<'a> {
             }
         }
 
-        dashboard
+        Ok(dashboard)
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Box<(dyn DashboardExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &Vertex, config: &RegressionConfig) -> Result<Box<(dyn DashboardExt<'a> + 'a)>> {
         let o = db.get_object_neighbour_with_properties_out(&object.id, EdgeTypes::Has).unwrap();
         let arr = o.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = o.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config)?;
 
         let dashboard = Box::new(Dashboard {
             object: Object {
@@ -81,7 +82,7 @@ impl<'a> Dashboard<'a> {
             },
         });
 
-        dashboard
+        Ok(dashboard)
     }
 }
 
@@ -100,9 +101,10 @@ impl RenderContext for Dashboard<'_> {
 }
 
 impl Renderer<'_> for Dashboard<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        let p_name = self.get_base_properties().get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
-        let dashboard_provider = DashboardProvider::load(&self.object.db, &self.get_object(), config);
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
+        let p_name = self.get_base_properties().get_str_value(KEY_PROVIDER, id_path)?.to_string();
+        let dashboard_provider = DashboardProvider::load(&self.object.db, &self.get_object(), config)?;
         let mut ctx: Box<DashboardRenderContext> = Box::new(DashboardRenderContext {
             base: Default::default(),
             module: Default::default(),
@@ -114,7 +116,7 @@ impl Renderer<'_> for Dashboard<'_> {
         for p in dashboard_provider {
             let m_props = p.get_module_properties();
 
-            if p_name == m_props.get(KEY_NAME).unwrap().as_str().unwrap() {
+            if p_name == m_props.get_str_value(KEY_NAME, id_path)? {
                 ctx = Box::new(DashboardRenderContext {
                     base: self.get_base_properties(),
                     module: self.get_module_properties(),
@@ -124,23 +126,24 @@ impl Renderer<'_> for Dashboard<'_> {
                 });
             }
         }
-        ctx
+        Ok(ctx)
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let dashboard_provider = DashboardProvider::load(&self.object.db, &self.get_object(), config);
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let p_name = self.get_base_properties().get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
+        let dashboard_provider = DashboardProvider::load(&self.object.db, &self.get_object(), config)?;
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
+        let p_name = self.get_base_properties().get_str_value(KEY_PROVIDER, id_path)?.to_string();
 
         for provider in dashboard_provider {
             let m_props = provider.get_module_properties();
-            let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+            let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-            if m_props.get(KEY_NAME).unwrap().as_str().unwrap() == p_name {
-                for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
-                    let path = format!("{}/{}/{}/{}/{}/{}", config.root_path, config.dashboard.path, module, scripts_path, p_name, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                    let contents = std::fs::read_to_string(path).expect("panic while opening dashboard script file");
+            if m_props.get_str_value(KEY_NAME, id_path)? == p_name {
+                for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
+                    let path = format!("{}/{}/{}/{}/{}/{}", config.root_path, config.dashboard.path, module, scripts_path, p_name, script.get_str_value(KEY_FILE, id_path)?);
+                    let contents = read_file(&path)?;
                     let ctx = ScriptDashboardRenderContext {
                         name: p_name.to_string(),
                         module: module.to_string(),
@@ -148,18 +151,18 @@ impl Renderer<'_> for Dashboard<'_> {
                     };
 
                     let mut commands: Vec<String> = Vec::new();
-                    for command in render_script(&ctx, &contents).lines() {
+                    for command in render_script(&ctx, &contents, &path)?.lines() {
                         commands.push(format!("{:indent$}{}", "", command, indent = 0));
                     }
 
                     let data: HashMap<String, Vec<String>> = [
-                        (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                        (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
                     ].into_iter().collect();
                     scripts.push(data);
                 }
             }
         }
-        scripts
+        Ok(scripts)
     }
 }
 
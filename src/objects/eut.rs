@@ -6,8 +6,9 @@ use serde_json::{json, Map, to_value, Value};
 use uuid::Uuid;
 
 use crate::{EdgeTypes, PropertyType, RegressionConfig, RenderContext, Renderer};
-use crate::constants::{KEY_ARTIFACTS_PATH, KEY_ID_PATH, KEY_MODULE};
+use crate::constants::{KEY_ARTIFACTS_PATH, KEY_ID_PATH, KEY_MODULE, KEY_NAME};
 use crate::db::Db;
+use crate::error::{Result, Sense8Error};
 use crate::objects::object::{Object, ObjectExt};
 use crate::objects::project::ProjectExt;
 
@@ -24,9 +25,9 @@ pub struct Eut<'a> {
 }
 
 impl<'a> Eut<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Eut, &mut path, label, pop);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Eut, &mut path, label, pop)?;
         let artifacts_path = format!("{}/{}/{}",
                                      config.eut.artifacts_dir,
                                      config.eut.module,
@@ -34,11 +35,12 @@ impl<'a> Eut<'a> {
         let _base_cfg = to_value(&config.eut).unwrap();
         let mut base_cfg = _base_cfg.as_object().unwrap().clone();
         base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &json!(base_cfg), PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.eut.module, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        base_cfg.insert(KEY_NAME.to_string(), json!(config.eut.name));
+        db.add_object_property(&o, &json!(base_cfg), PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.eut.module, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Eut {
+        Ok(Box::new(Eut {
             object: Object {
                 db,
                 id: o.id,
@@ -46,19 +48,27 @@ impl<'a> Eut<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &Box<(dyn ProjectExt + 'a)>, config: &RegressionConfig) -> Box<(dyn EutExt<'a> + 'a)> {
+    /// Load EUT of project `object` named like the EUT `config` is scoped to. Falls back to the
+    /// first EUT for graphs saved without EUT names.
+    pub fn load(db: &'a Db, object: &(dyn ProjectExt<'a> + 'a), config: &RegressionConfig) -> Result<Box<(dyn EutExt<'a> + 'a)>> {
         error!("Loading eut object");
-        let o = db.get_object_neighbour_with_properties_out(&object.get_id(), EdgeTypes::HasEut).unwrap();
+        let euts = db.get_object_neighbours_with_properties_out(&object.get_id(), EdgeTypes::HasEut);
+        let o = euts.iter()
+            .find(|e| e.props.get(PropertyType::Base.index())
+                .and_then(|p| p.value.get(KEY_NAME))
+                .and_then(|n| n.as_str()) == Some(config.eut.name.as_str()))
+            .or(euts.first())
+            .ok_or_else(|| Sense8Error::Db("project does not hold an eut object".to_string()))?;
         let p_base = o.props.get(PropertyType::Base.index()).unwrap();
         let arr = p_base.value.get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = p_base.value.get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config)?;
 
-        Box::new(Eut {
+        Ok(Box::new(Eut {
             object: Object {
                 db,
                 id: o.vertex.id,
@@ -66,16 +76,16 @@ impl<'a> Eut<'a> {
                 vertex: o.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
 impl Renderer<'_> for Eut<'_> {
-    fn gen_render_ctx(&self, _config: &RegressionConfig, _ctx: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
+    fn gen_render_ctx(&self, _config: &RegressionConfig, _ctx: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
         todo!()
     }
 
-    fn gen_script_render_ctx(&self, _config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, _config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
         todo!()
     }
 }
@@ -8,6 +8,7 @@ use uuid::Uuid;
 use crate::{EdgeTypes, FeatureRenderContext, PropertyType, RegressionConfig, render_script, RenderContext, Renderer, ScriptFeatureRenderContext};
 use crate::constants::{KEY_APPLICATIONS, KEY_ARTIFACTS_PATH, KEY_DATA, KEY_FEATURE, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_RELEASE, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config};
@@ -23,23 +24,23 @@ pub struct Feature<'a> {
 }
 
 impl<'a> Feature<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new feature object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Feature, &mut path, label, pop);
-        db.create_relationship(&parent, &o);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Feature, &mut path, label, pop)?;
+        db.create_relationship(&parent, &o)?;
         let features = db.get_object_neighbour_in_out_id(&o.id, EdgeTypes::HasFeature, VertexTypes::Features).unwrap();
         let eut = db.get_object_neighbour_in_out_id(&features.id, EdgeTypes::HasFeatures, VertexTypes::Eut).unwrap();
         let eut_p = db.get_object_properties(&eut).unwrap();
-        let eut_name = eut_p.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let f_name = base_cfg.get(KEY_NAME).unwrap().as_str().unwrap().to_string();
+        let eut_name = eut_p.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_MODULE, &id_path.get_str())?.to_string();
+        let f_name = base_cfg.get_str_value(KEY_NAME, &id_path.get_str())?.to_string();
         let artifacts_path = format!("{}/{}/{}/{}/{}", config.features.artifacts_dir, eut_name, KEY_APPLICATIONS.to_string(), f_name, config.features.artifacts_file);
         let mut _base_cfg = base_cfg.as_object().unwrap().clone();
         _base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Feature {
+        Ok(Box::new(Feature {
             object: Object {
                 db,
                 id: o.id,
@@ -47,19 +48,19 @@ impl<'a> Feature<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Box<(dyn FeatureExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Result<Box<(dyn FeatureExt<'a> + 'a)>> {
         error!("Loading feature object");
         let arr = object.props.get(PropertyType::Base.index()).
             unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = object.props.get(PropertyType::Base.index()).
             unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config)?;
 
-        Box::new(Feature {
+        Ok(Box::new(Feature {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -67,7 +68,7 @@ impl<'a> Feature<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
@@ -86,53 +87,55 @@ impl RenderContext for Feature<'_> {
 }
 
 impl Renderer<'_> for Feature<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        Box::new(FeatureRenderContext {
-            job: format!("{}_{}_{}", config.project.module, KEY_FEATURE,
-                         self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-"),
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
+        Ok(Box::new(FeatureRenderContext {
+            job: format!("{}_{}_{}", config.job_prefix(), KEY_FEATURE,
+                         self.get_base_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-"),
             eut: config.eut.module.to_string(),
             base: self.get_base_properties(),
             refs: Default::default(),
             module: self.get_module_properties(),
             project: config.project.clone(),
             scripts: scripts.clone(),
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let props_base: Map<String, Value> = self.get_base_properties();
         let props_module: Map<String, Value> = self.get_module_properties();
-        let scripts_path = props_module.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = props_module.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in props_module.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        for script in props_module.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}", config.root_path,
                                config.features.path,
                                module, scripts_path,
-                               script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening feature script file");
+                               script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptFeatureRenderContext {
                 eut: config.eut.module.to_string(),
                 name: module.to_string(),
-                data: props_base.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
+                data: props_base.get_str_value(KEY_DATA, id_path)?.to_string(),
                 module: module.to_string(),
-                release: props_module.get(KEY_RELEASE).unwrap().as_str().unwrap().to_string(),
+                release: props_module.get_str_value(KEY_RELEASE, id_path)?.to_string(),
                 project: config.project.clone(),
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
@@ -9,12 +9,12 @@ macro_rules! implement_object_ext {
                 fn get_base_properties(&self) -> Map<String, Value> { self.object.get_base_properties() }
                 fn get_module_properties(&self) -> Map<String, Value> { self.object.get_module_properties() }
                 fn get_object_with_properties(&self) -> VertexProperties { self.object.get_object_with_properties() }
-                fn add_base_properties(&self, value: Value) { self.object.add_base_properties(value) }
-                fn add_module_properties(&self, value: Value) { self.object.add_module_properties(value) }
-                fn insert_base_property(&self, key: String, value: Value) {
+                fn add_base_properties(&self, value: Value) -> $crate::error::Result<()> { self.object.add_base_properties(value) }
+                fn add_module_properties(&self, value: Value) -> $crate::error::Result<()> { self.object.add_module_properties(value) }
+                fn insert_base_property(&self, key: String, value: Value) -> $crate::error::Result<()> {
                     self.object.insert_base_property(key, value)
                 }
-                fn insert_module_property(&self, key: String, value: Value) {
+                fn insert_module_property(&self, key: String, value: Value) -> $crate::error::Result<()> {
                     self.object.insert_module_property(key, value)
                 }
             }
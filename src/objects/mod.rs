@@ -8,12 +8,14 @@ pub use collections::{Components, Connections, Features, Providers, Rtes, Sites,
 pub use dashboard::Dashboard;
 pub use eut::{Eut, EutExt};
 pub(crate) use macros::implement_object_ext;
-pub use project::Project;
+pub use project::{Project, ProjectExt};
 pub use provider::{EutProvider};
-pub use rte::Rte;
+pub use rte::{register_rte_type, Rte, RteCharacteristics, RteTypeFactory};
+pub(crate) use rte::check_rte_module;
 pub use site::Site;
 pub use application::Application;
 pub use test::Test;
+pub(crate) use test::dependency_refs;
 pub use connection::{Connection, ConnectionDestination, ConnectionSource};
 pub use feature::Feature;
 pub use verification::Verification;
@@ -22,8 +24,12 @@ pub use collector::Collector;
 pub use report::Report;
 
 use crate::constants::*;
+use crate::error::{read_file, Result};
+use crate::format;
+use crate::objects::config::check_module_config;
 use crate::RegressionConfig;
 
+pub mod config;
 mod project;
 mod ci;
 mod eut;
@@ -43,7 +49,7 @@ mod connection;
 mod component;
 mod report;
 
-fn load_object_config(_type: &str, module: &str, config: &RegressionConfig) -> Value {
+pub(crate) fn load_object_config(_type: &str, module: &str, config: &RegressionConfig) -> Result<Value> {
     info!("Loading module <{module}> configuration data...");
     let file: String;
 
@@ -51,7 +57,7 @@ fn load_object_config(_type: &str, module: &str, config: &RegressionConfig) -> V
         KEY_EUT => {
             match &config.eut.config {
                 None => {
-                    file = format!("{}/{}/{}/{}", config.root_path, config.eut.path, module, CONFIG_FILE_NAME);
+                    file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.eut.path, module));
                 }
                 Some(f) => {
                     file = format!("{}/{}/{}/{}", config.root_path, config.eut.path, module, f);
@@ -59,38 +65,40 @@ fn load_object_config(_type: &str, module: &str, config: &RegressionConfig) -> V
             }
         }
         KEY_RTE => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.rte.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.rte.path, module));
         }
         KEY_TEST => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.tests.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.tests.path, module));
         }
         KEY_FEATURE => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.features.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.features.path, module));
         }
         KEY_PROJECT => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.project.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.project.path, module));
         }
         KEY_REPORT => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.reports.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.reports.path, module));
         }
         KEY_COLLECTOR => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.collectors.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.collectors.path, module));
         }
         KEY_DASHBOARD => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.dashboard.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.dashboard.path, module));
         }
         KEY_APPLICATION => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.applications.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.applications.path, module));
         }
         KEY_VERIFICATION => {
-            file = format!("{}/{}/{}/{}", config.root_path, config.verifications.path, module, CONFIG_FILE_NAME);
+            file = format::module_config_file(&format!("{}/{}/{}", config.root_path, config.verifications.path, module));
         }
         _ => {
-            return Null;
+            return Ok(Null);
         }
     }
-    let raw = std::fs::read_to_string(String::from(&file)).unwrap();
-    let cfg: Value = serde_json::from_str(&raw).unwrap();
+    let raw = read_file(&file)?;
+    let mut cfg: Value = format::parse(&file, &raw)?;
+    config.overlay.apply_module(_type, module, &mut cfg);
+    check_module_config(_type, module, &file, &cfg)?;
     info!("Loading module <{module}> configuration data -> Done.");
-    cfg
+    Ok(cfg)
 }
\ No newline at end of file
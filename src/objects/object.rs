@@ -3,6 +3,7 @@ use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use crate::db::{Db, IdPath};
+use crate::error::Result;
 use crate::PropertyType;
 
 #[derive(serde::Serialize)]
@@ -24,10 +25,10 @@ pub trait ObjectExt {
     fn get_base_properties(&self) -> Map<String, Value>;
     fn get_module_properties(&self) -> Map<String, Value>;
     fn get_object_with_properties(&self) -> VertexProperties;
-    fn add_base_properties(&self, value: Value);
-    fn add_module_properties(&self, value: Value);
-    fn insert_base_property(&self, key: String, value: Value);
-    fn insert_module_property(&self, key: String, value: Value);
+    fn add_base_properties(&self, value: Value) -> Result<()>;
+    fn add_module_properties(&self, value: Value) -> Result<()>;
+    fn insert_base_property(&self, key: String, value: Value) -> Result<()>;
+    fn insert_module_property(&self, key: String, value: Value) -> Result<()>;
 }
 
 impl ObjectExt for Object<'_> {
@@ -59,23 +60,23 @@ impl ObjectExt for Object<'_> {
         self.db.get_object_with_properties(&self.vertex.id)
     }
 
-    fn add_base_properties(&self, value: Value) {
-        self.db.add_object_property(&self.vertex, &value, PropertyType::Base);
+    fn add_base_properties(&self, value: Value) -> Result<()> {
+        self.db.add_object_property(&self.vertex, &value, PropertyType::Base)
     }
 
-    fn add_module_properties(&self, value: Value) {
-        self.db.add_object_property(&self.vertex, &value, PropertyType::Module);
+    fn add_module_properties(&self, value: Value) -> Result<()> {
+        self.db.add_object_property(&self.vertex, &value, PropertyType::Module)
     }
 
-    fn insert_base_property(&self, key: String, value: Value) {
+    fn insert_base_property(&self, key: String, value: Value) -> Result<()> {
         let mut p = self.get_module_properties().clone();
         p.insert(key, value);
-        self.db.add_object_property(&self.vertex, &p, PropertyType::Base);
+        self.db.add_object_property(&self.vertex, &p, PropertyType::Base)
     }
 
-    fn insert_module_property(&self, key: String, value: Value) {
+    fn insert_module_property(&self, key: String, value: Value) -> Result<()> {
         let mut p = self.get_module_properties().clone();
         p.insert(key, value);
-        self.db.add_object_property(&self.vertex, &p, PropertyType::Module);
+        self.db.add_object_property(&self.vertex, &p, PropertyType::Module)
     }
 }
\ No newline at end of file
@@ -10,6 +10,7 @@ use crate::{ProjectRenderContext, PropertyType, RegressionConfig, render_script,
 use crate::constants::{KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_PROJECT, KEY_RELEASE, KEY_SCRIPT,
                        KEY_SCRIPTS, KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config};
@@ -25,14 +26,14 @@ pub struct Project<'a> {
 }
 
 impl<'a> Project<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new project object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Project, &mut path, label, pop);
-        db.add_object_property(&o, &config.project, PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.project.module, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Project, &mut path, label, pop)?;
+        db.add_object_property(&o, &config.project, PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), &config.project.module, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Project {
+        Ok(Box::new(Project {
             object: Object {
                 db,
                 id: o.id,
@@ -40,19 +41,19 @@ impl<'a> Project<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, id: &Uuid, config: &RegressionConfig) -> Box<(dyn ProjectExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, id: &Uuid, config: &RegressionConfig) -> Result<Box<(dyn ProjectExt<'a> + 'a)>> {
         error!("Loading project object");
         let o = db.get_object_with_properties(&id);
         let p_base = o.props.get(PropertyType::Base.index()).unwrap();
         let arr = o.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = p_base.value.get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config)?;
 
-        Box::new(Project {
+        Ok(Box::new(Project {
             object: Object {
                 db,
                 id: o.vertex.id,
@@ -60,49 +61,50 @@ impl<'a> Project<'a> {
                 vertex: o.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 
 }
 
 impl Renderer<'_> for Project<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        Box::new(ProjectRenderContext {
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        Ok(Box::new(ProjectRenderContext {
             job: format!("{}_{}", config.project.module, KEY_PROJECT).replace('_', "-"),
             base: self.get_base_properties(),
             module: self.get_module_properties(),
             scripts: scripts.clone(),
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let id_path = &self.get_id_path().get_str();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let m_props = self.get_module_properties();
-        let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
-            let path = format!("{}/{}/{}/{}/{}", config.root_path, config.project.path, module, scripts_path, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening project script file");
+        for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
+            let path = format!("{}/{}/{}/{}/{}", config.root_path, config.project.path, module, scripts_path, script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptProjectRenderContext {
                 base: self.get_base_properties(),
                 module: self.get_module_properties(),
                 project: config.project.clone(),
-                release: m_props.get(KEY_RELEASE).unwrap().to_string(),
+                release: m_props.get_value(KEY_RELEASE, id_path)?.to_string(),
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::{EdgeTypes, PropertyType, RegressionConfig};
 use crate::constants::{KEY_ID_PATH, KEY_NAME};
 use crate::db::Db;
+use crate::error::Result;
 
 use super::{implement_object_ext};
 use super::object::{Object, ObjectExt};
@@ -21,10 +22,10 @@ pub struct EutProvider<'a> {
 }
 
 impl<'a> DashboardProvider<'a> {
-    pub fn init(db: &'a Db, config: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new dashboard provider object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::DashboardProvider, &mut path, label, pop);
-        db.add_object_property(&o, &json!({KEY_NAME: label}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::DashboardProvider, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({KEY_NAME: label}), PropertyType::Base)?;
 
         let provider = Box::new(DashboardProvider {
             object: Object {
@@ -36,11 +37,11 @@ impl<'a> DashboardProvider<'a> {
             },
         });
 
-        provider.add_module_properties(config.clone());
-        provider
+        provider.add_module_properties(config.clone())?;
+        Ok(provider)
     }
 
-    pub fn load(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Vec<Box<(dyn ObjectExt + 'a)>> {
+    pub fn load(db: &'a Db, object: &Vertex, _config: &RegressionConfig) -> Result<Vec<Box<(dyn ObjectExt + 'a)>>> {
         let objects = db.get_object_neighbours_with_properties_out(&object.id, EdgeTypes::UsesProvider);
         let mut providers: Vec<Box<(dyn ObjectExt + 'a)>> = vec![];
 
@@ -59,17 +60,17 @@ impl<'a> DashboardProvider<'a> {
             });
             providers.push(provider);
         }
-        providers
+        Ok(providers)
     }
 }
 
 impl<'a> EutProvider<'a> {
-    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, _config: &RegressionConfig, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut provider object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::EutProvider, &mut path, label, pop);
-        db.add_object_property(&o, &json!({KEY_NAME: label}), PropertyType::Base);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::EutProvider, &mut path, label, pop)?;
+        db.add_object_property(&o, &json!({KEY_NAME: label}), PropertyType::Base)?;
 
-        Box::new(EutProvider {
+        Ok(Box::new(EutProvider {
             object: Object {
                 db,
                 id: o.id,
@@ -77,7 +78,7 @@ impl<'a> EutProvider<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 }
 
@@ -6,9 +6,10 @@ use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use crate::{EdgeTypes, PropertyType, RegressionConfig, render_script, RenderContext, Renderer, ReportRenderContext, ScriptReportRenderContext};
-use crate::constants::{KEY_DATA, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_REF_ARTIFACTS_PATH,
-                       KEY_REPORT, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH};
+use crate::constants::{KEY_COLLECTOR, KEY_DATA, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_REF_ARTIFACTS_PATH,
+                       KEY_REFS, KEY_REPORT, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH, KEY_TYPE};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result, Sense8Error};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{Collector, implement_object_ext, load_object_config};
@@ -24,15 +25,15 @@ pub struct Report<'a> {
 }
 
 impl<'a> Report<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new report object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Report, &mut path, label, pop);
-        db.add_object_property(&o, base_cfg, PropertyType::Base);
-        let module_name = base_cfg.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), module_name, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Report, &mut path, label, pop)?;
+        db.add_object_property(&o, base_cfg, PropertyType::Base)?;
+        let module_name = base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), module_name, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Report {
+        Ok(Box::new(Report {
             object: Object {
                 db,
                 id: o.id,
@@ -40,17 +41,17 @@ impl<'a> Report<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Box<(dyn ReportExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Result<Box<(dyn ReportExt<'a> + 'a)>> {
         error!("Loading report object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config)?;
 
-        Box::new(Report {
+        Ok(Box::new(Report {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -58,7 +59,7 @@ impl<'a> Report<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
@@ -75,61 +76,79 @@ impl RenderContext for Report<'_> {
 }
 
 impl Renderer<'_> for Report<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        Box::new(ReportRenderContext {
-            job: format!("{}_{}_{}_{}", config.project.module, KEY_REPORT,
-                         self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap().replace('_', "-"),
-                         self.get_module_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-"),
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
+        Ok(Box::new(ReportRenderContext {
+            job: format!("{}_{}_{}_{}", config.job_prefix(), KEY_REPORT,
+                         self.get_base_properties().get_str_value(KEY_NAME, id_path)?.replace('_', "-"),
+                         self.get_module_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-"),
             base: self.get_base_properties(),
             module: self.get_module_properties(),
             project: config.project.clone(),
             scripts: scripts.clone(),
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let name = self.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap().to_string();
-        let data = self.get_base_properties().get(KEY_DATA).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
+        let name = self.get_base_properties().get_str_value(KEY_NAME, id_path)?.to_string();
+        let data = self.get_base_properties().get_str_value(KEY_DATA, id_path)?.to_string();
         let m_props: Map<String, Value> = self.get_module_properties();
-        let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
-        let c_obj = self.object.db.get_object_neighbour_with_properties_out(&self.get_id(), EdgeTypes::ReportRefersCollector);
-        let collector = Collector::load(&self.object.db, &c_obj.unwrap(), &config);
-
-        for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
+        let c_obj = self.object.db.get_object_neighbour_with_properties_out(&self.get_id(), EdgeTypes::ReportRefersCollector)
+            .ok_or_else(|| Sense8Error::DanglingReference {
+                id_path: id_path.to_string(),
+                r#type: KEY_COLLECTOR.to_string(),
+                name: collector_ref(&self.get_base_properties()),
+            })?;
+        let collector = Collector::load(&self.object.db, &c_obj, &config)?;
+
+        for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}", config.root_path,
                                config.reports.path,
                                module, scripts_path,
-                               script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening report script file");
+                               script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptReportRenderContext {
                 eut: config.eut.module.to_string(),
                 name: name.to_string(),
                 data: data.to_string(),
-                refs: self.get_base_properties().get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                refs: self.get_base_properties().get_object_value(KEY_REF_ARTIFACTS_PATH, id_path)?.clone(),
                 module: module.to_string(),
                 project: config.project.clone(),
-                collector_name: collector.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                collector_module: collector.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
+                collector_name: collector.get_base_properties().get_str_value(KEY_NAME, id_path)?.to_string(),
+                collector_module: collector.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string(),
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
 #[typetag::serialize]
 impl ReportExt<'_> for Report<'_> {}
 
-implement_object_ext!(Report);
\ No newline at end of file
+implement_object_ext!(Report);
+
+/// Name of collector referred to in report `refs`. Empty if report refers to no collector.
+fn collector_ref(base: &Map<String, Value>) -> String {
+    base.get(KEY_REFS)
+        .and_then(|r| r.as_array())
+        .and_then(|r| r.iter().find(|r| r.get(KEY_TYPE).and_then(|t| t.as_str()) == Some(KEY_COLLECTOR)))
+        .and_then(|r| r.get(KEY_NAME))
+        .and_then(|n| n.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
@@ -1,5 +1,6 @@
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{OnceLock, RwLock};
 use indradb::{Vertex, VertexProperties};
 use log::{error, info};
 use regex::Regex;
@@ -12,16 +13,17 @@ use crate::{EdgeTypes, ObjRefs, PropertyType, RegressionConfig,
             ScriptRteRenderContext, ScriptTestRenderContext, ScriptVerificationRenderContext};
 use crate::constants::{KEY_ARTIFACTS_PATH, KEY_CI, KEY_COMPONENTS, KEY_CONNECTIONS, KEY_DATA,
                        KEY_DESTINATIONS, KEY_DST, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME,
-                       KEY_PROVIDER, KEY_REF_ARTIFACTS_PATH, KEY_RTE, KEY_SCRIPT,
-                       KEY_SCRIPTS, KEY_SCRIPTS_PATH, KEY_SOURCE, KEY_SRC, KEY_TEST,
+                       KEY_PROVIDER, KEY_REF_ARTIFACTS_PATH, KEY_REFS, KEY_RTE, KEY_SCRIPT, KEY_STAGE, KEY_AFTER, KEY_NEEDS, KEY_EXECUTION,
+                       KEY_SCRIPTS, KEY_SCRIPTS_PATH, KEY_SOURCE, KEY_SRC, KEY_TAGS, KEY_TEST,
                        KEY_TESTS, KEY_TYPE, KEY_VERIFICATION, KEY_VERIFICATIONS, RTE_TYPE_A,
-                       RTE_TYPE_B};
+                       RTE_TYPE_B, RTE_TYPE_MESH, VERTEX_TYPE_SITE};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result, Sense8Error};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{Ci, ComponentDestination, Components, ComponentSource, Connection, ConnectionDestination,
-            Connections, ConnectionSource, Eut, implement_object_ext, load_object_config, Project,
-            Test, Verification};
+            Connections, ConnectionSource, dependency_refs, Eut, implement_object_ext, load_object_config,
+            Project, Test, Verification};
 use super::super::db::IdPath;
 use super::super::VertexTypes;
 
@@ -34,18 +36,19 @@ pub struct Rte<'a> {
 }
 
 impl<'a> Rte<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Box<(dyn ObjectExt + 'a)>, object_refs: &mut Vec<ObjRefs>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Box<(dyn ObjectExt + 'a)>, object_refs: &mut Vec<ObjRefs>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new rte object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Rte,
-                                                     &mut path,
-                                                     base_cfg.get(KEY_NAME).unwrap().as_str().unwrap(),
-                                                     pop);
-        db.create_relationship(&parent.get_object(), &o);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config);
-        let rte_provider = base_cfg.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
-        let rte_name = base_cfg.get(KEY_NAME).unwrap().as_str().unwrap().to_string();
-        let rte_module = base_cfg.get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let rte_type = module_cfg.get(KEY_TYPE).unwrap().as_str().unwrap().to_string();
+        let name = base_cfg.get_str_value(KEY_NAME, &path.join("__"))?;
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Rte, &mut path, name, pop)?;
+        let cfg_path = id_path.get_str();
+        db.create_relationship(&parent.get_object(), &o)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config)?;
+        let rte_provider = base_cfg.get_str_value(KEY_PROVIDER, &cfg_path)?.to_string();
+        let rte_name = base_cfg.get_str_value(KEY_NAME, &cfg_path)?.to_string();
+        let rte_module = base_cfg.get_str_value(KEY_MODULE, &cfg_path)?.to_string();
+        let rte_type = module_cfg.get_str_value(KEY_TYPE, &cfg_path)?.to_string();
+        check_rte_module(&module_cfg, &cfg_path)?;
+        let rte_type_o = RteType::new(&rte_type, db).ok_or_else(|| unknown_rte_type(&rte_type, &cfg_path))?;
 
         let _module_cfg: Value = json!({
             KEY_NAME: rte_module,
@@ -62,54 +65,58 @@ impl<'a> Rte<'a> {
             },
         });
 
-        rte.add_module_properties(json!(_module_cfg));
+        rte.add_module_properties(json!(_module_cfg))?;
 
         let eut_o = db.get_object_neighbour_in_out_id(&parent.get_id(),
                                                       EdgeTypes::UsesRtes,
-                                                      VertexTypes::Eut).unwrap();
+                                                      VertexTypes::Eut)
+            .ok_or_else(|| Sense8Error::Db(format!("rte <{cfg_path}> is not used by an eut object")))?;
         let project_o = db.get_object_neighbour_in_out_id(&eut_o.id,
                                                           EdgeTypes::HasEut,
-                                                          VertexTypes::Project).unwrap();
-        let project = Project::load(&db, &project_o.id, &config);
-        let eut = Eut::load(&db, &project, &config);
+                                                          VertexTypes::Project)
+            .ok_or_else(|| Sense8Error::Db(format!("eut of rte <{cfg_path}> is not held by a project object")))?;
+        let project = Project::load(&db, &project_o.id, &config)?;
+        let eut = Eut::load(&db, project.as_ref(), &config)?;
 
         //RTE -> Features
         let eut_f_o = db.get_object_neighbour_out(&eut.get_id(), EdgeTypes::HasFeatures);
-        db.create_relationship(&rte.get_object(), &eut_f_o.unwrap());
+        db.create_relationship(&rte.get_object(), &eut_f_o.unwrap())?;
 
         //Rte components
         let rte_components = Components::init(&db, &config,
-                                              &mut rte.get_id_path().get_vec(), "", 0);
-        db.create_relationship(&rte.get_object(), &rte_components.get_object());
+                                              &mut rte.get_id_path().get_vec(), "", 0)?;
+        db.create_relationship(&rte.get_object(), &rte_components.get_object())?;
 
-        for (k, v) in module_cfg.as_object().unwrap() {
+        let module_cfg_o = module_cfg.as_object().ok_or_else(|| Sense8Error::InvalidType {
+            key: rte_module.clone(),
+            expected: "an object",
+            id_path: cfg_path.clone(),
+        })?;
+
+        for (k, v) in module_cfg_o {
             match k {
                 k if k == KEY_COMPONENTS => {
-                    if rte_type == RTE_TYPE_A {
-                        let c_src_o = ComponentSource::init(&db,
-                                                            &config,
-                                                            v.get(KEY_SRC).unwrap(),
-                                                            &mut rte_components.get_id_path().get_vec(),
-                                                            "", 0);
-                        db.create_relationship(&rte_components.get_object(), &c_src_o.get_object());
-                        let c_dst_o = ComponentDestination::init(&db,
-                                                                 &config, v.get(KEY_DST).unwrap(),
-                                                                 &mut rte_components.get_id_path().get_vec(),
-                                                                 "", 0);
-                        db.create_relationship(&rte_components.get_object(), &c_dst_o.get_object());
-                    } else if rte_type == RTE_TYPE_B {
-                        let c_src_o = ComponentSource::init(&db,
-                                                            &config, v.get(KEY_SRC).unwrap(),
-                                                            &mut rte_components.get_id_path().get_vec(),
-                                                            "", 0);
-                        db.create_relationship(&rte_components.get_object(), &c_src_o.get_object());
+                    for (role, c_cfg) in module_cfg.get_object_value(KEY_COMPONENTS, &cfg_path)?.iter() {
+                        let c_o = match role.as_str() {
+                            KEY_SRC => ComponentSource::init(&db, &config, c_cfg,
+                                                             &mut rte_components.get_id_path().get_vec(),
+                                                             "", 0)?,
+                            KEY_DST => ComponentDestination::init(&db, &config, c_cfg,
+                                                                  &mut rte_components.get_id_path().get_vec(),
+                                                                  "", 0)?,
+                            _ => return Err(Sense8Error::ConfigInvalid {
+                                path: cfg_path,
+                                msg: format!("unknown rte component <{role}>"),
+                            }),
+                        };
+                        db.create_relationship(&rte_components.get_object(), &c_o.get_object())?;
                     }
                 }
                 k if k == KEY_CI => {
                     let rte_ci = Ci::init(db, &config, v, &mut rte.get_id_path().get_vec(),
-                                          "", 0);
-                    db.create_relationship(&rte.get_object(), &rte_ci.get_object());
-                    rte_ci.add_base_properties(v.clone());
+                                          "", 0)?;
+                    db.create_relationship(&rte.get_object(), &rte_ci.get_object())?;
+                    rte_ci.add_base_properties(v.clone())?;
                 }
                 _ => {}
             }
@@ -118,7 +125,8 @@ impl<'a> Rte<'a> {
         //Rte connection src components
         let rte_src_component = Components::load_source_component(&db,
                                                                   &rte_components.get_object(),
-                                                                  &config).unwrap();
+                                                                  &config)?
+            .ok_or_else(|| Sense8Error::Db(format!("rte <{cfg_path}> has no source component object")))?;
         //Rte artifacts path
         let artifacts_path = format!("{}/{}/{}/{}/{}",
                                      config.rte.artifacts_dir,
@@ -132,84 +140,96 @@ impl<'a> Rte<'a> {
             KEY_MODULE: rte_module,
             KEY_TYPE: rte_type,
             KEY_PROVIDER: rte_provider,
-            KEY_ARTIFACTS_PATH: artifacts_path
+            KEY_ARTIFACTS_PATH: artifacts_path,
+            KEY_EXECUTION: base_cfg.get(KEY_EXECUTION)
         });
 
-        rte.add_base_properties(json!(&_base_cfg));
+        rte.add_base_properties(json!(&_base_cfg))?;
 
         //Rte
-        for (k, v) in base_cfg.as_object().unwrap().iter() {
+        let rte_cfg = base_cfg.as_object().ok_or_else(|| Sense8Error::InvalidType {
+            key: rte_name.to_string(),
+            expected: "an object",
+            id_path: cfg_path.clone(),
+        })?;
+        for (k, v) in rte_cfg.iter() {
             match k {
                 //Connections
                 k if k == KEY_CONNECTIONS => {
-                    let cs_o = Connections::init(&db, &config, &mut rte.get_id_path().get_vec(), "", 0);
-                    db.create_relationship(&rte.get_object(), &cs_o.get_object());
-
-                    for item in v.as_array().unwrap().iter() {
+                    let cs_o = Connections::init(&db, &config, &mut rte.get_id_path().get_vec(), "", 0)?;
+                    db.create_relationship(&rte.get_object(), &cs_o.get_object())?;
+
+                    let connections = v.as_array().ok_or_else(|| Sense8Error::InvalidType {
+                        key: KEY_CONNECTIONS.to_string(),
+                        expected: "an array",
+                        id_path: cfg_path.clone(),
+                    })?;
+                    for item in connections.iter() {
                         //Connection
-                        let c_name = item.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                        let c_name = item.get_str_value(KEY_NAME, &cfg_path)?;
                         let c_o = Connection::init(&db, &config,
-                                                   &json!({KEY_NAME: c_name}),
-                                                   &mut cs_o.get_id_path().get_vec(), "", 0);
-                        db.create_relationship(&cs_o.get_object(), &c_o.get_object());
+                                                   &json!({KEY_NAME: c_name, KEY_EXECUTION: item.get(KEY_EXECUTION)}),
+                                                   &mut cs_o.get_id_path().get_vec(), "", 0)?;
+                        db.create_relationship(&cs_o.get_object(), &c_o.get_object())?;
 
                         //Connection Source
-                        let source = item.as_object().unwrap().get(KEY_SOURCE).unwrap().as_str().unwrap();
+                        let source = item.get_str_value(KEY_SOURCE, &cfg_path)?;
                         let src_o = ConnectionSource::init(&db, &config,
                                                            &json!({KEY_NAME: &source,
-                                                               KEY_RTE: &base_cfg.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap()}),
+                                                               KEY_RTE: &base_cfg.get_str_value(KEY_NAME, &cfg_path)?}),
                                                            &mut c_o.get_id_path().get_vec(),
-                                                           "", 0);
-                        db.create_relationship(&c_o.get_object(), &src_o.get_object());
-                        let _sites = db.get_object_neighbour_out(&&eut.get_id(), EdgeTypes::HasSites);
-                        let sites = db.get_object_neighbours_with_properties_out(&_sites.unwrap().id,
-                                                                                 EdgeTypes::HasSite);
+                                                           "", 0)?;
+                        db.create_relationship(&c_o.get_object(), &src_o.get_object())?;
+                        let _sites = db.get_object_neighbour_out(&&eut.get_id(), EdgeTypes::HasSites)
+                            .ok_or_else(|| Sense8Error::Db(format!("eut of rte <{cfg_path}> has no sites object")))?;
+                        let sites = db.get_object_neighbours_with_properties_out(&_sites.id, EdgeTypes::HasSite);
 
                         //Connection Source -> Site
                         for s in sites.iter() {
-                            let site_name = s.props.get(PropertyType::Base.index()).unwrap().value.as_object().
-                                unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                            let site_name = s.props.get(PropertyType::Base.index()).unwrap().value
+                                .get_str_value(KEY_NAME, &cfg_path)?;
 
                             if site_name == source {
-                                db.create_relationship(&src_o.get_object(), &s.vertex);
+                                db.create_relationship(&src_o.get_object(), &s.vertex)?;
                                 //site --> rte
-                                db.create_relationship(&s.vertex, &rte.get_object());
+                                db.create_relationship(&s.vertex, &rte.get_object())?;
                             }
                         }
 
                         //Connection Destinations
-                        let destinations = item.as_object().unwrap().
-                            get(KEY_DESTINATIONS)
-                            .unwrap().as_array().unwrap();
+                        let destinations = item.get_array_value(KEY_DESTINATIONS, &cfg_path)?;
 
                         for d in destinations.iter() {
-                            let re = Regex::new(d.as_str().unwrap()).unwrap();
+                            let d = d.as_str().ok_or_else(|| Sense8Error::InvalidType {
+                                key: KEY_DESTINATIONS.to_string(),
+                                expected: "an array of strings",
+                                id_path: cfg_path.clone(),
+                            })?;
+                            let re = Regex::new(d)
+                                .map_err(|e| Sense8Error::ConfigInvalid { path: cfg_path.clone(), msg: e.to_string() })?;
 
                             for site in sites.iter() {
                                 let site_name = site.props.get(PropertyType::Base.index())
-                                    .unwrap().value.as_object()
-                                    .unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                                    .unwrap().value.get_str_value(KEY_NAME, &cfg_path)?;
 
                                 if let Some(_t) = re.captures(site_name) {
                                     let dst_o = ConnectionDestination::init(&db,
                                                                             &config,
                                                                             &json!({KEY_NAME: &d,
-                                                        KEY_RTE: &base_cfg.as_object().unwrap().get(KEY_MODULE)
-                                                        .unwrap().as_str().unwrap()}), &mut c_o.get_id_path().get_vec(),
-                                                                            "", 0);
+                                                        KEY_RTE: &base_cfg.get_str_value(KEY_MODULE, &cfg_path)?}), &mut c_o.get_id_path().get_vec(),
+                                                                            "", 0)?;
 
-                                    db.create_relationship(&src_o.get_object(), &dst_o.get_object());
+                                    db.create_relationship(&src_o.get_object(), &dst_o.get_object())?;
                                     //Connection Destination -> Site
-                                    db.create_relationship(&dst_o.get_object(), &site.vertex);
+                                    db.create_relationship(&dst_o.get_object(), &site.vertex)?;
                                     //site --> rte
-                                    db.create_relationship(&site.vertex, &rte.get_object());
+                                    db.create_relationship(&site.vertex, &rte.get_object())?;
                                 }
                             }
                         }
 
                         //Tests
-                        let tests = item.as_object().unwrap().get(KEY_TESTS)
-                            .unwrap().as_array().unwrap();
+                        let tests = item.get_array_value(KEY_TESTS, &cfg_path)?;
                         for (index, test) in tests.iter().enumerate() {
                             let mut _index = 0;
 
@@ -221,24 +241,31 @@ impl<'a> Rte<'a> {
                             let t_o = Test::init(&db, &config,
                                                  &test, &mut c_o.get_id_path().get_vec(),
                                                  &src_o.get_object(),
-                                                 test[KEY_NAME].as_str().unwrap(),
-                                                 _index);
+                                                 test.get_str_value(KEY_NAME, &cfg_path)?,
+                                                 _index)?;
                             let props = t_o.get_base_properties();
+                            let mut refs = props.get_array_value(KEY_REFS, &cfg_path)?.clone();
+                            refs.extend(dependency_refs(test, &base_cfg.get_str_value(KEY_NAME, &cfg_path)?, c_name));
 
                             object_refs.push(ObjRefs {
-                                refs: props.get("refs").unwrap().as_array().unwrap().clone(),
+                                refs,
                                 id: t_o.get_id(),
                             });
 
-                            for (k, v) in test.as_object().unwrap().iter() {
+                            let test_cfg = test.as_object().ok_or_else(|| Sense8Error::InvalidType {
+                                key: KEY_TESTS.to_string(),
+                                expected: "an array of objects",
+                                id_path: cfg_path.clone(),
+                            })?;
+                            for (k, _) in test_cfg.iter() {
                                 match k {
                                     k if k == KEY_VERIFICATIONS => {
-                                        for v in v.as_array().unwrap().iter() {
-                                            let v_module = v.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
+                                        for v in test.get_array_value(KEY_VERIFICATIONS, &cfg_path)?.iter() {
+                                            let v_module = v.get_str_value(KEY_MODULE, &cfg_path)?;
                                             let v_o = Verification::init(&db, &config,
                                                                          &v, &mut t_o.get_id_path().get_vec(),
-                                                                         v_module, 0);
-                                            db.create_relationship(&t_o.get_object(), &v_o.get_object());
+                                                                         v_module, 0)?;
+                                            db.create_relationship(&t_o.get_object(), &v_o.get_object())?;
                                         }
                                     }
                                     _ => {}
@@ -252,21 +279,20 @@ impl<'a> Rte<'a> {
         }
 
         //Rte type
-        let rte_type_o = RteType::new(&rte_type, db);
-        if let Some(r) = rte_type_o { r.init(&rte) }
+        rte_type_o.init(&rte)?;
 
-        rte
+        Ok(rte)
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Box<(dyn RteExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, config: &RegressionConfig) -> Result<Box<(dyn RteExt<'a> + 'a)>> {
         error!("Loading rte object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = object.props.get(PropertyType::Base.index()).unwrap().
             value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&object.vertex), module, &config)?;
 
-        Box::new(Rte {
+        Ok(Box::new(Rte {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -274,7 +300,7 @@ impl<'a> Rte<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg,
             }
-        })
+        }))
     }
 }
 
@@ -291,18 +317,23 @@ impl RenderContext for Rte<'_> {
 }
 
 impl Renderer<'_> for Rte<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
         let rte_base_p = self.get_base_properties();
-        let rte_name = rte_base_p.get(KEY_MODULE).unwrap().as_str().unwrap();
+        let rte_name = rte_base_p.get_str_value(KEY_MODULE, id_path)?;
         let rte_ci = Ci::load(&self.object.db, &self.get_object(), &config);
-        let rtes = self.object.db.get_object_neighbour_in_out_id(&self.get_id(), EdgeTypes::ProvidesRte, VertexTypes::Rtes).unwrap();
-        let eut_o = self.object.db.get_object_neighbour_in_out_id(&rtes.id, EdgeTypes::UsesRtes, VertexTypes::Eut).unwrap();
-        let project_o = self.object.db.get_object_neighbour_in_out_id(&eut_o.id, EdgeTypes::HasEut, VertexTypes::Project).unwrap();
-
+        let rtes = self.object.db.get_object_neighbour_in_out_id(&self.get_id(), EdgeTypes::ProvidesRte, VertexTypes::Rtes)
+            .ok_or_else(|| Sense8Error::Db(format!("rte <{id_path}> is not provided by an rtes object")))?;
+        let eut_o = self.object.db.get_object_neighbour_in_out_id(&rtes.id, EdgeTypes::UsesRtes, VertexTypes::Eut)
+            .ok_or_else(|| Sense8Error::Db(format!("rte <{id_path}> is not used by an eut object")))?;
+        let project_o = self.object.db.get_object_neighbour_in_out_id(&eut_o.id, EdgeTypes::HasEut, VertexTypes::Project)
+            .ok_or_else(|| Sense8Error::Db(format!("eut of rte <{id_path}> is not held by a project object")))?;
+
+        let rte_ci_p = rte_ci.get_base_properties();
         let rcrc = RteCiRenderContext {
-            timeout: rte_ci.get_base_properties().get("timeout").unwrap().clone(),
-            variables: rte_ci.get_base_properties().get("variables").unwrap().clone(),
-            artifacts: rte_ci.get_base_properties().get("artifacts").unwrap().clone(),
+            timeout: rte_ci_p.get_value("timeout", id_path)?.clone(),
+            variables: rte_ci_p.get_value("variables", id_path)?.clone(),
+            artifacts: rte_ci_p.get_value("artifacts", id_path)?.clone(),
         };
 
         let mut rte_crcs = RteRenderContext {
@@ -316,41 +347,41 @@ impl Renderer<'_> for Rte<'_> {
 
         //Process connections
         let rte_module_p = self.get_module_properties();
-        let rte_type = rte_module_p.get(KEY_TYPE).unwrap().as_str().unwrap();
+        let rte_type = rte_module_p.get_str_value(KEY_TYPE, id_path)?;
         let rte_type_o = RteType::new(rte_type, self.object.db);
 
         if let Some(r) = rte_type_o {
             r.build_conn_ctx(RteCtxParameters {
                 rte: &self.get_object_with_properties(),
                 config,
-                project_config: config.project.clone(),
                 eut: &self.object.db.get_object_with_properties(&eut_o.id),
                 rte_name: rte_name.to_string(),
                 rte_crcs: &mut rte_crcs,
                 project: &project_o,
                 rte_scripts: scripts,
-            })
+            })?
         }
 
-        Box::new(rte_crcs)
+        Ok(Box::new(rte_crcs))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let rte_base_p: Map<String, Value> = self.get_base_properties();
-        let rte_provider = rte_base_p.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
-        let rte_artifacts_path = rte_base_p.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string();
+        let rte_provider = rte_base_p.get_str_value(KEY_PROVIDER, id_path)?.to_string();
+        let rte_artifacts_path = rte_base_p.get_str_value(KEY_ARTIFACTS_PATH, id_path)?.to_string();
         let components = Components::load_collection(&self.object.db, &self.get_object(), &config);
-        let src_component = Components::load_source_component(&self.object.db, &components.get_object(), &config).unwrap();
+        let src_component = Components::load_source_component(&self.object.db, &components.get_object(), &config)?
+            .ok_or_else(|| Sense8Error::Db(format!("rte <{id_path}> has no source component object")))?;
         let src_component_base_p = src_component.get_base_properties();
-        let scripts_path = src_component_base_p.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
-        let src_component_name = src_component_base_p.get(KEY_NAME).unwrap().as_str().unwrap().to_string();
+        let scripts_path = src_component_base_p.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
+        let src_component_name = src_component_base_p.get_str_value(KEY_NAME, id_path)?.to_string();
 
-        for script in src_component_base_p.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
-            let path = format!("{}/{}/{}/{}/{}/{}/{}", config.root_path, config.rte.path, module, scripts_path, rte_provider, src_component_name, script.as_object()
-                .unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening rte script file");
+        for script in src_component_base_p.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
+            let path = format!("{}/{}/{}/{}/{}/{}/{}", config.root_path, config.rte.path, module, scripts_path, rte_provider, src_component_name, script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptRteRenderContext {
                 eut: config.eut.module.to_string(),
                 site: "".to_string(),
@@ -364,23 +395,96 @@ impl Renderer<'_> for Rte<'_> {
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
+    }
+}
+
+/// Behaviour of an rte type. Which components an rte has is declared in the rte module
+/// `config.json` below `components`. The type decides which of them it requires, how connections
+/// are wired to them and which render contexts are built per connection.
+pub trait RteCharacteristics: {
+    fn init<'b>(&self, rte: &Box<Rte<'b>>) -> Result<()>;
+    fn build_conn_ctx(&self, params: RteCtxParameters) -> Result<()>;
+}
+
+/// Creates rte type implementation working on object graph `db`.
+pub type RteTypeFactory = for<'a> fn(&'a Db) -> Box<dyn RteCharacteristics + 'a>;
+
+struct RteTypeEntry {
+    /// Component roles, i.e. keys below `components`, the rte module configuration has to provide
+    components: Vec<String>,
+    factory: RteTypeFactory,
+}
+
+/// Rte type name to implementation. Holds built-in types until more are registered.
+fn rte_type_registry() -> &'static RwLock<HashMap<String, RteTypeEntry>> {
+    static RTE_TYPES: OnceLock<RwLock<HashMap<String, RteTypeEntry>>> = OnceLock::new();
+
+    RTE_TYPES.get_or_init(|| {
+        let mut map: HashMap<String, RteTypeEntry> = HashMap::new();
+        let builtin: [(&str, &[&str], RteTypeFactory); 3] = [
+            (RTE_TYPE_A, &[KEY_SRC, KEY_DST], |db| Box::new(RteTypeA { db })),
+            (RTE_TYPE_B, &[KEY_SRC], |db| Box::new(RteTypeB { db })),
+            (RTE_TYPE_MESH, &[KEY_SRC], |db| Box::new(RteTypeMesh { db })),
+        ];
+        for (name, components, factory) in builtin {
+            map.insert(name.to_string(), RteTypeEntry { components: components.iter().map(|c| c.to_string()).collect(), factory });
+        }
+        RwLock::new(map)
+    })
+}
+
+/// Register rte type `name` requiring `components` in its rte module configuration. Rte modules
+/// naming `name` as their type are built with the implementation returned by `factory`. A type
+/// registered before under the same name, built-in types included, is replaced.
+pub fn register_rte_type(name: &str, components: &[&str], factory: RteTypeFactory) {
+    let entry = RteTypeEntry { components: components.iter().map(|c| c.to_string()).collect(), factory };
+    rte_type_registry().write().unwrap().insert(name.to_string(), entry);
+}
+
+/// Names of registered rte types.
+fn rte_types() -> Vec<String> {
+    let mut types: Vec<String> = rte_type_registry().read().unwrap().keys().cloned().collect();
+    types.sort();
+    types
+}
+
+/// Check rte module configuration `module_cfg` loaded from `path` names a registered rte type and
+/// provides the components this type requires.
+pub(crate) fn check_rte_module(module_cfg: &Value, path: &str) -> Result<()> {
+    let rte_type = module_cfg.get_str_value(KEY_TYPE, path)?;
+    let required = rte_type_registry().read().unwrap().get(rte_type)
+        .map(|entry| entry.components.clone())
+        .ok_or_else(|| unknown_rte_type(rte_type, path))?;
+    let components = module_cfg.get_object_value(KEY_COMPONENTS, path)?;
+
+    for role in required.iter() {
+        if !components.contains_key(role) {
+            return Err(Sense8Error::ConfigInvalid {
+                path: path.to_string(),
+                msg: format!("rte type <{rte_type}> requires component <{role}>"),
+            });
+        }
     }
+
+    Ok(())
 }
 
-trait RteCharacteristics: {
-    fn init<'b>(&self, rte: &Box<Rte<'b>>);
-    fn build_conn_ctx(&self, params: RteCtxParameters);
+fn unknown_rte_type(rte_type: &str, path: &str) -> Sense8Error {
+    Sense8Error::ConfigInvalid {
+        path: path.to_string(),
+        msg: format!("unknown rte type <{rte_type}>. Known types: {}", rte_types().join(", ")),
+    }
 }
 
 struct RteTypeA<'a> {
@@ -388,91 +492,78 @@ struct RteTypeA<'a> {
 }
 
 impl<'a> RteCharacteristics for RteTypeA<'a> {
-    fn init<'b>(&self, rte: &Box<Rte<'b>>) {
-        error!("RTE TYPE A init connection components --> {:?}", &rte.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap());
+    fn init<'b>(&self, rte: &Box<Rte<'b>>) -> Result<()> {
+        error!("RTE TYPE A init connection components --> {:?}", &rte.get_base_properties().get_str_value(KEY_NAME, &rte.get_id_path().get_str())?);
         // Connection -> Component
         let _c = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasConnections);
         let connections = self.db.get_object_neighbours_out(&_c.unwrap().id, EdgeTypes::HasConnection);
-        let _p = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::NeedsProvider);
-        let rte_provider = self.db.get_object_neighbours_with_properties_out(&_p.unwrap().id, EdgeTypes::ProvidesProvider);
+        let _components = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasComponents).unwrap();
+        let component_src = self.db.get_object_neighbour_out(&_components.id, EdgeTypes::HasComponentSrc).unwrap();
+        let component_dst = self.db.get_object_neighbour_out(&_components.id, EdgeTypes::HasComponentDst).unwrap();
 
         for c in connections.iter() {
             let c_s = self.db.get_object_neighbour_with_properties_out(&c.id, EdgeTypes::HasConnectionSrc).unwrap();
-            let site = self.db.get_object_neighbour_out(&c_s.vertex.id, EdgeTypes::RefersSite);
-            let site_provider = self.db.get_object_neighbour_with_properties_out(&site.unwrap().id, EdgeTypes::UsesProvider).unwrap();
-            let s_p_name = site_provider.props.get(PropertyType::Base.index()).unwrap().value.as_object().
-                unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-
-            let _c_d_s: Vec<VertexProperties> = self.db.get_object_neighbours_with_properties_out(&c_s.vertex.id, EdgeTypes::HasConnectionDst);
-            for p in rte_provider.iter() {
-                let _components = self.db.get_object_neighbour_out(&p.vertex.id, EdgeTypes::HasComponents);
-                let component_src = self.db.get_object_neighbour_out(&_components.unwrap().id, EdgeTypes::HasComponentSrc);
-                let r_p_name = p.props.get(PropertyType::Module.index()).unwrap().value.as_object().
-                    unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                if s_p_name == r_p_name {
-                    self.db.create_relationship(&c_s.vertex, &component_src.unwrap());
-                }
-            }
+            self.db.create_relationship(&c_s.vertex, &component_src)?;
 
             //CONNECTION DSTs
-            for c_d in _c_d_s.iter() {
-                for p in rte_provider.iter() {
-                    let _components = self.db.get_object_neighbour_out(&p.vertex.id, EdgeTypes::HasComponents);
-                    let component_dst = self.db.get_object_neighbour_out(&_components.unwrap().id, EdgeTypes::HasComponentDst);
-                    self.db.create_relationship(&c_d.vertex, &component_dst.unwrap());
-                }
+            for c_d in self.db.get_object_neighbours_out(&c_s.vertex.id, EdgeTypes::HasConnectionDst).iter() {
+                self.db.create_relationship(c_d, &component_dst)?;
             }
         }
         info!("Init rte type a connection components -> Done.");
+
+        Ok(())
     }
 
-    fn build_conn_ctx(&self, params: RteCtxParameters) {
+    fn build_conn_ctx(&self, mut params: RteCtxParameters) -> Result<()> {
         error!("RTE TYPE A build conn ctx --> {}", params.rte_name);
         //Connection DST rt set
-        let mut server_destinations: HashSet<String> = HashSet::new();
+        let mut server_destinations: BTreeSet<String> = BTreeSet::new();
 
         let _c = self.db.get_object_neighbour_out(&params.rte.vertex.id, EdgeTypes::HasConnections);
         let connections = self.db.get_object_neighbours_with_properties_out(&_c.unwrap().id, EdgeTypes::HasConnection);
-        let mut site_to_rte_map: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut site_to_rte_map: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
 
         for conn in connections.iter() {
-            let connection_name = conn.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+            let connection_name = conn.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
             let src = self.db.get_object_neighbour_with_properties_out(&conn.vertex.id, EdgeTypes::HasConnectionSrc).unwrap();
-            let src_name = src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-            let src_site = self.db.get_object_neighbour_with_properties_out(&src.vertex.id, EdgeTypes::RefersSite).unwrap();
-            let src_site_name = src_site.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-            let src_provider = self.db.get_object_neighbour_with_properties_out(&src_site.vertex.id, EdgeTypes::UsesProvider).unwrap();
-            let src_p_name = src_provider.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+            let src_name = src.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+            let src_site = self.db.get_object_neighbour_with_properties_out(&src.vertex.id, EdgeTypes::RefersSite)
+                .ok_or_else(|| dangling_reference(&src, VERTEX_TYPE_SITE, src_name))?;
+            let src_site_name = src_site.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+            let src_provider = self.db.get_object_neighbour_with_properties_out(&src_site.vertex.id, EdgeTypes::UsesProvider)
+                .ok_or_else(|| dangling_reference(&src_site, KEY_PROVIDER, src_site_name))?;
+            let src_p_name = src_provider.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
             let comp_src = self.db.get_object_neighbour_with_properties_out(&src.vertex.id, EdgeTypes::HasComponentSrc).unwrap();
-            let comp_src_name = &comp_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-            let rte_job_name = format!("{}_{}_{}_{}_{}_{}_{}", params.project_config.module, KEY_RTE, params.rte_name, &connection_name, &src_p_name, &src_name, &comp_src_name).replace('_', "-");
+            let comp_src_name = &comp_src.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+            let rte_job_name = format!("{}_{}_{}_{}_{}_{}_{}", params.config.job_prefix(), KEY_RTE, params.rte_name, &connection_name, &src_p_name, &src_name, &comp_src_name).replace('_', "-");
 
             //Process site_to_rte_map
-            let mut _rtes: HashSet<String> = HashSet::new();
+            let mut _rtes: BTreeSet<String> = BTreeSet::new();
             _rtes.insert(params.rte_name.to_string());
             site_to_rte_map.entry(src_site_name.to_string()).or_insert(_rtes);
 
             //Process rte src component scripts
-            //let scripts_path = comp_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+            //let scripts_path = comp_src.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
             let scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
 
             //Process client destination list
-            let mut client_destinations: HashSet<String> = HashSet::new();
+            let mut client_destinations: BTreeSet<String> = BTreeSet::new();
             let dsts = self.db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::HasConnectionDst);
             for dst in dsts.iter() {
-                client_destinations.insert(dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string());
+                client_destinations.insert(dst.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string());
             }
 
             /*for p in params.provider.iter() {
-                let p_name = p.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                let p_name = p.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
 
-                for script in comp_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+                for script in comp_src.props.get(PropertyType::Base.index()).unwrap().value.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
                     if src_p_name == p_name {
-                        let path = format!("{}/{}/{}/{}/{}/{}/{}", params.config.root_path, params.config.rte.path, params.rte_name, scripts_path, p_name, comp_src_name, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                        let contents = std::fs::read_to_string(&path).expect("panic while opening rte apply.script file");
+                        let path = format!("{}/{}/{}/{}/{}/{}/{}", params.config.root_path, params.config.rte.path, params.rte_name, scripts_path, p_name, comp_src_name, script.get_str_value(KEY_FILE, &params.rte_name)?);
+                        let contents = read_file(&path)?;
                         let ctx = ScriptRteRenderContext {
                             rte: params.rte_name.to_string(),
-                            eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
+                            eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
                             site: src_site_name.to_string(),
                             project: params.config.project.clone(),
                             release: "".to_string(),
@@ -482,12 +573,12 @@ impl<'a> RteCharacteristics for RteTypeA<'a> {
                         };
 
                         let mut commands: Vec<String> = Vec::new();
-                        for command in render_script(&ctx, &contents).lines() {
+                        for command in render_script(&ctx, &contents, &path)?.lines() {
                             commands.push(format!("{:indent$}{}", "", command, indent = 0));
                         }
 
                         let data: HashMap<String, Vec<String>> = [
-                            (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                            (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
                         ].into_iter().collect();
                         scripts.push(data);
                     }
@@ -507,35 +598,37 @@ impl<'a> RteCharacteristics for RteTypeA<'a> {
             //Process connection destinations
             for dst in dsts.iter() {
                 let dst_p_base = dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap();
-                let dst_name = dst_p_base.get(KEY_NAME).unwrap().as_str().unwrap();
-                let dst_site = self.db.get_object_neighbour_with_properties_out(&dst.vertex.id, EdgeTypes::RefersSite).unwrap();
-                let dst_site_name = dst_site.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                let dst_provider = self.db.get_object_neighbour_with_properties_out(&dst_site.vertex.id, EdgeTypes::UsesProvider).unwrap();
-                let dst_p_name = dst_provider.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                let dst_name = dst_p_base.get_str_value(KEY_NAME, &params.rte_name)?;
+                let dst_site = self.db.get_object_neighbour_with_properties_out(&dst.vertex.id, EdgeTypes::RefersSite)
+                    .ok_or_else(|| dangling_reference(dst, VERTEX_TYPE_SITE, dst_name))?;
+                let dst_site_name = dst_site.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+                let dst_provider = self.db.get_object_neighbour_with_properties_out(&dst_site.vertex.id, EdgeTypes::UsesProvider)
+                    .ok_or_else(|| dangling_reference(&dst_site, KEY_PROVIDER, dst_site_name))?;
+                let dst_p_name = dst_provider.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
                 let comp_dst = self.db.get_object_neighbour_with_properties_out(&dst.vertex.id, EdgeTypes::HasComponentDst).unwrap();
-                let comp_dst_name = &comp_dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                let rte_job_name = format!("{}_{}_{}_{}_{}_{}_{}", params.project_config.module, KEY_RTE, &params.rte_name, &connection_name, &dst_p_name, &dst_name, &comp_dst_name).replace('_', "-");
+                let comp_dst_name = &comp_dst.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+                let rte_job_name = format!("{}_{}_{}_{}_{}_{}_{}", params.config.job_prefix(), KEY_RTE, &params.rte_name, &connection_name, &dst_p_name, &dst_name, &comp_dst_name).replace('_', "-");
 
                 //Process server destination list
                 let rt_dsts = self.db.get_object_neighbours_with_properties_in(&dst.vertex.id, EdgeTypes::HasConnectionDst);
                 for dst in rt_dsts.iter() {
-                    server_destinations.insert(dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string());
+                    server_destinations.insert(dst.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string());
                 }
 
                 //Process rte dst component scripts
-                //let scripts_path = comp_dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+                //let scripts_path = comp_dst.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
                 let scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
 
                 /*for p in params.provider.iter() {
-                    let p_name = p.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
+                    let p_name = p.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
 
-                    for script in comp_dst.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+                    for script in comp_dst.props.get(PropertyType::Base.index()).unwrap().value.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
                         if dst_p_name == p_name {
-                            let path = format!("{}/{}/{}/{}/{}/{}/{}", params.config.root_path, params.config.rte.path, params.rte_name, scripts_path, p_name, comp_dst_name, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                            let contents = std::fs::read_to_string(path).expect("panic while opening rte apply.script file");
+                            let path = format!("{}/{}/{}/{}/{}/{}/{}", params.config.root_path, params.config.rte.path, params.rte_name, scripts_path, p_name, comp_dst_name, script.get_str_value(KEY_FILE, &params.rte_name)?);
+                            let contents = read_file(&path)?;
                             let ctx = ScriptRteRenderContext {
                                 rte: params.rte_name.to_string(),
-                                eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
+                                eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
                                 site: dst_site_name.to_string(),
                                 release: "".to_string(),
                                 project: params.config.project.clone(),
@@ -545,12 +638,12 @@ impl<'a> RteCharacteristics for RteTypeA<'a> {
                             };
 
                             let mut commands: Vec<String> = Vec::new();
-                            for command in render_script(&ctx, &contents).lines() {
+                            for command in render_script(&ctx, &contents, &path)?.lines() {
                                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
                             }
 
                             let data: HashMap<String, Vec<String>> = [
-                                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                                (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
                             ].into_iter().collect();
                             scripts.push(data);
                         }
@@ -569,132 +662,171 @@ impl<'a> RteCharacteristics for RteTypeA<'a> {
             }
 
             //Tests
-            let tests_p = self.db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::Runs);
-            for t in tests_p.iter() {
-                let t_job_name = format!("{}_{}_{}_{}",
-                                         params.project_config.module,
-                                         KEY_TEST,
-                                         src_name,
-                                         t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap()
-                ).replace('_', "-");
-
-                //Process test scripts
-                let t_p_base = t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap();
-                let t_p_module = t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap();
-                let t_name = t_p_base.get(KEY_NAME).unwrap().as_str().unwrap();
-                let t_module = t_p_base.get(KEY_MODULE).unwrap().as_str().unwrap();
-                let t_collector = self.db.get_object_neighbour_out(&t.vertex.id, EdgeTypes::TestRefersCollector);
-
-                let collector = match t_collector {
-                    Some(t) => self.db.get_object_properties(&t).unwrap().props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().to_string(),
-                    None => "".to_string()
-                };
-
-                let scripts_path = t_p_module.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
-                let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
+            build_tests_ctx(self.db, &mut params, &src, src_name)?;
+        }
 
-                for script in t_p_module.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
-                    let path = format!("{}/{}/{}/{}/{}", params.config.root_path, params.config.tests.path, t_module, scripts_path, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                    let contents = std::fs::read_to_string(path).expect("panic while opening test script file");
-                    let ctx = ScriptTestRenderContext {
-                        eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                        name: t_name.to_string(),
-                        data: t_p_base.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                        refs: t_p_base.get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
-                        module: t_module.to_string(),
-                        project: params.config.project.clone(),
-                        rte_provider: src_name.to_string(),
-                        artifacts_path: "".to_string(),
-                        rte_name: "".to_string(),
-                        rte_module: "".to_string(),
-                        rte_artifacts_path: "".to_string(),
-                    };
+        Ok(())
+    }
+}
 
-                    let mut commands: Vec<String> = Vec::new();
-                    for command in render_script(&ctx, &contents).lines() {
-                        commands.push(format!("{:indent$}{}", "", command, indent = 0));
-                    }
+/// Test and verification render contexts of tests run by connection source `src`. Shared by rte
+/// types whose tests run on the connection source site.
+fn build_tests_ctx(db: &Db, params: &mut RteCtxParameters, src: &VertexProperties, src_name: &str) -> Result<()> {
+    let tests_p = db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::Runs);
+    for t in tests_p.iter() {
+        let t_job_name = format!("{}_{}_{}_{}",
+                                 params.config.job_prefix(),
+                                 KEY_TEST,
+                                 src_name,
+                                 t.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?
+        ).replace('_', "-");
+
+        //Process test scripts
+        let t_p_base = t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap();
+        let t_p_module = t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap();
+        let t_name = t_p_base.get_str_value(KEY_NAME, &params.rte_name)?;
+        let t_module = t_p_base.get_str_value(KEY_MODULE, &params.rte_name)?;
+        let t_collector = db.get_object_neighbour_out(&t.vertex.id, EdgeTypes::TestRefersCollector);
+
+        let collector = match t_collector {
+            Some(t) => db.get_object_properties(&t).unwrap().props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get_value(KEY_MODULE, &params.rte_name)?.to_string(),
+            None => "".to_string()
+        };
 
-                    let data: HashMap<String, Vec<String>> = [
-                        (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
-                    ].into_iter().collect();
-                    scripts.push(data);
-                }
+        let scripts_path = t_p_module.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
+        let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
 
-                //Verifications
-                let verifications_p = self.db.get_object_neighbours_with_properties_out(&t.vertex.id, EdgeTypes::Needs);
-                let mut verifications: Vec<RteVerificationRenderContext> = Vec::new();
-                for v in verifications_p.iter() {
-                    let v_job_name = format!("{}_{}_{}_{}_{}",
-                                             KEY_VERIFICATION,
-                                             params.rte_name,
-                                             src_name,
-                                             &t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                                             v.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap(),
-                    ).replace('_', "-");
+        for script in t_p_module.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
+            let path = format!("{}/{}/{}/{}/{}", params.config.root_path, params.config.tests.path, t_module, scripts_path, script.get_str_value(KEY_FILE, &params.rte_name)?);
+            let contents = read_file(&path)?;
+            let ctx = ScriptTestRenderContext {
+                eut: params.eut.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                name: t_name.to_string(),
+                data: t_p_base.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+                refs: t_p_base.get_object_value(KEY_REF_ARTIFACTS_PATH, &params.rte_name)?.clone(),
+                module: t_module.to_string(),
+                project: params.config.project.clone(),
+                rte_provider: src_name.to_string(),
+                artifacts_path: "".to_string(),
+                rte_name: "".to_string(),
+                rte_module: "".to_string(),
+                rte_artifacts_path: "".to_string(),
+            };
 
-                    //Process verification scripts
-                    let v_name = v.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap();
-                    let v_module = v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap();
-                    let v_data = v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_DATA).unwrap().as_str().unwrap();
-                    let scripts_path = v.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
-                    let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-                    for script in v.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
-                        let path = format!("{}/{}/{}/{}/{}", params.config.root_path, params.config.verifications.path, v_module, scripts_path, script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                        let contents = std::fs::read_to_string(path).expect("panic while opening test script file");
-                        let ctx = ScriptVerificationRenderContext {
-                            name: v_name.to_string(),
-                            data: v_data.to_string(),
-                            module: v_module.to_string(),
-                            provider: src_name.to_string(),
-                            collector: collector.clone(),
-                            test_name: t_name.to_string(),
-                            test_module: t_module.to_string(),
-                            test_artifacts_path: "".to_string(),
-                            rte_name: params.rte_name.to_string(),
-                            rte_module: params.rte_name.to_string(),
-                            rte_artifacts_path: "".to_string(),
-                        };
+            let mut commands: Vec<String> = Vec::new();
+            for command in render_script(&ctx, &contents, &path)?.lines() {
+                commands.push(format!("{:indent$}{}", "", command, indent = 0));
+            }
 
-                        let mut commands: Vec<String> = Vec::new();
-                        for command in render_script(&ctx, &contents).lines() {
-                            commands.push(format!("{:indent$}{}", "", command, indent = 0));
-                        }
+            let data: HashMap<String, Vec<String>> = [
+                (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
+            ].into_iter().collect();
+            scripts.push(data);
+        }
 
-                        let data: HashMap<String, Vec<String>> = [
-                            (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
-                        ].into_iter().collect();
-                        scripts.push(data);
-                    }
+        //Verifications
+        let verifications_p = db.get_object_neighbours_with_properties_out(&t.vertex.id, EdgeTypes::Needs);
+        let mut verifications: Vec<RteVerificationRenderContext> = Vec::new();
+        for v in verifications_p.iter() {
+            let v_job_name = format!("{}_{}_{}_{}_{}_{}",
+                                     params.config.job_prefix(),
+                                     KEY_VERIFICATION,
+                                     params.rte_name,
+                                     src_name,
+                                     &t.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?,
+                                     v.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?,
+            ).replace('_', "-");
+
+            //Process verification scripts
+            let v_name = v.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?;
+            let v_module = v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_MODULE, &params.rte_name)?;
+            let v_data = v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_DATA, &params.rte_name)?;
+            let scripts_path = v.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
+            let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
+            for script in v.props.get(PropertyType::Module.index()).unwrap().value.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
+                let path = format!("{}/{}/{}/{}/{}", params.config.root_path, params.config.verifications.path, v_module, scripts_path, script.get_str_value(KEY_FILE, &params.rte_name)?);
+                let contents = read_file(&path)?;
+                let ctx = ScriptVerificationRenderContext {
+                    name: v_name.to_string(),
+                    data: v_data.to_string(),
+                    module: v_module.to_string(),
+                    provider: src_name.to_string(),
+                    collector: collector.clone(),
+                    test_name: t_name.to_string(),
+                    test_module: t_module.to_string(),
+                    test_artifacts_path: "".to_string(),
+                    rte_name: params.rte_name.to_string(),
+                    rte_module: params.rte_name.to_string(),
+                    rte_artifacts_path: "".to_string(),
+                };
 
-                    let rte_vrc = RteVerificationRenderContext {
-                        ci: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_CI).unwrap().as_object().unwrap().clone(),
-                        test: t_name.to_string(),
-                        rte: params.rte_name.to_string(),
-                        job: v_job_name,
-                        name: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                        module: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                        data: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                        scripts,
-                    };
-                    verifications.push(rte_vrc);
+                let mut commands: Vec<String> = Vec::new();
+                for command in render_script(&ctx, &contents, &path)?.lines() {
+                    commands.push(format!("{:indent$}{}", "", command, indent = 0));
                 }
 
-                let rterc = RteTestRenderContext {
-                    ci: t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_CI).unwrap().as_object().unwrap().clone(),
-                    rte: params.rte_name.to_string(),
-                    job: t_job_name,
-                    name: t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                    data: t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                    module: t.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                    provider: src_name.to_string(),
-                    scripts,
-                    verifications,
-                };
-                params.rte_crcs.tests.push(rterc);
+                let data: HashMap<String, Vec<String>> = [
+                    (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
+                ].into_iter().collect();
+                scripts.push(data);
             }
+
+            let rte_vrc = RteVerificationRenderContext {
+                ci: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get_object_value(KEY_CI, &params.rte_name)?.clone(),
+                test: t_name.to_string(),
+                rte: params.rte_name.to_string(),
+                job: v_job_name,
+                name: v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                module: v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
+                data: v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+                tags: strings(v.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_TAGS)),
+                stage: stage(v.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_STAGE)),
+                scripts,
+            };
+            verifications.push(rte_vrc);
         }
+
+        let rterc = RteTestRenderContext {
+            id: t.vertex.id,
+            ci: t.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get_object_value(KEY_CI, &params.rte_name)?.clone(),
+            rte: params.rte_name.to_string(),
+            job: t_job_name,
+            name: t.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+            data: t.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+            module: t.props.get(PropertyType::Module.index()).unwrap().value.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
+            provider: src_name.to_string(),
+            tags: strings(t.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_TAGS)),
+            stage: stage(t.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_STAGE)),
+            needs: strings(t.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_NEEDS)),
+            after: strings(t.props.get(PropertyType::Base.index()).unwrap().value.get(KEY_AFTER)),
+            scripts,
+            verifications,
+        };
+        params.rte_crcs.tests.push(rterc);
     }
+
+    Ok(())
+}
+
+/// Error for `object` whose edge to the `type` object named `name` is missing.
+fn dangling_reference(object: &VertexProperties, r#type: &str, name: &str) -> Sense8Error {
+    let id_path = object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(KEY_ID_PATH))
+        .and_then(|p| p.as_array())
+        .map(|p| p.iter().filter_map(|c| c.as_str()).collect::<Vec<_>>().join("__"))
+        .unwrap_or_default();
+
+    Sense8Error::DanglingReference { id_path, r#type: r#type.to_string(), name: name.to_string() }
+}
+
+fn strings(value: Option<&Value>) -> Vec<String> {
+    value.and_then(|t| t.as_array())
+        .map(|t| t.iter().filter_map(|t| t.as_str()).map(|t| t.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn stage(value: Option<&Value>) -> String {
+    value.and_then(|s| s.as_str()).unwrap_or_default().to_string()
 }
 
 struct RteTypeB<'a> {
@@ -702,8 +834,8 @@ struct RteTypeB<'a> {
 }
 
 impl<'a> RteCharacteristics for RteTypeB<'a> {
-    fn init<'b>(&self, rte: &Box<Rte<'b>>) {
-        error!("Init RTE TYPE B connection component --> {}", &rte.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap());
+    fn init<'b>(&self, rte: &Box<Rte<'b>>) -> Result<()> {
+        error!("Init RTE TYPE B connection component --> {}", &rte.get_base_properties().get_str_value(KEY_NAME, &rte.get_id_path().get_str())?);
         // Connection -> Component
         let _c = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasConnections);
         let connections = self.db.get_object_neighbours_out(&_c.unwrap().id, EdgeTypes::HasConnection);
@@ -712,36 +844,38 @@ impl<'a> RteCharacteristics for RteTypeB<'a> {
             let c_s = self.db.get_object_neighbour_with_properties_out(&c.id, EdgeTypes::HasConnectionSrc).unwrap();
             let _components = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasComponents);
             let component_src = self.db.get_object_neighbour_out(&_components.unwrap().id, EdgeTypes::HasComponentSrc);
-            self.db.create_relationship(&c_s.vertex, &component_src.unwrap());
+            self.db.create_relationship(&c_s.vertex, &component_src.unwrap())?;
         }
 
-        error!("Init RTE TYPE B connection component: {} --> Done.",  &rte.get_base_properties().get(KEY_NAME).unwrap().as_str().unwrap());
+        error!("Init RTE TYPE B connection component: {} --> Done.",  &rte.get_base_properties().get_str_value(KEY_NAME, &rte.get_id_path().get_str())?);
+
+        Ok(())
     }
 
-    fn build_conn_ctx(&self, params: RteCtxParameters) {
+    fn build_conn_ctx(&self, params: RteCtxParameters) -> Result<()> {
         error!("RTE TYPE B build conn ctx --> {}", params.rte_name);
-        let project = Project::load(&self.db, &params.project.id, &params.config);
-        let eut = Eut::load(self.db, &project, &params.config);
+        let project = Project::load(&self.db, &params.project.id, &params.config)?;
+        let eut = Eut::load(self.db, project.as_ref(), &params.config)?;
         let eut_base_p = eut.get_base_properties();
-        let eut_module = eut_base_p.get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
-        let rte = Rte::load(&self.db, &params.rte, &params.config);
+        let eut_module = eut_base_p.get_str_value(KEY_MODULE, &params.rte_name)?.to_string();
+        let rte = Rte::load(&self.db, &params.rte, &params.config)?;
         let rte_base_p = rte.get_base_properties();
-        let rte_provider = rte_base_p.get(KEY_PROVIDER).unwrap().as_str().unwrap();
+        let rte_provider = rte_base_p.get_str_value(KEY_PROVIDER, &params.rte_name)?;
         let _connections = Connections::load_collection(&self.db, &rte.get_object(), &params.config);
-        let connections = Connections::load(&self.db, &_connections.get_object(), &params.config);
+        let connections = Connections::load(&self.db, &_connections.get_object(), &params.config)?;
 
         for c in connections {
-            let c_src = ConnectionSource::load(&self.db, &c.get_object(), &params.config);
+            let c_src = ConnectionSource::load(&self.db, &c.get_object(), &params.config)?;
             let c_src_base_p = c_src.get_base_properties();
-            let c_src_name = c_src_base_p.get(KEY_NAME).unwrap().as_str().unwrap();
+            let c_src_name = c_src_base_p.get_str_value(KEY_NAME, &params.rte_name)?;
             let tests = self.db.get_object_neighbours_with_properties_out(&c_src.get_id(), EdgeTypes::Runs);
             let component_src_o = self.db.get_object_neighbour_with_properties_out(&c_src.get_id(), EdgeTypes::HasComponentSrc).unwrap();
-            let component_src = ComponentSource::load(&self.db, &component_src_o, params.config);
+            let component_src = ComponentSource::load(&self.db, &component_src_o, params.config)?;
             let component_src_base_p = component_src.get_base_properties();
-            let component_src_name = component_src_base_p.get(KEY_NAME).unwrap().as_str().unwrap();
+            let component_src_name = component_src_base_p.get_str_value(KEY_NAME, &params.rte_name)?;
 
             let rte_job_name = format!("{}_{}_{}_{}_{}",
-                                       params.project_config.module, KEY_RTE,
+                                       params.config.job_prefix(), KEY_RTE,
                                        params.rte_name,
                                        &rte_provider,
                                        &c_src_name).replace('_', "-");
@@ -757,53 +891,53 @@ impl<'a> RteCharacteristics for RteTypeB<'a> {
             params.rte_crcs.components.push(rte_crc);
 
             for t in tests {
-                let test = Test::load(&self.db, &t.vertex.id, &params.config);
+                let test = Test::load(&self.db, &t.vertex.id, &params.config)?;
                 let test_base_p = test.get_base_properties();
                 let test_module_p = test.get_module_properties();
-                let test_name = test_base_p.get(KEY_NAME).unwrap().as_str().unwrap();
-                let test_module = test_base_p.get(KEY_MODULE).unwrap().as_str().unwrap();
+                let test_name = test_base_p.get_str_value(KEY_NAME, &params.rte_name)?;
+                let test_module = test_base_p.get_str_value(KEY_MODULE, &params.rte_name)?;
                 let t_collector = self.db.get_object_neighbour_out(&t.vertex.id, EdgeTypes::TestRefersCollector);
                 let collector = match t_collector {
                     Some(t) => {
-                        self.db.get_object_properties(&t).unwrap().props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_MODULE).unwrap().to_string()
+                        self.db.get_object_properties(&t).unwrap().props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get_value(KEY_MODULE, &params.rte_name)?.to_string()
                     }
                     None => "".to_string()
                 };
                 let t_job_name = format!("{}_{}_{}",
-                                         params.project_config.module,
+                                         params.config.job_prefix(),
                                          KEY_TEST,
                                          test_name).replace('_', "-");
-                let scripts_path = test_module_p.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+                let scripts_path = test_module_p.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
                 let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
 
-                for script in test_module_p.get(KEY_SCRIPTS).unwrap().as_array().unwrap() {
+                for script in test_module_p.get_array_value(KEY_SCRIPTS, &params.rte_name)? {
                     let path = format!("{}/{}/{}/{}/{}",
                                        params.config.root_path,
                                        params.config.tests.path,
                                        test_module, scripts_path,
-                                       script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                    let contents = std::fs::read_to_string(path).expect("panic while opening test script file");
+                                       script.get_str_value(KEY_FILE, &params.rte_name)?);
+                    let contents = read_file(&path)?;
                     let ctx = ScriptTestRenderContext {
                         eut: eut_module.to_string(),
                         name: test_name.to_string().replace('-', "_"),
-                        data: test_base_p.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                        refs: test_base_p.get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                        data: test_base_p.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+                        refs: test_base_p.get_object_value(KEY_REF_ARTIFACTS_PATH, &params.rte_name)?.clone(),
                         module: test_module.to_string(),
                         project: params.config.project.clone(),
-                        artifacts_path: test_base_p.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
-                        rte_name: rte_base_p.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                        rte_module: rte_base_p.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
+                        artifacts_path: test_base_p.get_str_value(KEY_ARTIFACTS_PATH, &params.rte_name)?.to_string(),
+                        rte_name: rte_base_p.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                        rte_module: rte_base_p.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
                         rte_provider: rte_provider.to_string(),
-                        rte_artifacts_path: rte_base_p.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
+                        rte_artifacts_path: rte_base_p.get_str_value(KEY_ARTIFACTS_PATH, &params.rte_name)?.to_string(),
                     };
 
                     let mut commands: Vec<String> = Vec::new();
-                    for command in render_script(&ctx, &contents).lines() {
+                    for command in render_script(&ctx, &contents, &path)?.lines() {
                         commands.push(format!("{:indent$}{}", "", command, indent = 0));
                     }
 
                     let data: HashMap<String, Vec<String>> = [
-                        (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                        (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
                     ].into_iter().collect();
                     scripts.push(data);
                 }
@@ -816,23 +950,23 @@ impl<'a> RteCharacteristics for RteTypeB<'a> {
                     //Process verification scripts
                     let v_p_base = v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap();
                     let v_p_module = v.props.get(PropertyType::Module.index()).unwrap().value.as_object().unwrap();
-                    let v_name = v_p_base.get(KEY_NAME).unwrap().as_str().unwrap();
-                    let v_data = v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_DATA).unwrap().as_str().unwrap();
-                    let v_module = v_p_base.get(KEY_MODULE).unwrap().as_str().unwrap();
+                    let v_name = v_p_base.get_str_value(KEY_NAME, &params.rte_name)?;
+                    let v_data = v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_DATA, &params.rte_name)?;
+                    let v_module = v_p_base.get_str_value(KEY_MODULE, &params.rte_name)?;
                     let v_job_name = format!("{}_{}_{}",
-                                             params.project_config.module,
+                                             params.config.job_prefix(),
                                              KEY_VERIFICATION,
                                              v_name).replace('_', "-");
-                    let scripts_path = v_p_module.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+                    let scripts_path = v_p_module.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
                     let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-                    for script in v_p_module.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+                    for script in v_p_module.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
                         let path = format!("{}/{}/{}/{}/{}",
                                            params.config.root_path,
                                            params.config.verifications.path,
                                            v_module,
                                            scripts_path,
-                                           script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-                        let contents = std::fs::read_to_string(path).expect("panic while opening test script file");
+                                           script.get_str_value(KEY_FILE, &params.rte_name)?);
+                        let contents = read_file(&path)?;
                         let ctx = ScriptVerificationRenderContext {
                             name: v_name.to_string(),
                             data: v_data.to_string(),
@@ -841,50 +975,174 @@ impl<'a> RteCharacteristics for RteTypeB<'a> {
                             collector: collector.clone(),
                             test_name: test_name.to_string(),
                             test_module: test_module.to_string(),
-                            test_artifacts_path: test_base_p.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
-                            rte_name: rte_base_p.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                            rte_module: rte_base_p.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                            rte_artifacts_path: rte_base_p.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
+                            test_artifacts_path: test_base_p.get_str_value(KEY_ARTIFACTS_PATH, &params.rte_name)?.to_string(),
+                            rte_name: rte_base_p.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                            rte_module: rte_base_p.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
+                            rte_artifacts_path: rte_base_p.get_str_value(KEY_ARTIFACTS_PATH, &params.rte_name)?.to_string(),
                         };
 
                         let mut commands: Vec<String> = Vec::new();
-                        for command in render_script(&ctx, &contents).lines() {
+                        for command in render_script(&ctx, &contents, &path)?.lines() {
                             commands.push(format!("{:indent$}{}", "", command, indent = 0));
                         }
 
                         let data: HashMap<String, Vec<String>> = [
-                            (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                            (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
                         ].into_iter().collect();
                         scripts.push(data);
                     }
 
                     let rte_vrc = RteVerificationRenderContext {
-                        ci: v_p_base.get(KEY_CI).unwrap().as_object().unwrap().clone(),
+                        ci: v_p_base.get_object_value(KEY_CI, &params.rte_name)?.clone(),
                         test: test_name.to_string(),
                         rte: params.rte_name.to_string(),
                         job: v_job_name,
-                        name: v_p_base.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                        module: v_p_base.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                        data: v.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
+                        name: v_p_base.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                        module: v_p_base.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
+                        data: v.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+                        tags: strings(v_p_base.get(KEY_TAGS)),
+                        stage: stage(v_p_base.get(KEY_STAGE)),
                         scripts,
                     };
                     verifications.push(rte_vrc);
                 }
 
                 let rtetrc = RteTestRenderContext {
-                    ci: test_base_p.get(KEY_CI).unwrap().as_object().unwrap().clone(),
+                    id: t.vertex.id,
+                    ci: test_base_p.get_object_value(KEY_CI, &params.rte_name)?.clone(),
                     rte: params.rte_name.to_string(),
                     job: t_job_name,
-                    name: test_base_p.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                    data: test_base_p.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                    module: test_base_p.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
+                    name: test_base_p.get_str_value(KEY_NAME, &params.rte_name)?.to_string(),
+                    data: test_base_p.get_str_value(KEY_DATA, &params.rte_name)?.to_string(),
+                    module: test_base_p.get_str_value(KEY_MODULE, &params.rte_name)?.to_string(),
                     provider: c_src_name.to_string(),
+                    tags: strings(test_base_p.get(KEY_TAGS)),
+                    stage: stage(test_base_p.get(KEY_STAGE)),
+                    needs: strings(test_base_p.get(KEY_NEEDS)),
+                    after: strings(test_base_p.get(KEY_AFTER)),
                     scripts,
                     verifications,
                 };
                 params.rte_crcs.tests.push(rtetrc);
             }
         }
+
+        Ok(())
+    }
+}
+
+/// Any-to-any rte. Connection source and all sites matched by connection destinations are peers
+/// running the source component. Each peer gets the sites of all other peers as destinations.
+struct RteTypeMesh<'a> {
+    db: &'a Db,
+}
+
+impl<'a> RteCharacteristics for RteTypeMesh<'a> {
+    fn init<'b>(&self, rte: &Box<Rte<'b>>) -> Result<()> {
+        error!("Init RTE TYPE MESH connection components --> {}", &rte.get_base_properties().get_str_value(KEY_NAME, &rte.get_id_path().get_str())?);
+        // Connection -> Component
+        let _c = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasConnections);
+        let connections = self.db.get_object_neighbours_out(&_c.unwrap().id, EdgeTypes::HasConnection);
+        let _components = self.db.get_object_neighbour_out(&rte.get_id(), EdgeTypes::HasComponents).unwrap();
+        let component_src = self.db.get_object_neighbour_out(&_components.id, EdgeTypes::HasComponentSrc).unwrap();
+
+        for c in connections.iter() {
+            let c_s = self.db.get_object_neighbour_with_properties_out(&c.id, EdgeTypes::HasConnectionSrc).unwrap();
+            self.db.create_relationship(&c_s.vertex, &component_src)?;
+
+            for c_d in self.db.get_object_neighbours_out(&c_s.vertex.id, EdgeTypes::HasConnectionDst).iter() {
+                self.db.create_relationship(c_d, &component_src)?;
+            }
+        }
+
+        info!("Init rte type mesh connection components -> Done.");
+
+        Ok(())
+    }
+
+    fn build_conn_ctx(&self, mut params: RteCtxParameters) -> Result<()> {
+        error!("RTE TYPE MESH build conn ctx --> {}", params.rte_name);
+        let rte = Rte::load(&self.db, &params.rte, &params.config)?;
+        let rte_base_p = rte.get_base_properties();
+        let rte_module = rte_base_p.get_str_value(KEY_MODULE, &params.rte_name)?.to_string();
+        let rte_provider = rte_base_p.get_str_value(KEY_PROVIDER, &params.rte_name)?.to_string();
+        let rte_artifacts_path = rte_base_p.get_str_value(KEY_ARTIFACTS_PATH, &params.rte_name)?.to_string();
+        let _c = self.db.get_object_neighbour_out(&params.rte.vertex.id, EdgeTypes::HasConnections);
+        let connections = self.db.get_object_neighbours_with_properties_out(&_c.unwrap().id, EdgeTypes::HasConnection);
+
+        for conn in connections.iter() {
+            let connection_name = conn.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string();
+            let src = self.db.get_object_neighbour_with_properties_out(&conn.vertex.id, EdgeTypes::HasConnectionSrc).unwrap();
+            let src_name = src.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string();
+            let comp_src = self.db.get_object_neighbour_with_properties_out(&src.vertex.id, EdgeTypes::HasComponentSrc).unwrap();
+            let comp_src_p = comp_src.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap();
+            let comp_src_name = comp_src_p.get_str_value(KEY_NAME, &params.rte_name)?;
+            let scripts_path = comp_src_p.get_str_value(KEY_SCRIPTS_PATH, &params.rte_name)?;
+
+            //Peers are connection source site and all sites referred by connection destinations
+            let mut peers: Vec<(String, String)> = Vec::new();
+            let mut endpoints = vec![src.clone()];
+            endpoints.extend(self.db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::HasConnectionDst));
+
+            for endpoint in endpoints.iter() {
+                for site in self.db.get_object_neighbours_with_properties_out(&endpoint.vertex.id, EdgeTypes::RefersSite).iter() {
+                    let site_name = site.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string();
+                    let provider = self.db.get_object_neighbour_with_properties_out(&site.vertex.id, EdgeTypes::UsesProvider)
+                        .ok_or_else(|| dangling_reference(site, KEY_PROVIDER, &site_name))?;
+                    let p_name = provider.props.get(PropertyType::Base.index()).unwrap().value.get_str_value(KEY_NAME, &params.rte_name)?.to_string();
+
+                    if !peers.iter().any(|(s, _)| s == &site_name) {
+                        peers.push((site_name, p_name));
+                    }
+                }
+            }
+
+            for (site_name, p_name) in peers.iter() {
+                let destinations: Vec<&String> = peers.iter().map(|(s, _)| s).filter(|s| *s != site_name).collect();
+                let rte_job_name = format!("{}_{}_{}_{}_{}_{}_{}", params.config.job_prefix(), KEY_RTE, params.rte_name, &connection_name, p_name, site_name, comp_src_name).replace('_', "-");
+                let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
+
+                for script in comp_src_p.get_array_value(KEY_SCRIPTS, &params.rte_name)?.iter() {
+                    let path = format!("{}/{}/{}/{}/{}/{}/{}", params.config.root_path, params.config.rte.path, rte_module, scripts_path, rte_provider, comp_src_name, script.get_str_value(KEY_FILE, &params.rte_name)?);
+                    let contents = read_file(&path)?;
+                    let ctx = ScriptRteRenderContext {
+                        eut: params.config.eut.module.to_string(),
+                        site: site_name.to_string(),
+                        base: rte_base_p.clone(),
+                        module: rte.get_module_properties(),
+                        release: "".to_string(),
+                        project: params.config.project.clone(),
+                        provider: p_name.to_string(),
+                        destinations: serde_json::to_string(&destinations).unwrap(),
+                        artifacts_path: rte_artifacts_path.clone(),
+                    };
+
+                    let mut commands: Vec<String> = Vec::new();
+                    for command in render_script(&ctx, &contents, &path)?.lines() {
+                        commands.push(format!("{:indent$}{}", "", command, indent = 0));
+                    }
+
+                    let data: HashMap<String, Vec<String>> = [
+                        (script.get_str_value(KEY_SCRIPT, &params.rte_name)?.to_string(), commands),
+                    ].into_iter().collect();
+                    scripts.push(data);
+                }
+
+                params.rte_crcs.components.push(RteComponentRenderContext {
+                    job: rte_job_name,
+                    rte: params.rte_name.to_string(),
+                    name: comp_src_name.to_string(),
+                    site: site_name.to_string(),
+                    provider: p_name.to_string(),
+                    scripts,
+                });
+            }
+
+            //Tests
+            build_tests_ctx(self.db, &mut params, &src, &src_name)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -895,21 +1153,16 @@ struct RteType<T> {
 
 impl<'a> RteType<Box<dyn RteCharacteristics + 'a>> {
     fn new(rte_type: &str, db: &'a Db) -> Option<RteType<Box<dyn RteCharacteristics + 'a>>> {
-        if rte_type == RTE_TYPE_A {
-            Some(Self { rte: Box::new(RteTypeA { db }), _type: RTE_TYPE_A.to_string() })
-        } else if rte_type == RTE_TYPE_B {
-            Some(Self { rte: Box::new(RteTypeB { db }), _type: RTE_TYPE_B.to_string() })
-        } else {
-            None
-        }
+        let factory = rte_type_registry().read().unwrap().get(rte_type).map(|entry| entry.factory)?;
+        Some(Self { rte: factory(db), _type: rte_type.to_string() })
     }
 
-    fn init(&self, rte: &Box<Rte<'_>>) {
-        self.rte.init(rte);
+    fn init(&self, rte: &Box<Rte<'_>>) -> Result<()> {
+        self.rte.init(rte)
     }
 
-    fn build_conn_ctx(&self, params: RteCtxParameters) {
-        self.rte.build_conn_ctx(params);
+    fn build_conn_ctx(&self, params: RteCtxParameters) -> Result<()> {
+        self.rte.build_conn_ctx(params)
     }
 }
 
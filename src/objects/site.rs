@@ -11,6 +11,7 @@ use crate::constants::{KEY_APPLICATION, KEY_ARTIFACTS_PATH, KEY_FILE, KEY_ID_PAT
                        KEY_MODULE, KEY_NAME, KEY_PROVIDER, KEY_RELEASE, KEY_SCRIPT, KEY_SCRIPTS,
                        KEY_SCRIPTS_PATH};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext};
@@ -26,18 +27,18 @@ pub struct Site<'a> {
 }
 
 impl<'a> Site<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new eut site object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Site, &mut path, label, pop);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Site, &mut path, label, pop)?;
         let artifacts_path = format!("{}/{}/{}",
                                      config.eut.artifacts_dir,
                                      config.eut.module,
                                      config.eut.artifacts_file);
         let mut _base_cfg = base_cfg.as_object().unwrap().clone();
         _base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base);
+        db.add_object_property(&o, &json!(_base_cfg), PropertyType::Base)?;
 
-        Box::new(Site {
+        Ok(Box::new(Site {
             object: Object {
                 db,
                 id: o.id,
@@ -45,15 +46,15 @@ impl<'a> Site<'a> {
                 vertex: o,
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Box<(dyn SiteExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, object: &VertexProperties, _config: &RegressionConfig) -> Result<Box<(dyn SiteExt<'a> + 'a)>> {
         error!("Loading eut site object");
         let arr = object.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
 
-        Box::new(Site {
+        Ok(Box::new(Site {
             object: Object {
                 db,
                 id: object.vertex.id,
@@ -61,7 +62,7 @@ impl<'a> Site<'a> {
                 vertex: object.vertex.clone(),
                 module_cfg: json!(null),
             },
-        })
+        }))
     }
 }
 
@@ -78,68 +79,70 @@ impl RenderContext for Site<'_> {
 }
 
 impl Renderer<'_> for Site<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
         let job: String;
-        let provider: String = self.get_base_properties().get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string();
+        let provider: String = self.get_base_properties().get_str_value(KEY_PROVIDER, id_path)?.to_string();
 
         if provider.len() > 0 {
-            job = format!("{}_{}_{}_{}", config.project.module, KEY_APPLICATION, self.get_module_properties().get(KEY_NAME).unwrap().as_str().unwrap(), provider).replace('_', "-")
+            job = format!("{}_{}_{}_{}", config.job_prefix(), KEY_APPLICATION, self.get_module_properties().get_str_value(KEY_NAME, id_path)?, provider).replace('_', "-")
         } else {
-            job = format!("{}_{}_{}", config.project.module, KEY_APPLICATION, self.get_module_properties().get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-")
+            job = format!("{}_{}_{}", config.job_prefix(), KEY_APPLICATION, self.get_module_properties().get_str_value(KEY_NAME, id_path)?).replace('_', "-")
         }
 
-        Box::new(EutSiteRenderContext {
+        Ok(Box::new(EutSiteRenderContext {
             job,
             name: "".to_string(),
             index: 0,
             scripts: scripts.clone(),
             provider,
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let m_props: Map<String, Value> = self.get_module_properties();
         let base_props: Map<String, Value> = self.get_base_properties();
-        let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}",
                                config.root_path,
                                config.applications.path, module,
                                scripts_path,
-                               script.as_object().unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening eut site script file");
+                               script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
 
             let ctx = ScriptEutRenderContext {
                 //eut: config.eut.module.to_string(),
                 rte: "".to_string(),
                 name: module.to_string(),
-                //data: base_props.get(KEY_DATA).unwrap().as_str().unwrap().to_string(),
-                //refs: base_props.get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                //data: base_props.get_str_value(KEY_DATA, id_path)?.to_string(),
+                //refs: base_props.get_object_value(KEY_REF_ARTIFACTS_PATH, id_path)?.clone(),
                 site: "".to_string(),
                 rtes: vec![],
                 index: 0,
                 counter: 0,
-                release: m_props.get(KEY_RELEASE).unwrap().as_str().unwrap().to_string(),
-                provider: base_props.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string(),
+                release: m_props.get_str_value(KEY_RELEASE, id_path)?.to_string(),
+                provider: base_props.get_str_value(KEY_PROVIDER, id_path)?.to_string(),
                 project: config.project.clone(),
-                artifacts_path: base_props.get(KEY_ARTIFACTS_PATH).unwrap().as_str().unwrap().to_string(),
+                artifacts_path: base_props.get_str_value(KEY_ARTIFACTS_PATH, id_path)?.to_string(),
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
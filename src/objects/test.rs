@@ -6,8 +6,9 @@ use serde_json::{json, Map, Value};
 use uuid::Uuid;
 
 use crate::{EdgeTypes, PropertyType, RegressionConfig, render_script, RenderContext, Renderer, RteTestRenderContext, ScriptTestRenderContext};
-use crate::constants::{KEY_ARTIFACTS_PATH, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_PROVIDER, KEY_REF_ARTIFACTS_PATH, KEY_SCRIPT, KEY_SCRIPTS, KEY_SCRIPTS_PATH, KEY_TEST};
+use crate::constants::{KEY_ARTIFACTS_PATH, KEY_CONNECTION, KEY_DEPENDS_ON, KEY_FILE, KEY_ID_PATH, KEY_MODULE, KEY_NAME, KEY_PROVIDER, KEY_REF_ARTIFACTS_PATH, KEY_SCRIPT, KEY_RTE, KEY_SCRIPTS, KEY_SCRIPTS_PATH, KEY_TAGS, KEY_TEST, KEY_TYPE};
 use crate::db::Db;
+use crate::error::{read_file, PropertiesExt, Result};
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config, Rte};
@@ -23,33 +24,34 @@ pub struct Test<'a> {
 }
 
 impl<'a> Test<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, parent: &Vertex, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new test object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Test, &mut path, label, pop);
-        db.create_relationship(&parent, &o);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Test, &mut path, label, pop)?;
+        db.create_relationship(&parent, &o)?;
         let c_src = db.get_object_neighbour_in_out_id(&o.id, EdgeTypes::Runs, VertexTypes::ConnectionSrc).unwrap();
         let connection = db.get_object_neighbour_in_out_id(&c_src.id, EdgeTypes::HasConnectionSrc, VertexTypes::Connection).unwrap();
         let connections = db.get_object_neighbour_in_out_id(&connection.id, EdgeTypes::HasConnection, VertexTypes::Connections).unwrap();
         let rte_o = db.get_object_neighbour_in_out_id(&connections.id, EdgeTypes::HasConnections, VertexTypes::Rte).unwrap();
         let rte_o_p = db.get_object_properties(&rte_o).unwrap();
-        let rte = Rte::load(&db, &rte_o_p, &config);
+        let rte = Rte::load(&db, &rte_o_p, &config)?;
         let rte_base_p = rte.get_base_properties();
         let artifacts_path = format!("{}/{}/{}/{}/{}/{}/{}",
                                      config.tests.artifacts_dir,
-                                     rte_base_p.get(KEY_NAME).unwrap().as_str().unwrap().to_string(),
-                                     rte_base_p.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                                     rte_base_p.get(KEY_PROVIDER).unwrap().as_str().unwrap().to_string(),
-                                     base_cfg.get(KEY_MODULE).unwrap().as_str().unwrap().to_string(),
-                                     base_cfg.get(KEY_NAME).unwrap().as_str().unwrap().to_string().replace("-", "_"),
+                                     rte_base_p.get_str_value(KEY_NAME, &id_path.get_str())?.to_string(),
+                                     rte_base_p.get_str_value(KEY_MODULE, &id_path.get_str())?.to_string(),
+                                     rte_base_p.get_str_value(KEY_PROVIDER, &id_path.get_str())?.to_string(),
+                                     base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?.to_string(),
+                                     base_cfg.get_str_value(KEY_NAME, &id_path.get_str())?.to_string().replace("-", "_"),
                                      config.tests.artifacts_file);
         let mut _base_cfg = base_cfg.as_object().unwrap().clone();
         _base_cfg.insert(KEY_ARTIFACTS_PATH.to_string(), json!(artifacts_path));
-        db.add_object_property(&o, &_base_cfg, PropertyType::Base);
+        _base_cfg.entry(KEY_TAGS).or_insert(json!([]));
+        db.add_object_property(&o, &_base_cfg, PropertyType::Base)?;
         let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o),
-                                            base_cfg.as_object().unwrap().get(KEY_MODULE).unwrap().as_str().unwrap(), &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+                                            base_cfg.get_str_value(KEY_MODULE, &id_path.get_str())?, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
-        Box::new(Test {
+        Ok(Box::new(Test {
             object: Object {
                 db,
                 id: o.id,
@@ -57,19 +59,19 @@ impl<'a> Test<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 
-    pub fn load(db: &'a Db, id: &Uuid, config: &RegressionConfig) -> Box<(dyn TestExt<'a> + 'a)> {
+    pub fn load(db: &'a Db, id: &Uuid, config: &RegressionConfig) -> Result<Box<(dyn TestExt<'a> + 'a)>> {
         error!("Loading test object");
         let o = db.get_object_with_properties(&id);
         let p_base = o.props.get(PropertyType::Base.index()).unwrap();
         let arr = o.props.get(PropertyType::Base.index()).unwrap().value.as_object().unwrap().get(KEY_ID_PATH).unwrap().as_array().unwrap();
         let id_path = IdPath::load_from_array(arr.iter().map(|c| c.as_str().unwrap().to_string()).collect());
         let module = p_base.value.get(KEY_MODULE).unwrap().as_str().unwrap();
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config);
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o.vertex), module, &config)?;
 
-        Box::new(Test {
+        Ok(Box::new(Test {
             object: Object {
                 db,
                 id: o.vertex.id,
@@ -77,7 +79,7 @@ impl<'a> Test<'a> {
                 vertex: o.vertex.clone(),
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
@@ -93,40 +95,59 @@ impl RenderContext for Test<'_> {
     }
 }
 
+/// Test refs of `depends_on` entries of test configuration `test`. Entries without rte or
+/// connection refer to tests of `rte` and `connection`.
+pub(crate) fn dependency_refs(test: &Value, rte: &str, connection: &str) -> Vec<Value> {
+    test.get(KEY_DEPENDS_ON).and_then(|d| d.as_array()).into_iter().flatten()
+        .map(|d| json!({
+            KEY_TYPE: KEY_TEST,
+            KEY_NAME: d.get(KEY_NAME).and_then(|n| n.as_str()).unwrap_or_default(),
+            KEY_RTE: d.get(KEY_RTE).and_then(|r| r.as_str()).unwrap_or(rte),
+            KEY_CONNECTION: d.get(KEY_CONNECTION).and_then(|c| c.as_str()).unwrap_or(connection),
+        }))
+        .collect()
+}
+
 impl Renderer<'_> for Test<'_> {
-    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Box<dyn RenderContext> {
-        Box::new(RteTestRenderContext {
+    fn gen_render_ctx(&self, config: &RegressionConfig, scripts: Vec<HashMap<String, Vec<String>>>) -> Result<Box<dyn RenderContext>> {
+        let id_path = &self.get_id_path().get_str();
+        Ok(Box::new(RteTestRenderContext {
+            id: self.get_id(),
             ci: Default::default(),
             rte: "".to_string(),
-            job: format!("{}_{}_{}", config.project.module, KEY_TEST, self.get_module_properties()
-                .get(KEY_NAME).unwrap().as_str().unwrap()).replace('_', "-"),
+            job: format!("{}_{}_{}", config.job_prefix(), KEY_TEST, self.get_module_properties()
+                .get_str_value(KEY_NAME, id_path)?).replace('_', "-"),
             name: "".to_string(),
             data: "".to_string(),
             module: "".to_string(),
             provider: "".to_string(),
+            tags: vec![],
+            stage: "".to_string(),
+            needs: vec![],
+            after: vec![],
             scripts: scripts.clone(),
             verifications: vec![],
-        })
+        }))
     }
 
-    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Vec<HashMap<String, Vec<String>>> {
+    fn gen_script_render_ctx(&self, config: &RegressionConfig) -> Result<Vec<HashMap<String, Vec<String>>>> {
+        let id_path = &self.get_id_path().get_str();
         let mut scripts: Vec<HashMap<String, Vec<String>>> = Vec::new();
-        let module = self.get_base_properties().get(KEY_MODULE).unwrap().as_str().unwrap().to_string();
+        let module = self.get_base_properties().get_str_value(KEY_MODULE, id_path)?.to_string();
         let m_props: Map<String, Value> = self.get_module_properties();
-        let scripts_path = m_props.get(KEY_SCRIPTS_PATH).unwrap().as_str().unwrap();
+        let scripts_path = m_props.get_str_value(KEY_SCRIPTS_PATH, id_path)?;
 
-        for script in m_props.get(KEY_SCRIPTS).unwrap().as_array().unwrap().iter() {
+        for script in m_props.get_array_value(KEY_SCRIPTS, id_path)?.iter() {
             let path = format!("{}/{}/{}/{}/{}", config.root_path,
                                config.rte.path, module,
                                scripts_path,
-                               script.as_object()
-                                   .unwrap().get(KEY_FILE).unwrap().as_str().unwrap());
-            let contents = std::fs::read_to_string(path).expect("panic while opening test script file");
+                               script.get_str_value(KEY_FILE, id_path)?);
+            let contents = read_file(&path)?;
             let ctx = ScriptTestRenderContext {
                 eut: config.eut.module.to_string(),
                 name: "".to_string(),
                 data: "".to_string(),
-                refs: self.get_base_properties().get(KEY_REF_ARTIFACTS_PATH).unwrap().as_object().unwrap().clone(),
+                refs: self.get_base_properties().get_object_value(KEY_REF_ARTIFACTS_PATH, id_path)?.clone(),
                 module: module.clone(),
                 project: config.project.clone(),
                 rte_provider: "".to_string(),
@@ -137,17 +158,17 @@ impl Renderer<'_> for Test<'_> {
             };
 
             let mut commands: Vec<String> = Vec::new();
-            for command in render_script(&ctx, &contents).lines() {
+            for command in render_script(&ctx, &contents, &path)?.lines() {
                 commands.push(format!("{:indent$}{}", "", command, indent = 0));
             }
 
             let data: HashMap<String, Vec<String>> = [
-                (script.as_object().unwrap().get(KEY_SCRIPT).unwrap().as_str().unwrap().to_string(), commands),
+                (script.get_str_value(KEY_SCRIPT, id_path)?.to_string(), commands),
             ].into_iter().collect();
             scripts.push(data);
         }
 
-        scripts
+        Ok(scripts)
     }
 }
 
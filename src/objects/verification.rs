@@ -1,10 +1,12 @@
 use indradb::{Vertex, VertexProperties};
 use log::error;
-use serde_json::{Map, Value};
+use serde_json::{json, Map, Value};
 use uuid::Uuid;
 
 use crate::{PropertyType, RegressionConfig};
+use crate::constants::KEY_TAGS;
 use crate::db::Db;
+use crate::error::Result;
 use crate::objects::object::{Object, ObjectExt};
 
 use super::{implement_object_ext, load_object_config};
@@ -16,15 +18,17 @@ pub struct Verification<'a> {
 }
 
 impl<'a> Verification<'a> {
-    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Box<(dyn ObjectExt + 'a)> {
+    pub fn init(db: &'a Db, config: &RegressionConfig, base_cfg: &Value, mut path: &mut Vec<String>, label: &str, pop: usize) -> Result<Box<(dyn ObjectExt + 'a)>> {
         error!("Initialize new verification object");
-        let (o, id_path) = db.create_object_and_init(VertexTypes::Verification, &mut path, label, pop);
-        db.add_object_property(&o, &base_cfg, PropertyType::Base);
-        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config);
-        db.add_object_property(&o, &module_cfg, PropertyType::Module);
+        let (o, id_path) = db.create_object_and_init(VertexTypes::Verification, &mut path, label, pop)?;
+        let mut _base_cfg = base_cfg.as_object().unwrap().clone();
+        _base_cfg.entry(KEY_TAGS).or_insert(json!([]));
+        db.add_object_property(&o, &_base_cfg, PropertyType::Base)?;
+        let module_cfg = load_object_config(VertexTypes::get_name_by_object(&o), label, &config)?;
+        db.add_object_property(&o, &module_cfg, PropertyType::Module)?;
 
 
-        Box::new(Verification {
+        Ok(Box::new(Verification {
             object: Object {
                 db,
                 id: o.id,
@@ -32,7 +36,7 @@ impl<'a> Verification<'a> {
                 vertex: o,
                 module_cfg,
             },
-        })
+        }))
     }
 }
 
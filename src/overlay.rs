@@ -0,0 +1,220 @@
+/*!
+Layered configuration overlays.
+
+An overlay is a partial regression configuration file, e.g. one per environment like dev,
+staging or prod, deep-merged on top of the base regression configuration. Overlays are applied
+in the order given. Objects are merged key by key, scalars are replaced and arrays are merged
+with the selected [`ArrayMerge`] strategy.
+
+Module configurations loaded by `load_object_config` are overlaid from the `modules` section of
+an overlay keyed by module type and module name:
+
+```yaml
+eut:
+  artifacts_dir: artifacts/prod
+modules:
+  rte:
+    rte_a:
+      ci:
+        timeout: 3600
+```
+
+Single values are overridden with `<path.to.key>=<value>` assignments applied after overlay
+files. Variables assigned the same way or loaded from a vars file are exposed to the regression
+configuration template as `vars`, environment variables via the `env(name=..., default=...)`
+function.
+ */
+
+use std::collections::HashMap;
+
+use log::info;
+use serde_json::{Map, Value};
+use serde_json::Value::Null;
+
+use crate::constants::*;
+use crate::error::{read_file, Result, Sense8Error};
+use crate::format;
+use crate::merge_json_with;
+
+/// Array merge strategies accepted by [`ArrayMerge::from_name`].
+pub const ARRAY_MERGE_STRATEGIES: &[&str] = &[ARRAY_MERGE_REPLACE, ARRAY_MERGE_APPEND, ARRAY_MERGE_BY_NAME];
+
+/// How an overlay array is merged into the base array at the same key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayMerge {
+    /// Overlay array replaces base array
+    #[default]
+    Replace,
+    /// Overlay array items are appended to base array
+    Append,
+    /// Overlay array objects are merged into base array objects with the same `name`. Other
+    /// items are appended
+    MergeByName,
+}
+
+impl ArrayMerge {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            ARRAY_MERGE_REPLACE => Some(ArrayMerge::Replace),
+            ARRAY_MERGE_APPEND => Some(ArrayMerge::Append),
+            ARRAY_MERGE_BY_NAME => Some(ArrayMerge::MergeByName),
+            _ => None,
+        }
+    }
+}
+
+/// Single `<key>=<value>` assignment. Dots in key separate nested keys respectively array
+/// indices. Value is parsed as JSON and taken as string if that fails.
+#[derive(Debug, Clone)]
+pub struct Assignment {
+    pub key: String,
+    pub value: Value,
+}
+
+impl Assignment {
+    pub fn parse(s: &str) -> Result<Self> {
+        let (key, value) = s.split_once('=')
+            .filter(|(k, _)| !k.is_empty() && !k.split('.').any(str::is_empty))
+            .ok_or_else(|| Sense8Error::Override(format!("<{s}> is expected to be <path.to.key>=<value>")))?;
+        let value = serde_json::from_str(value).unwrap_or_else(|_| Value::from(value));
+
+        Ok(Assignment { key: key.to_string(), value })
+    }
+
+    /// Set value at key path in `target`. Missing objects on the way are created.
+    fn apply(&self, target: &mut Value) -> Result<()> {
+        let mut curr = target;
+
+        for segment in self.key.split('.') {
+            if curr.is_null() {
+                *curr = Value::Object(Map::new());
+            }
+            curr = match curr {
+                Value::Object(o) => o.entry(segment.to_string()).or_insert(Null),
+                Value::Array(a) => {
+                    let len = a.len();
+                    segment.parse::<usize>().ok()
+                        .and_then(|i| a.get_mut(i))
+                        .ok_or_else(|| Sense8Error::Override(
+                            format!("<{}>: <{segment}> is not an index of array with {len} items", self.key)))?
+                }
+                other => return Err(Sense8Error::Override(
+                    format!("<{}>: <{segment}> can not be set on value <{other}>", self.key))),
+            };
+        }
+        *curr = self.value.clone();
+
+        Ok(())
+    }
+}
+
+/// Loaded overlay files, overrides and variables applied to regression and module configurations.
+#[derive(Debug, Clone, Default)]
+pub struct Overlay {
+    layers: Vec<(String, Value)>,
+    strategy: ArrayMerge,
+    sets: Vec<Assignment>,
+    vars: Value,
+}
+
+impl Overlay {
+    /// Read and parse overlay `files` in JSON, YAML or TOML format.
+    pub fn load(files: &[String], strategy: ArrayMerge) -> Result<Self> {
+        let mut layers: Vec<(String, Value)> = Vec::new();
+
+        for file in files.iter() {
+            let raw = read_file(file)?;
+            let layer = format::parse(file, &raw)?;
+            if !layer.is_object() {
+                return Err(Sense8Error::ConfigInvalid {
+                    path: file.to_string(),
+                    msg: format!("expected a {} object", format::ConfigFormat::from_path(file).name()),
+                });
+            }
+            layers.push((file.to_string(), layer));
+        }
+
+        Ok(Overlay { layers, strategy, ..Default::default() })
+    }
+
+    /// Overrides applied to regression configuration after overlay files.
+    pub fn with_sets(mut self, sets: &[Assignment]) -> Self {
+        self.sets = sets.to_vec();
+        self
+    }
+
+    /// Template variables read from `file` if given. `vars` are applied on top.
+    pub fn with_vars(mut self, file: Option<&str>, vars: &[Assignment]) -> Result<Self> {
+        self.vars = match file {
+            Some(file) => {
+                let raw = read_file(file)?;
+                let v = format::parse(file, &raw)?;
+                if !v.is_object() {
+                    return Err(Sense8Error::ConfigInvalid {
+                        path: file.to_string(),
+                        msg: format!("expected a {} object", format::ConfigFormat::from_path(file).name()),
+                    });
+                }
+                v
+            }
+            None => Value::Object(Map::new()),
+        };
+        for var in vars.iter() {
+            var.apply(&mut self.vars)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Template variables exposed to regression configuration as `vars`.
+    pub(crate) fn vars(&self) -> &Value {
+        &self.vars
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Merge every overlay without its `modules` section into regression configuration `base`
+    /// and apply overrides.
+    pub(crate) fn apply(&self, base: &mut Value) -> Result<()> {
+        for (file, layer) in self.layers.iter() {
+            info!("Apply overlay <{file}>...");
+            let mut layer = layer.clone();
+            if let Some(l) = layer.as_object_mut() {
+                l.remove(OVERLAY_KEY_MODULES);
+            }
+            merge_json_with(base, &layer, self.strategy);
+        }
+        for set in self.sets.iter() {
+            info!("Apply override <{}>...", set.key);
+            set.apply(base)?;
+        }
+
+        Ok(())
+    }
+
+    /// Merge `modules.<_type>.<module>` section of every overlay into module configuration `base`.
+    pub(crate) fn apply_module(&self, _type: &str, module: &str, base: &mut Value) {
+        for (file, layer) in self.layers.iter() {
+            if let Some(m) = layer.get(OVERLAY_KEY_MODULES).and_then(|m| m.get(_type)).and_then(|t| t.get(module)) {
+                info!("Apply overlay <{file}> to module <{module}>...");
+                merge_json_with(base, m, self.strategy);
+            }
+        }
+    }
+}
+
+/// Tera function `env(name="VAR", default="value")` returning environment variable `name`.
+/// Fails rendering if the variable is not set and no default is given.
+pub(crate) fn env(args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let name = args.get(KEY_NAME).and_then(|n| n.as_str())
+        .ok_or_else(|| tera::Error::msg(format!("{TERA_FN_ENV}: missing string argument <{KEY_NAME}>")))?;
+
+    match std::env::var(name) {
+        Ok(v) => Ok(Value::from(v)),
+        Err(_) => args.get(KEY_DEFAULT).cloned().ok_or_else(|| {
+            tera::Error::msg(format!("{TERA_FN_ENV}: environment variable <{name}> is not set and no <{KEY_DEFAULT}> given"))
+        }),
+    }
+}
@@ -0,0 +1,746 @@
+/*!
+Typed CI pipeline model.
+
+GitLab CI pipeline built from the stage chain and the object render contexts produced by
+`Regression::build_context` and serialized with serde_yaml. Rendered by `generate --native`
+instead of the pipeline template, which stays the default.
+
+Job names and `ACTION` rules follow the bundled template: every object gets a `<job>-deploy` job
+and a `<job>-destroy` job. A job runs in triggered pipelines if `ACTION` names its kind, e.g.
+`deploy`, `destroy`, `test` or `verify`, or the job itself, e.g. `deploy-<job>`. Unlike the
+template, the model does not render the monitor job, `-artifacts` jobs, sequential test jobs or
+per kind retry settings.
+ */
+
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use tera::Context;
+
+use crate::constants::*;
+use crate::error::{Result, Sense8Error};
+use crate::gv::quote;
+use crate::RegressionConfig;
+
+/// Stage DAG export formats accepted by `Regression::stages_graph`.
+pub const STAGES_FORMATS: &[&str] = &[GV_FORMAT_DOT, GV_FORMAT_MERMAID];
+
+/// Job rules and workflow rules `when` keyword.
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum When {
+    OnSuccess,
+    OnFailure,
+    Always,
+    Manual,
+    Never,
+}
+
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct Rule {
+    #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when: Option<When>,
+}
+
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct Workflow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct Artifacts {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_in: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when: Option<When>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct Need {
+    pub job: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+}
+
+/// Ordered `name: value` pairs serialized as YAML mapping.
+#[derive(Default, Clone, Debug)]
+pub struct Variables(pub Vec<(String, String)>);
+
+impl Variables {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn insert(&mut self, name: &str, value: &str) {
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some(v) => v.1 = value.to_string(),
+            None => self.0.push((name.to_string(), value.to_string())),
+        }
+    }
+}
+
+impl Serialize for Variables {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in self.0.iter() {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Keywords applied to all jobs.
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct JobDefaults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifacts: Option<Artifacts>,
+}
+
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct Job {
+    /// Stage is omitted for hidden template jobs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Variables::is_empty")]
+    pub variables: Variables,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub needs: Vec<Need>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<Rule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub script: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifacts: Option<Artifacts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<u64>,
+}
+
+impl Job {
+    /// Apply per object `ci` settings. Supported keys are `image`, `tags`, `timeout`, `retry`,
+    /// `variables` given as map or as list of `name` / `value` objects and `artifacts` given as
+    /// `path` / `paths` with optional `expire_in`. Unknown keys and unset values are ignored.
+    fn apply_ci(&mut self, ci: &Map<String, Value>) {
+        if let Some(image) = ci.get("image").and_then(|v| v.as_str()) {
+            self.image = Some(image.to_string());
+        }
+        if let Some(tags) = ci.get("tags").and_then(|v| v.as_array()) {
+            self.tags = tags.iter().filter_map(|t| t.as_str()).map(String::from).collect();
+        }
+        match ci.get("timeout") {
+            Some(Value::String(t)) if !t.is_empty() => self.timeout = Some(t.to_string()),
+            Some(Value::Number(t)) => self.timeout = Some(format!("{t}m")),
+            _ => {}
+        }
+        if let Some(retry) = ci.get("retry").and_then(|v| v.as_u64()) {
+            self.retry = Some(retry);
+        }
+        match ci.get("variables") {
+            Some(Value::Object(vars)) => {
+                for (name, value) in vars.iter() {
+                    self.variables.insert(name, &value_to_string(value));
+                }
+            }
+            Some(Value::Array(vars)) => {
+                for var in vars.iter() {
+                    if let (Some(name), Some(value)) = (var.get(KEY_NAME).and_then(|n| n.as_str()), var.get("value")) {
+                        self.variables.insert(name, &value_to_string(value));
+                    }
+                }
+            }
+            _ => {}
+        }
+        if let Some(Value::Object(artifacts)) = ci.get(KEY_ARTIFACTS) {
+            let mut paths: Vec<String> = Vec::new();
+            if let Some(p) = artifacts.get("path").and_then(|p| p.as_str()) {
+                paths.push(p.to_string());
+            }
+            if let Some(p) = artifacts.get("paths").and_then(|p| p.as_array()) {
+                paths.extend(p.iter().filter_map(|p| p.as_str()).map(String::from));
+            }
+            if !paths.is_empty() {
+                self.artifacts = Some(Artifacts {
+                    paths,
+                    expire_in: artifacts.get("expire_in").and_then(|e| e.as_str()).map(String::from),
+                    when: Some(When::Always),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Pipeline {
+    pub stages: Vec<String>,
+    pub variables: Variables,
+    pub workflow: Option<Workflow>,
+    pub default: Option<JobDefaults>,
+    /// Jobs in pipeline file order
+    pub jobs: Vec<(String, Job)>,
+}
+
+impl Serialize for Pipeline {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(workflow) = &self.workflow {
+            map.serialize_entry(KEY_WORKFLOW, workflow)?;
+        }
+        map.serialize_entry(KEY_STAGES, &self.stages)?;
+        if !self.variables.is_empty() {
+            map.serialize_entry("variables", &self.variables)?;
+        }
+        if let Some(default) = &self.default {
+            map.serialize_entry("default", default)?;
+        }
+        for (name, job) in self.jobs.iter() {
+            map.serialize_entry(name, job)?;
+        }
+        map.end()
+    }
+}
+
+/// Subset of an object render context needed to build its jobs.
+#[derive(Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+struct JobSource {
+    job: String,
+    ci: Map<String, Value>,
+    scripts: Vec<Map<String, Value>>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct CollectorSource {
+    #[serde(flatten)]
+    source: JobSource,
+    module: Map<String, Value>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct VerificationSource {
+    #[serde(flatten)]
+    source: JobSource,
+    rte: String,
+    test: String,
+    name: String,
+    module: String,
+    stage: String,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct TestSource {
+    #[serde(flatten)]
+    source: JobSource,
+    rte: String,
+    name: String,
+    module: String,
+    provider: String,
+    stage: String,
+    needs: Vec<String>,
+    after: Vec<String>,
+    verifications: Vec<VerificationSource>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct RteSource {
+    ci: Map<String, Value>,
+    components: Vec<JobSource>,
+    tests: Vec<TestSource>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct EutSource {
+    sites: Vec<JobSource>,
+}
+
+/// Objects held by one EUT of `euts` render context.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct EutGroupSource {
+    name: String,
+    eut: EutSource,
+    rtes: Vec<RteSource>,
+    features: Vec<JobSource>,
+    applications: Vec<JobSource>,
+    collectors: Vec<CollectorSource>,
+    reports: Vec<JobSource>,
+}
+
+impl Pipeline {
+    /// Build pipeline from regression configuration and render context returned by
+    /// `Regression::build_context`.
+    pub fn build(config: &RegressionConfig, context: &Context) -> Result<Self> {
+        let stages: Vec<String> = from_context(context, KEY_STAGES)?;
+        let mut variables = Variables::default();
+        for v in config.ci.variables.iter() {
+            variables.insert(&v.name, &v.value);
+        }
+
+        let mut builder = Builder {
+            stages: &stages,
+            jobs: Vec::new(),
+        };
+
+        for template in config.ci.job_templates.iter() {
+            let mut job = Job::default();
+            for v in template.variables.iter() {
+                job.variables.insert(&v.name, &v.value);
+            }
+            builder.jobs.push((format!(".{}", template.name), job));
+        }
+
+        let mut project: JobSource = from_context(context, KEY_PROJECT)?;
+        project.job = KEY_PROJECT.to_string();
+        builder.add(&project, &config.project.ci.stages.deploy, &config.project.ci.stages.destroy, NO_ACTIONS);
+
+        let mut dashboard: JobSource = from_context(context, KEY_DASHBOARD)?;
+        dashboard.job = KEY_DASHBOARD.to_string();
+        builder.add(&dashboard, &config.dashboard.ci.stages.deploy, &config.dashboard.ci.stages.destroy, NO_ACTIONS);
+
+        //Jobs of every EUT use the stages of its scoped configuration
+        let euts: Vec<EutGroupSource> = from_context(context, KEY_EUTS)?;
+        let scoped = config.euts();
+        for eut in euts.iter() {
+            let config = scoped.iter().find(|c| c.eut.name == eut.name).unwrap_or(config);
+            builder.add_eut(config, eut);
+        }
+
+        //Jobs without script are not added. Required needs on them are dropped
+        let names: Vec<String> = builder.jobs.iter().map(|(name, _)| name.clone()).collect();
+        for (_, job) in builder.jobs.iter_mut() {
+            job.needs.retain(|n| n.optional || names.contains(&n.job));
+        }
+
+        Ok(Pipeline {
+            stages: stages.clone(),
+            variables,
+            workflow: Some(Workflow { name: Some(config.project.module.clone()), rules: vec![] }),
+            default: Some(JobDefaults {
+                image: Some(config.ci.image.clone()),
+                tags: config.ci.tags.clone(),
+                artifacts: Some(Artifacts {
+                    paths: vec![config.ci.artifacts.path.clone()],
+                    expire_in: Some(config.ci.artifacts.expire_in.clone()),
+                    when: Some(When::Always),
+                }),
+            }),
+            jobs: builder.jobs,
+        })
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|e| Sense8Error::Pipeline(e.to_string()))
+    }
+
+    /// Jobs per stage in stage order. Hidden template jobs are not part of any stage.
+    fn stage_jobs(&self) -> Vec<(&str, Vec<&str>)> {
+        self.stages.iter()
+            .map(|stage| (stage.as_str(), self.jobs.iter()
+                .filter(|(_, job)| job.stage.as_deref() == Some(stage.as_str()))
+                .map(|(name, _)| name.as_str())
+                .collect()))
+            .collect()
+    }
+
+    /// Needs as pairs of job and needed job. Needs of jobs not part of the pipeline are skipped.
+    fn job_needs(&self) -> Vec<(&str, &str)> {
+        self.jobs.iter()
+            .flat_map(|(name, job)| job.needs.iter().map(move |n| (name.as_str(), n.job.as_str())))
+            .filter(|(_, need)| self.jobs.iter().any(|(name, _)| name == need))
+            .collect()
+    }
+
+    /// Export stage DAG as GraphViz DOT. Stages are chained in pipeline order, jobs hang off their
+    /// stage and needs link jobs to the jobs they need.
+    pub fn stages_to_dot(&self) -> String {
+        let mut out = String::from("digraph stages {\nrankdir = LR;\nnode [fontname = \"Migu 1M\"];\n");
+        let stage_jobs = self.stage_jobs();
+        // Stage and job names may collide. Stage node ids are prefixed
+        let stage_id = |stage: &str| quote(&format!("{KEY_STAGE}:{stage}"));
+
+        for (stage, jobs) in stage_jobs.iter() {
+            out.push_str(&format!("\"{}\" [label = \"{}\", shape = cds, style = filled, fillcolor = \"#a6d96a\"];\n",
+                stage_id(stage), quote(stage)));
+            for job in jobs.iter() {
+                out.push_str(&format!("\"{}\" [shape = box];\n", quote(job)));
+                out.push_str(&format!("\"{}\" -> \"{}\" [style = dashed, arrowhead = none];\n", stage_id(stage), quote(job)));
+            }
+        }
+        for pair in stage_jobs.windows(2) {
+            out.push_str(&format!("\"{}\" -> \"{}\" [style = bold];\n", stage_id(pair[0].0), stage_id(pair[1].0)));
+        }
+        for (job, need) in self.job_needs().iter() {
+            out.push_str(&format!("\"{}\" -> \"{}\" [label = \"{KEY_NEEDS}\", color = blue];\n", quote(job), quote(need)));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Export stage DAG as Mermaid flowchart. Every stage is a subgraph holding its jobs. Stages
+    /// are chained in pipeline order and needs link jobs to the jobs they need.
+    pub fn stages_to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        let stage_jobs = self.stage_jobs();
+        let mut ids: Vec<&str> = Vec::new();
+
+        for (i, (stage, jobs)) in stage_jobs.iter().enumerate() {
+            out.push_str(&format!("    subgraph s{i}[\"{}\"]\n", mermaid_quote(stage)));
+            for job in jobs.iter() {
+                out.push_str(&format!("        j{}[\"{}\"]\n", ids.len(), mermaid_quote(job)));
+                ids.push(job);
+            }
+            out.push_str("    end\n");
+        }
+        for i in 1..stage_jobs.len() {
+            out.push_str(&format!("    s{} --> s{i}\n", i - 1));
+        }
+        let id = |job: &str| ids.iter().position(|j| *j == job);
+        for (job, need) in self.job_needs().iter() {
+            if let (Some(a), Some(b)) = (id(job), id(need)) {
+                out.push_str(&format!("    j{a} -. {KEY_NEEDS} .-> j{b}\n"));
+            }
+        }
+
+        out
+    }
+}
+
+pub(crate) fn mermaid_quote(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+struct Builder<'a> {
+    stages: &'a Vec<String>,
+    jobs: Vec<(String, Job)>,
+}
+
+impl Builder<'_> {
+    /// Add deploy job and destroy job of object. Jobs are placed into the first stage of `deploy`
+    /// respectively `destroy` known to the stage chain. Deploy job runs all script sections except
+    /// `artifacts` and `destroy`, destroy job runs the `destroy` section only. `actions` are the
+    /// kind wide deploy and destroy actions triggering the jobs besides their own actions. Returns
+    /// the deploy job.
+    fn add(&mut self, source: &JobSource, deploy: &[String], destroy: &[String], actions: Actions) -> Option<&mut Job> {
+        if source.job.is_empty() {
+            return None;
+        }
+
+        let mut deploy_idx = None;
+        if let Some(stage) = deploy.iter().find(|s| self.stages.contains(s)) {
+            let script = scripts(source, |section| section != KEY_ARTIFACTS && section != KEY_DESTROY);
+            if !script.is_empty() {
+                self.jobs.push(job(source, KEY_DEPLOY, actions.0, stage, script));
+                deploy_idx = Some(self.jobs.len() - 1);
+            }
+        }
+
+        if let Some(stage) = destroy.iter().find(|s| self.stages.contains(s)) {
+            let script = scripts(source, |section| section == KEY_DESTROY);
+            if !script.is_empty() {
+                self.jobs.push(job(source, KEY_DESTROY, actions.1, stage, script));
+            }
+        }
+
+        deploy_idx.map(move |i| &mut self.jobs[i].1)
+    }
+
+    /// Add jobs of objects held by `eut` placed into the stages of its scoped `config`.
+    fn add_eut(&mut self, config: &RegressionConfig, eut: &EutGroupSource) {
+        for rte in eut.rtes.iter() {
+            for component in rte.components.iter() {
+                let component = JobSource { ci: rte.ci.clone(), ..component.clone() };
+                self.add(&component, &config.rte.ci.stages.deploy, &config.rte.ci.stages.destroy, OBJECT_ACTIONS);
+            }
+        }
+
+        for feature in eut.features.iter() {
+            self.add(feature, &config.features.ci.stages.deploy, &config.features.ci.stages.destroy, OBJECT_ACTIONS);
+        }
+
+        for site in eut.eut.sites.iter() {
+            self.add(site, &config.eut.ci.stages.deploy, &config.eut.ci.stages.destroy, OBJECT_ACTIONS);
+        }
+
+        for application in eut.applications.iter() {
+            self.add(application, &config.applications.ci.stages.deploy, &config.applications.ci.stages.destroy, OBJECT_ACTIONS);
+        }
+
+        for rte in eut.rtes.iter() {
+            for test in rte.tests.iter() {
+                let stage = match test.stage.is_empty() {
+                    true => config.namespaced(&stage_name(&[KEY_TEST, &test.rte, &test.provider, &test.module, &test.name, KEY_DEPLOY])),
+                    false => test.stage.clone(),
+                };
+                let deploy = [stage.clone()];
+                let deploy = if self.stages.contains(&stage) { &deploy[..] } else { &config.tests.ci.stages.deploy[..] };
+                //Tests waited for only to keep order may be left out of pipelines triggered for
+                //single tests. Tests depended on may not
+                if let Some(job) = self.add(&test.source, deploy, &[], TEST_ACTIONS) {
+                    for need in test.needs.iter() {
+                        job.needs.push(Need { job: format!("{}-{}", need, KEY_DEPLOY), optional: false });
+                    }
+                    for need in test.after.iter() {
+                        job.needs.push(Need { job: format!("{}-{}", need, KEY_DEPLOY), optional: true });
+                    }
+                }
+
+                for verification in test.verifications.iter() {
+                    let stage = match verification.stage.is_empty() {
+                        true => config.namespaced(&stage_name(&[KEY_VERIFICATION, &verification.rte, &test.provider, &verification.test,
+                            &verification.module, &verification.name, KEY_DEPLOY])),
+                        false => verification.stage.clone(),
+                    };
+                    let deploy = [stage.clone()];
+                    let deploy = if self.stages.contains(&stage) { &deploy[..] } else { &config.verifications.ci.stages.deploy[..] };
+                    //Test job is left out of pipelines triggered with verify action
+                    if let Some(job) = self.add(&verification.source, deploy, &[], VERIFICATION_ACTIONS) {
+                        job.needs.push(Need { job: format!("{}-{}", test.source.job, KEY_DEPLOY), optional: true });
+                    }
+                }
+            }
+        }
+
+        for collector in eut.collectors.iter() {
+            let deploy: Vec<String> = collector.module.get(KEY_STAGES)
+                .and_then(|s| s.get(KEY_DEPLOY))
+                .and_then(|d| serde_json::from_value::<Vec<String>>(d.clone()).ok())
+                .unwrap_or_default()
+                .iter()
+                .map(|s| config.namespaced(s))
+                .collect();
+            self.add(&collector.source, &deploy, &[], NO_ACTIONS);
+        }
+
+        for report in eut.reports.iter() {
+            self.add(report, &config.reports.ci.stages.deploy, &config.reports.ci.stages.destroy, NO_ACTIONS);
+        }
+    }
+}
+
+/// Kind wide deploy and destroy actions of an object kind.
+type Actions = (Option<&'static str>, Option<&'static str>);
+
+const OBJECT_ACTIONS: Actions = (Some(KEY_DEPLOY), Some(KEY_DESTROY));
+const TEST_ACTIONS: Actions = (Some(ACTION_TEST), None);
+const VERIFICATION_ACTIONS: Actions = (Some(ACTION_VERIFY), None);
+const NO_ACTIONS: Actions = (None, None);
+
+/// Job `<job>-<action>` triggered if `ACTION` variable equals kind wide `kind_action` or
+/// `<action>-<job>`. Jobs never run in pipelines not triggered with an `ACTION`.
+fn job(source: &JobSource, action: &str, kind_action: Option<&str>, stage: &str, script: Vec<String>) -> (String, Job) {
+    let rule = |action: &str| Rule {
+        condition: Some(format!("$ACTION == \"{action}\" && $CI_PIPELINE_SOURCE == \"trigger\" && $CI_PIPELINE_TRIGGERED == \"true\"")),
+        when: None,
+    };
+    let mut job = Job {
+        stage: Some(stage.to_string()),
+        script,
+        rules: kind_action.into_iter().map(rule).chain([rule(&format!("{}-{}", action, source.job))]).collect(),
+        ..Default::default()
+    };
+    job.apply_ci(&source.ci);
+
+    (format!("{}-{}", source.job, action), job)
+}
+
+fn scripts(source: &JobSource, section: impl Fn(&str) -> bool) -> Vec<String> {
+    let mut commands: Vec<String> = Vec::new();
+    for script in source.scripts.iter() {
+        for (_, c) in script.iter().filter(|(s, _)| section(s)) {
+            commands.extend(c.as_array().into_iter().flatten().filter_map(|c| c.as_str()).map(String::from));
+        }
+    }
+    commands
+}
+
+fn stage_name(parts: &[&str]) -> String {
+    parts.join("-").replace('_', "-")
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        v => v.to_string(),
+    }
+}
+
+fn from_context<T: serde::de::DeserializeOwned + Default>(context: &Context, key: &str) -> Result<T> {
+    match context.get(key) {
+        Some(v) => serde_json::from_value(v.clone()).map_err(|e| Sense8Error::Pipeline(format!("<{key}> render context: {e}"))),
+        None => Ok(T::default()),
+    }
+}
+
+/// Top level keys of a GitLab CI file which do not define jobs.
+const GLOBAL_KEYWORDS: &[&str] = &["default", "include", "stages", "variables", "workflow", "image", "services",
+    "cache", "before_script", "after_script"];
+
+/// Stages GitLab provides without declaring them.
+const IMPLICIT_STAGES: &[&str] = &[".pre", ".post"];
+
+/// Stage of jobs not setting `stage` themselves or through `extends`.
+const DEFAULT_STAGE: &str = "test";
+
+/// Top level mapping entries in file order. Unlike `serde_yaml::Mapping`, duplicate keys are kept.
+struct Entries(Vec<(String, serde_yaml::Value)>);
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EntriesVisitor {
+            type Value = Entries;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a mapping of pipeline keywords and jobs")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> std::result::Result<Entries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((k, v)) = map.next_entry::<serde_yaml::Value, serde_yaml::Value>()? {
+                    let key = match k {
+                        serde_yaml::Value::String(s) => s,
+                        k => serde_yaml::to_string(&k).unwrap_or_default().trim_end().to_string(),
+                    };
+                    entries.push((key, v));
+                }
+                Ok(Entries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// Check rendered GitLab CI pipeline before it is written. Every job's stage must be listed in
+/// top level `stages`, every `needs` entry must name an existing job, every `extends` target must
+/// exist and job names must be unique. All problems found are returned with
+/// [`Sense8Error::PipelineInvalid`].
+pub fn check(data: &str) -> Result<()> {
+    let entries: Entries = serde_yaml::from_str(data)
+        .map_err(|e| Sense8Error::PipelineInvalid(vec![(String::new(), e.to_string())]))?;
+    let mut issues: Vec<(String, String)> = Vec::new();
+    let mut jobs: Vec<(String, serde_yaml::Value)> = Vec::new();
+    let mut stages: Option<Vec<String>> = None;
+
+    for (name, value) in entries.0.into_iter() {
+        if name == KEY_STAGES {
+            stages = Some(serde_yaml::from_value(value)
+                .map_err(|e| Sense8Error::PipelineInvalid(vec![(String::new(), format!("<{KEY_STAGES}>: {e}"))]))?);
+        } else if !GLOBAL_KEYWORDS.contains(&name.as_str()) {
+            if jobs.iter().any(|(n, _)| *n == name) {
+                issues.push((name.to_string(), "job name is not unique".to_string()));
+                continue;
+            }
+            let mut value = value;
+            if let Err(e) = value.apply_merge() {
+                issues.push((name.to_string(), e.to_string()));
+            }
+            jobs.push((name, value));
+        }
+    }
+
+    let stages = stages.unwrap_or_default();
+    let find = |name: &str| jobs.iter().find(|(n, _)| n == name).map(|(_, j)| j);
+
+    for (name, job) in jobs.iter() {
+        if !job.is_mapping() {
+            if !name.starts_with('.') {
+                issues.push((name.to_string(), "job definition is not a mapping".to_string()));
+            }
+            continue;
+        }
+
+        for target in extends(job) {
+            if find(&target).is_none() {
+                issues.push((name.to_string(), format!("extends unknown job <{target}>")));
+            }
+        }
+
+        // Hidden jobs are templates and only validated through the jobs extending them
+        if name.starts_with('.') {
+            continue;
+        }
+
+        let stage = resolve_stage(job, &find, 0).unwrap_or_else(|| DEFAULT_STAGE.to_string());
+        if !stages.contains(&stage) && !IMPLICIT_STAGES.contains(&stage.as_str()) {
+            issues.push((name.to_string(), format!("stage <{stage}> is not defined in <{KEY_STAGES}>")));
+        }
+
+        for need in job.get(KEY_NEEDS).and_then(|n| n.as_sequence()).into_iter().flatten() {
+            let target = match need {
+                serde_yaml::Value::String(s) => s.as_str(),
+                serde_yaml::Value::Mapping(m) => {
+                    // Cross project / parent pipeline needs and optional needs may refer to absent jobs
+                    if m.contains_key("project") || m.contains_key("pipeline")
+                        || m.get("optional").and_then(|o| o.as_bool()).unwrap_or(false) {
+                        continue;
+                    }
+                    match m.get("job").and_then(|j| j.as_str()) {
+                        Some(j) => j,
+                        None => {
+                            issues.push((name.to_string(), "needs entry without job".to_string()));
+                            continue;
+                        }
+                    }
+                }
+                _ => {
+                    issues.push((name.to_string(), "needs entry is neither job name nor mapping".to_string()));
+                    continue;
+                }
+            };
+            if target.starts_with('.') || find(target).is_none() {
+                issues.push((name.to_string(), format!("needs unknown job <{target}>")));
+            }
+        }
+    }
+
+    match issues.is_empty() {
+        true => Ok(()),
+        false => Err(Sense8Error::PipelineInvalid(issues)),
+    }
+}
+
+fn extends(job: &serde_yaml::Value) -> Vec<String> {
+    match job.get("extends") {
+        Some(serde_yaml::Value::String(s)) => vec![s.to_string()],
+        Some(serde_yaml::Value::Sequence(s)) => s.iter().filter_map(|e| e.as_str()).map(String::from).collect(),
+        _ => vec![],
+    }
+}
+
+/// Stage of job set directly or inherited through `extends`. Later `extends` entries take precedence
+/// like in GitLab. Recursion stops at GitLab's nesting limit of 11 levels.
+fn resolve_stage<'a>(job: &serde_yaml::Value, find: &impl Fn(&str) -> Option<&'a serde_yaml::Value>, depth: usize) -> Option<String> {
+    if let Some(stage) = job.get("stage").and_then(|s| s.as_str()) {
+        return Some(stage.to_string());
+    }
+    if depth > 11 {
+        return None;
+    }
+
+    extends(job).iter().rev()
+        .filter_map(|e| find(e))
+        .find_map(|parent| resolve_stage(parent, find, depth + 1))
+}
@@ -0,0 +1,261 @@
+/*!
+RTE connection topology export.
+
+Collects per RTE its connections from the object graph: the connection source site, the sites
+matched by the destination regexes, the RTE components wired to source and destinations and
+the tests run by the connection source. Every RTE is exported as Mermaid flowchart or as
+PlantUML deployment diagram to be embedded in markdown docs and wikis. RTEs of a project holding
+more than one EUT are named after their EUT as well.
+ */
+
+use indradb::VertexProperties;
+use serde_derive::Serialize;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::pipeline::mermaid_quote;
+use crate::{EdgeTypes, PropertyType, VertexTypes};
+
+/// Topology export formats accepted by [`RteTopology::render`].
+pub const TOPOLOGY_FORMATS: &[&str] = &[GV_FORMAT_MERMAID, TOPOLOGY_FORMAT_PLANTUML];
+
+/// Connection source or destination with the site it refers to and the components wired to it.
+#[derive(Serialize, Debug, Default)]
+pub struct Endpoint {
+    /// Source site name respectively destination regex
+    pub name: String,
+    pub site: String,
+    pub provider: String,
+    pub components: Vec<(String, String)>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConnectionTopology {
+    pub name: String,
+    pub source: Endpoint,
+    pub destinations: Vec<Endpoint>,
+    pub tests: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct RteTopology {
+    pub name: String,
+    /// EUT holding the RTE. Only set if project holds more than one EUT
+    pub eut: Option<String>,
+    pub provider: String,
+    pub connections: Vec<ConnectionTopology>,
+}
+
+impl RteTopology {
+    /// Load topology of every RTE in `db` sorted by EUT and RTE name.
+    pub fn load(db: &Db) -> Vec<RteTopology> {
+        let objects = db.get_all_objects().unwrap_or_default();
+        let multiple_euts = objects.iter().filter(|v| v.t.as_str() == VERTEX_TYPE_EUT).count() > 1;
+
+        let mut rtes: Vec<RteTopology> = objects.iter()
+            .filter(|v| v.t.as_str() == VERTEX_TYPE_RTE)
+            .map(|v| {
+                let rte = db.get_object_with_properties(&v.id);
+                // Rte id_paths do not hold their EUT. Walk rtes collection back to the EUT using it
+                let eut = match multiple_euts {
+                    true => db.get_object_neighbour_in_out_id(&v.id, EdgeTypes::ProvidesRte, VertexTypes::Rtes)
+                        .and_then(|rtes| db.get_object_neighbour_in_out_id(&rtes.id, EdgeTypes::UsesRtes, VertexTypes::Eut))
+                        .map(|e| base_str(&db.get_object_with_properties(&e.id), KEY_NAME)),
+                    false => None,
+                };
+                Self::load_rte(db, &rte, eut)
+            })
+            .collect();
+        rtes.sort_by(|a, b| a.eut.cmp(&b.eut).then_with(|| a.name.cmp(&b.name)));
+        rtes
+    }
+
+    fn load_rte(db: &Db, rte: &VertexProperties, eut: Option<String>) -> RteTopology {
+        let mut topology = RteTopology {
+            name: base_str(rte, KEY_NAME),
+            eut,
+            provider: base_str(rte, KEY_PROVIDER),
+            connections: vec![],
+        };
+
+        let connections = db.get_object_neighbour_out(&rte.vertex.id, EdgeTypes::HasConnections);
+        for c in connections.iter().flat_map(|cs| db.get_object_neighbours_with_properties_out(&cs.id, EdgeTypes::HasConnection)) {
+            let mut connection = ConnectionTopology { name: base_str(&c, KEY_NAME), ..Default::default() };
+
+            if let Some(src) = db.get_object_neighbour_with_properties_out(&c.vertex.id, EdgeTypes::HasConnectionSrc) {
+                connection.source = endpoint(db, &src);
+                connection.destinations = db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::HasConnectionDst)
+                    .iter()
+                    .map(|dst| endpoint(db, dst))
+                    .collect();
+                connection.tests = db.get_object_neighbours_with_properties_out(&src.vertex.id, EdgeTypes::Runs)
+                    .iter()
+                    .map(|t| base_str(t, KEY_NAME))
+                    .collect();
+            }
+            topology.connections.push(connection);
+        }
+
+        topology
+    }
+
+    /// Render topology as `format`, one of [`TOPOLOGY_FORMATS`].
+    pub fn render(&self, format: &str) -> String {
+        match format {
+            TOPOLOGY_FORMAT_PLANTUML => self.to_plantuml(),
+            _ => self.to_mermaid(),
+        }
+    }
+
+    /// File name topology in `format` is written to.
+    pub fn file_name(&self, format: &str) -> String {
+        let extension = match format {
+            TOPOLOGY_FORMAT_PLANTUML => "puml",
+            _ => "mmd",
+        };
+        format!("topology-{}.{extension}", self.id())
+    }
+
+    /// RTE name prefixed with EUT name if set.
+    fn id(&self) -> String {
+        match &self.eut {
+            Some(eut) => format!("{eut}-{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Diagram title naming RTE, its provider and its EUT if set.
+    fn title(&self, quote: fn(&str) -> String) -> String {
+        let title = format!("{} {} ({} {})", KEY_RTE, quote(&self.name), KEY_PROVIDER, quote(&self.provider));
+        match &self.eut {
+            Some(eut) => format!("{} {} {title}", KEY_EUT, quote(eut)),
+            None => title,
+        }
+    }
+
+    /// Mermaid flowchart with one subgraph per connection. Source links to destinations labeled
+    /// with the destination regex, to its components and to the tests it runs.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = format!("---\ntitle: \"{}\"\n---\nflowchart LR\n", self.title(mermaid_quote));
+
+        for (i, c) in self.connections.iter().enumerate() {
+            let src = format!("c{i}_src");
+            out.push_str(&format!("    subgraph c{i}[\"{} {}\"]\n", KEY_CONNECTION, mermaid_quote(&c.name)));
+            out.push_str(&format!("        {src}[\"{}\"]\n", mermaid_site(&c.source)));
+            mermaid_components(&mut out, &src, &c.source);
+
+            for (j, d) in c.destinations.iter().enumerate() {
+                let dst = format!("c{i}_dst{j}");
+                out.push_str(&format!("        {dst}[\"{}\"]\n", mermaid_site(d)));
+                out.push_str(&format!("        {src} -->|\"{}\"| {dst}\n", mermaid_quote(&d.name)));
+                mermaid_components(&mut out, &dst, d);
+            }
+            for (j, t) in c.tests.iter().enumerate() {
+                out.push_str(&format!("        c{i}_test{j}([\"{} {}\"])\n", KEY_TEST, mermaid_quote(t)));
+                out.push_str(&format!("        {src} -. {EDGE_TYPE_RUNS} .-> c{i}_test{j}\n"));
+            }
+            out.push_str("    end\n");
+        }
+
+        out
+    }
+
+    /// PlantUML deployment diagram with one frame per connection. Sites are nodes, components
+    /// are components and tests are artifacts.
+    pub fn to_plantuml(&self) -> String {
+        let mut out = format!("@startuml {}\ntitle {}\n", plantuml_id(&self.id()),
+                              self.title(str::to_string));
+        let mut links: Vec<String> = Vec::new();
+
+        for (i, c) in self.connections.iter().enumerate() {
+            let src = format!("c{i}_src");
+            out.push_str(&format!("frame \"{} {}\" as c{i} {{\n", KEY_CONNECTION, plantuml_quote(&c.name)));
+            out.push_str(&format!("  node \"{}\" as {src}\n", plantuml_site(&c.source)));
+            plantuml_components(&mut out, &mut links, &src, &c.source);
+
+            for (j, d) in c.destinations.iter().enumerate() {
+                let dst = format!("c{i}_dst{j}");
+                out.push_str(&format!("  node \"{}\" as {dst}\n", plantuml_site(d)));
+                links.push(format!("{src} --> {dst} : {}", plantuml_quote(&d.name)));
+                plantuml_components(&mut out, &mut links, &dst, d);
+            }
+            for (j, t) in c.tests.iter().enumerate() {
+                out.push_str(&format!("  artifact \"{} {}\" as c{i}_test{j}\n", KEY_TEST, plantuml_quote(t)));
+                links.push(format!("{src} ..> c{i}_test{j} : {EDGE_TYPE_RUNS}"));
+            }
+            out.push_str("}\n");
+        }
+
+        for link in links.iter() {
+            out.push_str(link);
+            out.push('\n');
+        }
+        out.push_str("@enduml\n");
+        out
+    }
+}
+
+fn endpoint(db: &Db, object: &VertexProperties) -> Endpoint {
+    let site = db.get_object_neighbour_with_properties_out(&object.vertex.id, EdgeTypes::RefersSite);
+    let provider = site.as_ref()
+        .and_then(|s| db.get_object_neighbour_with_properties_out(&s.vertex.id, EdgeTypes::UsesProvider))
+        .map(|p| base_str(&p, KEY_NAME))
+        .unwrap_or_default();
+
+    let mut components: Vec<(String, String)> = Vec::new();
+    for (edge, role) in [(EdgeTypes::HasComponentSrc, KEY_SRC), (EdgeTypes::HasComponentDst, KEY_DST)] {
+        components.extend(db.get_object_neighbours_with_properties_out(&object.vertex.id, edge).iter()
+            .map(|c| (role.to_string(), base_str(c, KEY_NAME))));
+    }
+
+    Endpoint {
+        name: base_str(object, KEY_NAME),
+        site: site.map(|s| base_str(&s, KEY_NAME)).unwrap_or_default(),
+        provider,
+        components,
+    }
+}
+
+fn mermaid_site(endpoint: &Endpoint) -> String {
+    match endpoint.provider.is_empty() {
+        true => format!("{} {}", VERTEX_TYPE_SITE, mermaid_quote(&endpoint.site)),
+        false => format!("{} {}<br/>{}", VERTEX_TYPE_SITE, mermaid_quote(&endpoint.site), mermaid_quote(&endpoint.provider)),
+    }
+}
+
+fn mermaid_components(out: &mut String, from: &str, endpoint: &Endpoint) {
+    for (k, (role, name)) in endpoint.components.iter().enumerate() {
+        out.push_str(&format!("        {from}_cmp{k}[/\"{} {role} {}\"/]\n", KEY_COMPONENT, mermaid_quote(name)));
+        out.push_str(&format!("        {from} --- {from}_cmp{k}\n"));
+    }
+}
+
+fn plantuml_site(endpoint: &Endpoint) -> String {
+    match endpoint.provider.is_empty() {
+        true => format!("{} {}", VERTEX_TYPE_SITE, plantuml_quote(&endpoint.site)),
+        false => format!("{} {}\\n{}", VERTEX_TYPE_SITE, plantuml_quote(&endpoint.site), plantuml_quote(&endpoint.provider)),
+    }
+}
+
+fn plantuml_components(out: &mut String, links: &mut Vec<String>, from: &str, endpoint: &Endpoint) {
+    for (k, (role, name)) in endpoint.components.iter().enumerate() {
+        out.push_str(&format!("  component \"{} {role} {}\" as {from}_cmp{k}\n", KEY_COMPONENT, plantuml_quote(name)));
+        links.push(format!("{from} -- {from}_cmp{k}"));
+    }
+}
+
+fn plantuml_quote(value: &str) -> String {
+    value.replace('"', "'")
+}
+
+fn plantuml_id(value: &str) -> String {
+    value.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
+
+fn base_str(object: &VertexProperties, key: &str) -> String {
+    object.props.get(PropertyType::Base.index())
+        .and_then(|p| p.value.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
@@ -0,0 +1,439 @@
+/*!
+Regression tree validation.
+
+Walks the regression configuration tree without rendering anything and collects every problem
+found instead of stopping at the first one. Static checks run on the raw configuration first.
+If these do not find anything the object graph is built via `Regression::init` and
+`Regression::init_refs` to catch remaining issues.
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use serde_derive::Serialize;
+use serde_json::Value;
+
+use crate::constants::*;
+use crate::db::Db;
+use crate::error::{PropertiesExt, Sense8Error};
+use crate::objects::{check_rte_module, dependency_refs, load_object_config};
+use crate::overlay::Overlay;
+use crate::{Regression, RegressionConfig};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// Configuration could not be loaded or is structurally wrong.
+    Config,
+    /// Module directory referenced by configuration does not exist.
+    MissingModule,
+    /// Script listed in module configuration does not exist below scripts_path.
+    MissingScript,
+    /// Ref points at an object which is not defined.
+    DanglingReference,
+    /// Two objects of the same type share a name.
+    DuplicateName,
+}
+
+impl IssueKind {
+    fn name(&self) -> &'static str {
+        match self {
+            IssueKind::Config => "config",
+            IssueKind::MissingModule => "missing_module",
+            IssueKind::MissingScript => "missing_script",
+            IssueKind::DanglingReference => "dangling_reference",
+            IssueKind::DuplicateName => "duplicate_name",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Issue {
+    pub kind: IssueKind,
+    pub location: String,
+    pub msg: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error[{}] {}: {}", self.kind.name(), self.location, self.msg)
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+
+    fn push(&mut self, kind: IssueKind, location: &str, msg: String) {
+        self.issues.push(Issue { kind, location: location.to_string(), msg });
+    }
+
+    fn push_error(&mut self, location: &str, e: Sense8Error) {
+        let kind = match e {
+            Sense8Error::DanglingReference { .. } => IssueKind::DanglingReference,
+            _ => IssueKind::Config,
+        };
+        self.push(kind, location, e.to_string());
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in self.issues.iter() {
+            writeln!(f, "{issue}")?;
+        }
+        match self.issues.len() {
+            0 => write!(f, "regression tree is valid"),
+            1 => write!(f, "found 1 problem"),
+            n => write!(f, "found {n} problems"),
+        }
+    }
+}
+
+/// Validate regression tree at `path` using regression configuration `file`.
+pub fn validate(db: &Db, path: &str, file: &str, eut_file: &Option<String>, overlay: &Overlay) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let r = match Regression::new(db, path, file, "", eut_file, overlay) {
+        Ok(r) => r,
+        Err(e) => {
+            report.push_error(file, e);
+            return report;
+        }
+    };
+
+    Checker { config: &r.config, report: &mut report, names: Default::default() }.run_project();
+
+    //Every EUT is checked on its own. Object names are resolved within the EUT only
+    for config in r.config.euts().iter() {
+        Checker { config, report: &mut report, names: Default::default() }.run();
+    }
+
+    if report.is_ok() {
+        match r.init() {
+            Ok((p, refs)) => {
+                if let Err(e) = r.init_refs(p, &refs) {
+                    report.push_error(KEY_EUT, e);
+                }
+            }
+            Err(e) => report.push_error(KEY_EUT, e),
+        }
+    }
+
+    report
+}
+
+struct Checker<'a, 'b> {
+    config: &'a RegressionConfig,
+    report: &'b mut ValidationReport,
+    // object type -> known object names, used to resolve refs
+    names: HashMap<&'static str, HashSet<String>>,
+}
+
+impl Checker<'_, '_> {
+    /// Check project and dashboard modules shared by all EUTs.
+    fn run_project(&mut self) {
+        let config = self.config;
+        let location = format!("{KEY_PROJECT}/{}", config.project.module);
+        if let Some(module_cfg) = self.load_module(KEY_PROJECT, &config.project.path, &config.project.module, &location) {
+            self.check_scripts(&module_cfg, &location, |s| format!("{}/{}/{s}", config.project.path, config.project.module));
+        }
+
+        let location = format!("{KEY_DASHBOARD}/{}", config.dashboard.module);
+        if let Some(module_cfg) = self.load_module(KEY_DASHBOARD, &config.dashboard.path, &config.dashboard.module, &location) {
+            let provider = &config.dashboard.provider;
+            match module_cfg.get(KEY_PROVIDER).and_then(|p| p.get(provider)) {
+                Some(provider_cfg) => self.check_scripts(provider_cfg, &location,
+                                                         |s| format!("{}/{}/{s}/{provider}", config.dashboard.path, config.dashboard.module)),
+                None => self.report.push(IssueKind::DanglingReference, &location,
+                                         format!("refers to unknown {KEY_PROVIDER} <{provider}>")),
+            }
+        }
+    }
+
+    fn run(&mut self) {
+        let config = self.config;
+        let eut_location = format!("{KEY_EUT}/{}", config.eut.name);
+
+        if !self.module_exists(&config.eut.path, &config.eut.module, &eut_location) {
+            return;
+        }
+
+        let eut_cfg = match load_object_config(KEY_EUT, &config.eut.module, config) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                self.report.push_error(&eut_location, e);
+                return;
+            }
+        };
+
+        self.check_scripts(&eut_cfg, &eut_location, |s| format!("{}/{}/{s}", config.eut.path, config.eut.module));
+
+        let providers: HashSet<String> = eut_cfg.get(KEY_PROVIDER).and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|p| p.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+        let mut refs: Vec<(String, Value)> = Vec::new();
+
+        // Sites
+        if let Some(sites) = eut_cfg.get(KEY_SITES).and_then(|v| v.as_object()) {
+            for (site_name, site_attr) in sites.iter() {
+                let location = format!("{eut_location}/{KEY_SITES}/{site_name}");
+                let provider = site_attr.get(KEY_PROVIDER).and_then(|v| v.as_str()).unwrap_or_default();
+
+                if !providers.contains(provider) {
+                    self.report.push(IssueKind::DanglingReference, &location,
+                                     format!("refers to unknown {KEY_PROVIDER} <{provider}>"));
+                }
+
+                match site_attr.get_i64_value(KEY_COUNT, &location) {
+                    Ok(1) => self.add_name(VERTEX_TYPE_SITE, site_name, &location),
+                    Ok(count) => {
+                        for c in 1..=count {
+                            self.add_name(VERTEX_TYPE_SITE, &format!("{site_name}_{c}"), &location);
+                        }
+                    }
+                    Err(e) => self.report.push_error(&location, e),
+                }
+            }
+        }
+
+        // Features, collectors, reports and applications
+        let collections = [
+            (KEY_FEATURES, VERTEX_TYPE_FEATURE, KEY_FEATURE, &config.features.path),
+            (KEY_COLLECTORS, VERTEX_TYPE_COLLECTOR, KEY_COLLECTOR, &config.collectors.path),
+            (KEY_REPORTS, VERTEX_TYPE_REPORT, KEY_REPORT, &config.reports.path),
+            (KEY_APPLICATIONS, VERTEX_TYPE_APPLICATION, KEY_APPLICATION, &config.applications.path),
+        ];
+
+        for (key, v_type, cfg_type, path) in collections {
+            for item in eut_cfg.get(key).and_then(|v| v.as_array()).into_iter().flatten() {
+                let name = item.get(KEY_NAME).and_then(|v| v.as_str()).unwrap_or_default();
+                let location = format!("{eut_location}/{key}/{name}");
+                self.add_name(v_type, name, &location);
+
+                let module = match item.get_str_value(KEY_MODULE, &location) {
+                    Ok(m) => m,
+                    Err(e) => {
+                        self.report.push_error(&location, e);
+                        continue;
+                    }
+                };
+
+                if let Some(module_cfg) = self.load_module(cfg_type, path, module, &location) {
+                    if cfg_type == KEY_APPLICATION {
+                        let provider = item.get(KEY_PROVIDER).and_then(|v| v.as_str()).unwrap_or_default();
+                        if !providers.contains(provider) {
+                            self.report.push(IssueKind::DanglingReference, &location,
+                                             format!("refers to unknown {KEY_PROVIDER} <{provider}>"));
+                        }
+                        self.check_scripts(&module_cfg, &location, |s| format!("{path}/{module}/{s}/{provider}"));
+                    } else {
+                        self.check_scripts(&module_cfg, &location, |s| format!("{path}/{module}/{s}"));
+                    }
+                }
+
+                if let Some(r) = item.get(KEY_REFS).and_then(|v| v.as_array()) {
+                    refs.extend(r.iter().map(|r| (location.clone(), r.clone())));
+                }
+            }
+        }
+
+        // Rtes, connections and tests
+        let mut tests: HashSet<(String, String, String)> = HashSet::new();
+
+        for rte in eut_cfg.get(KEY_RTES).and_then(|v| v.as_array()).into_iter().flatten() {
+            let rte_name = rte.get(KEY_NAME).and_then(|v| v.as_str()).unwrap_or_default();
+            let location = format!("{eut_location}/{KEY_RTES}/{rte_name}");
+            self.add_name(VERTEX_TYPE_RTE, rte_name, &location);
+
+            match rte.get_str_value(KEY_MODULE, &location) {
+                Ok(module) => {
+                    if let Some(module_cfg) = self.load_module(KEY_RTE, &config.rte.path, module, &location) {
+                        if let Err(e) = check_rte_module(&module_cfg, &format!("{}/{module}", config.rte.path)) {
+                            self.report.push_error(&location, e);
+                        }
+
+                        let provider = rte.get(KEY_PROVIDER).and_then(|v| v.as_str()).unwrap_or_default();
+
+                        for key in [KEY_SRC, KEY_DST] {
+                            if let Some(component) = module_cfg.get(KEY_COMPONENTS).and_then(|c| c.get(key)) {
+                                let name = component.get(KEY_NAME).and_then(|v| v.as_str()).unwrap_or_default();
+                                self.check_scripts(component, &location, |s| format!("{}/{module}/{s}/{provider}/{name}", config.rte.path));
+                            }
+                        }
+                    }
+                }
+                Err(e) => self.report.push_error(&location, e),
+            }
+
+            let mut connections: HashSet<String> = HashSet::new();
+
+            for connection in rte.get(KEY_CONNECTIONS).and_then(|v| v.as_array()).into_iter().flatten() {
+                let c_name = connection.get(KEY_NAME).and_then(|v| v.as_str()).unwrap_or_default();
+                let c_location = format!("{location}/{KEY_CONNECTIONS}/{c_name}");
+
+                if !connections.insert(c_name.to_string()) {
+                    self.report.push(IssueKind::DuplicateName, &c_location,
+                                     format!("{KEY_CONNECTION} <{c_name}> is defined more than once"));
+                }
+
+                if let Some(source) = connection.get(KEY_SOURCE).and_then(|v| v.as_str()) {
+                    if !self.has_name(VERTEX_TYPE_SITE, source) {
+                        self.report.push(IssueKind::DanglingReference, &c_location,
+                                         format!("{KEY_SOURCE} refers to unknown {VERTEX_TYPE_SITE} <{source}>"));
+                    }
+                }
+
+                let mut c_tests: HashSet<String> = HashSet::new();
+
+                for test in connection.get(KEY_TESTS).and_then(|v| v.as_array()).into_iter().flatten() {
+                    let t_name = test.get(KEY_NAME).and_then(|v| v.as_str()).unwrap_or_default();
+                    let t_location = format!("{c_location}/{KEY_TESTS}/{t_name}");
+
+                    if !c_tests.insert(t_name.to_string()) {
+                        self.report.push(IssueKind::DuplicateName, &t_location,
+                                         format!("{KEY_TEST} <{t_name}> is defined more than once"));
+                    }
+                    tests.insert((rte_name.to_string(), c_name.to_string(), t_name.to_string()));
+
+                    match test.get_str_value(KEY_MODULE, &t_location) {
+                        Ok(module) => {
+                            if let Some(module_cfg) = self.load_module(KEY_TEST, &config.tests.path, module, &t_location) {
+                                self.check_scripts(&module_cfg, &t_location, |s| format!("{}/{module}/{s}", config.tests.path));
+                            }
+                        }
+                        Err(e) => self.report.push_error(&t_location, e),
+                    }
+
+                    for v in test.get(KEY_VERIFICATIONS).and_then(|v| v.as_array()).into_iter().flatten() {
+                        match v.get_str_value(KEY_MODULE, &t_location) {
+                            Ok(module) => {
+                                let v_location = format!("{t_location}/{KEY_VERIFICATIONS}/{module}");
+                                if let Some(module_cfg) = self.load_module(KEY_VERIFICATION, &config.verifications.path, module, &v_location) {
+                                    self.check_scripts(&module_cfg, &v_location, |s| format!("{}/{module}/{s}", config.verifications.path));
+                                }
+                            }
+                            Err(e) => self.report.push_error(&t_location, e),
+                        }
+                    }
+
+                    if let Some(r) = test.get(KEY_REFS).and_then(|v| v.as_array()) {
+                        refs.extend(r.iter().map(|r| (t_location.clone(), r.clone())));
+                    }
+                    refs.extend(dependency_refs(test, rte_name, c_name).into_iter().map(|r| (t_location.clone(), r)));
+                }
+            }
+        }
+
+        // Refs
+        for (location, r) in refs.iter() {
+            self.check_ref(location, r, &tests);
+        }
+    }
+
+    fn check_ref(&mut self, location: &str, r: &Value, tests: &HashSet<(String, String, String)>) {
+        let (r_type, name) = match (r.get_str_value(KEY_TYPE, location), r.get_str_value(KEY_NAME, location)) {
+            (Ok(t), Ok(n)) => (t, n),
+            (Err(e), _) | (_, Err(e)) => {
+                self.report.push_error(location, e);
+                return;
+            }
+        };
+
+        let found = match r_type {
+            VERTEX_TYPE_APPLICATION | VERTEX_TYPE_COLLECTOR | VERTEX_TYPE_SITE | VERTEX_TYPE_FEATURE |
+            VERTEX_TYPE_RTE | VERTEX_TYPE_REPORT => self.has_name(r_type, name),
+            VERTEX_TYPE_TEST => {
+                let rte = r.get(KEY_RTE).and_then(|v| v.as_str()).unwrap_or_default();
+                let connection = r.get(KEY_CONNECTION).and_then(|v| v.as_str()).unwrap_or_default();
+                tests.contains(&(rte.to_string(), connection.to_string(), name.to_string()))
+            }
+            _ => {
+                self.report.push(IssueKind::Config, location, format!("ref of unknown type <{r_type}>"));
+                return;
+            }
+        };
+
+        if !found {
+            self.report.push(IssueKind::DanglingReference, location,
+                             format!("refers to unknown {r_type} <{name}>"));
+        }
+    }
+
+    fn add_name(&mut self, v_type: &'static str, name: &str, location: &str) {
+        if !self.names.entry(v_type).or_default().insert(name.to_string()) {
+            self.report.push(IssueKind::DuplicateName, location,
+                             format!("{v_type} <{name}> is defined more than once"));
+        }
+    }
+
+    fn has_name(&self, v_type: &str, name: &str) -> bool {
+        self.names.get(v_type).is_some_and(|n| n.contains(name))
+    }
+
+    fn module_exists(&mut self, path: &str, module: &str, location: &str) -> bool {
+        let dir = format!("{}/{}/{}", self.config.root_path, path, module);
+
+        if !Path::new(&dir).is_dir() {
+            self.report.push(IssueKind::MissingModule, location,
+                             format!("module directory <{dir}> does not exist"));
+            return false;
+        }
+
+        true
+    }
+
+    fn load_module(&mut self, cfg_type: &str, path: &str, module: &str, location: &str) -> Option<Value> {
+        if !self.module_exists(path, module, location) {
+            return None;
+        }
+
+        match load_object_config(cfg_type, module, self.config) {
+            Ok(cfg) => Some(cfg),
+            Err(e) => {
+                self.report.push_error(location, e);
+                None
+            }
+        }
+    }
+
+    /// Check scripts listed in `cfg` exist. `dir` builds the scripts directory relative to root path
+    /// from the configured scripts_path.
+    fn check_scripts(&mut self, cfg: &Value, location: &str, dir: impl Fn(&str) -> String) {
+        let scripts = match cfg.get(KEY_SCRIPTS).and_then(|v| v.as_array()) {
+            Some(s) => s,
+            None => return,
+        };
+        let scripts_path = match cfg.get_str_value(KEY_SCRIPTS_PATH, location) {
+            Ok(p) => p,
+            Err(e) => {
+                self.report.push_error(location, e);
+                return;
+            }
+        };
+        let dir = dir(scripts_path);
+
+        for script in scripts.iter() {
+            match script.get_str_value(KEY_FILE, location) {
+                Ok(file) => {
+                    let path = format!("{}/{}/{}", self.config.root_path, dir, file);
+                    if !Path::new(&path).is_file() {
+                        self.report.push(IssueKind::MissingScript, location,
+                                         format!("script <{path}> does not exist"));
+                    }
+                }
+                Err(e) => self.report.push_error(location, e),
+            }
+        }
+    }
+}
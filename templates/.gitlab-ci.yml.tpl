@@ -1,3 +1,18 @@
+{#- Commands of script sections selected by action. "apply" selects every section except
+    "artifacts" and "destroy", other actions select the section of the same name -#}
+{%- macro script(scripts, action) -%}
+{%- for script in scripts -%}
+{%- for section, commands in script -%}
+{%- if action == section or action == "apply" and section != "artifacts" and section != "destroy" -%}
+{%- for command in commands %}
+        {{ command }}
+{%- endfor -%}
+{%- endif -%}
+{%- endfor -%}
+{%- endfor -%}
+{%- endmacro script -%}
+{#- Deploy jobs are placed into the first deploy stage of their object kind, artifacts jobs into
+    the last one and destroy jobs into the first destroy stage -#}
 #################################################################################
 # THIS FILE IT IS AUTOGENERATED. MANUAL CHANGES TO THIS FILE CAN BE OVERWRITTEN #
 #################################################################################
@@ -58,7 +73,8 @@ variables:
 .regression_test_seq_and_verification_rules:
   rules:
     - if: $ACTION == "test-seq-and-verify" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
-{% for feature in features %}
+{% for group in euts -%}
+{% for feature in group.features %}
 .deploy_{{ feature.job | replace(from="-", to="_") }}_rules:
   rules:
     - if: $ACTION == "deploy-{{ feature.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
@@ -67,7 +83,7 @@ variables:
   rules:
     - if: $ACTION == "destroy-{{ feature.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
 {% endfor -%}
-{% for site in eut.sites %}
+{% for site in group.eut.sites %}
 .deploy_{{ site.job | replace(from="-", to="_") }}_rules:
   rules:
     - if: $ACTION == "deploy-{{ site.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
@@ -76,7 +92,7 @@ variables:
   rules:
     - if: $ACTION == "destroy-{{ site.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
 {% endfor -%}
-{% for rte in rtes -%}
+{% for rte in group.rtes -%}
 {% for component in rte.components %}
 .deploy_{{ component.job | replace(from="-", to="_") }}_rules:
   rules:
@@ -99,7 +115,7 @@ variables:
 {% endfor -%}
 {% endfor -%}
 {% endfor -%}
-{% for application in applications %}
+{% for application in group.applications %}
 .deploy_{{ application.job | replace(from="-", to="_") }}_rules:
   rules:
     - if: $ACTION == "deploy-{{ application.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
@@ -108,10 +124,11 @@ variables:
   rules:
     - if: $ACTION == "destroy-{{ application.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
 {% endfor -%}
-{% for report in reports %}
+{% for report in group.reports %}
 .regression_{{ report.job | replace(from="-", to="_") }}_rules:
   rules:
     - if: $ACTION == "deploy-{{ report.job }}" && $CI_PIPELINE_SOURCE == "trigger" && $CI_PIPELINE_TRIGGERED == "true"
+{% endfor -%}
 {% endfor %}
 .base: &base
   tags:
@@ -190,39 +207,33 @@ monitor-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 
-# project - {{ project.module.name }} - deploy
+# project - {{ project.base.module }} - deploy
 project-deploy:
   <<: *base
-  stage: project-deploy
+  stage: {{ config.project.ci.stages.deploy | first }}
   rules:
     - !reference [ .deploy_project_rules, rules ]
   script:
       - |
-        {%- for script in project.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=project.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if project.module.ci.timeout %}
   timeout: {{ project.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
-# project - {{ project.module.name }} - artifacts
+{% if project.scripts | filter(attribute="artifacts") | length > 0 %}
+# project - {{ project.base.module }} - artifacts
 project-artifacts:
   <<: *base
-  stage: project-artifacts
+  stage: {{ config.project.ci.stages.deploy | last }}
   rules:
       - if: $ACTION == "deploy-project"
         when: never
@@ -233,90 +244,73 @@ project-artifacts:
       - when: always
   script:
       - |
-        {%- for script in project.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=project.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if project.module.ci.timeout %}
   timeout: {{ project.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% endif %}
 # dashboard - {{ dashboard.base.module }} - deploy
 dashboard-deploy:
   <<: *base
-  stage: dashboard-deploy
+  stage: {{ config.dashboard.ci.stages.deploy | first }}
   rules:
     - !reference [ .deploy_dashboard_rules, rules ]
   script:
       - |
-        {%- for script in dashboard.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=dashboard.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if dashboard.provider.ci.timeout %}
   timeout: {{ dashboard.provider.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-{% for rte in rtes -%}
+{% for group in euts -%}
+{% for rte in group.rtes -%}
 {% for component in rte.components %}
 # {{ component.job | replace(from="_", to="-") }} - deploy
 {{ component.job | replace(from="_", to="-") }}-deploy:
   <<: *base
-  stage: rte-deploy
+  stage: {{ group.stages.rte.deploy | first }}
   rules:
     - !reference [ .deploy_rules, rules ]
     - !reference [ .deploy_{{ component.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in component.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=component.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
-      {%- for path in rte.ci.artifacts.paths %}
+      {%- for path in rte.ci.artifacts.paths | default(value=[]) %}
       - {{ path }}
       {%- endfor %}
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if rte.ci.timeout %}
   timeout: {{ rte.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% if component.scripts | filter(attribute="artifacts") | length > 0 %}
 # {{ component.job | replace(from="_", to="-") }} - artifacts
 {{ component.job | replace(from="_", to="-") }}-artifacts:
   <<: *base
@@ -328,7 +322,7 @@ dashboard-deploy:
     {%- for test in rte.tests %}
     - !reference [ .regression_{{ test.job | replace(from="-", to="_") }}_rules, rules ]
     {%- endfor %}
-    {%- for application in applications %}
+    {%- for application in group.applications %}
     {%- for ref in application.base.refs %}
     {%- if ref.type == "rte" %}
     - !reference [ .deploy_{{ application.job | replace(from="-", to="_") }}_rules, rules ]
@@ -336,18 +330,10 @@ dashboard-deploy:
     {%- endif %}
     {%- endfor %}
     {%- endfor %}
-  stage: rte-artifacts
+  stage: {{ group.stages.rte.deploy | last }}
   script:
       - |
-        {%- for script in component.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=component.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
@@ -359,46 +345,41 @@ dashboard-deploy:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
+{% endif -%}
 {% endfor -%}
 {% endfor -%}
-{% for site in eut.sites %}
+{% for site in group.eut.sites %}
 # eut - {{ site.job }} - deploy
 {{ site.job }}-deploy:
   <<: *base
-  stage: eut-deploy
+  stage: {{ group.stages.eut.deploy | first }}
   rules:
     - !reference [ .deploy_rules, rules ]
     - !reference [ .deploy_{{ site.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in site.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=site.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
-  timeout: {{ eut.module.ci.timeout }}
+  {%- if group.eut.module.ci.timeout %}
+  timeout: {{ group.eut.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% if site.scripts | filter(attribute="artifacts") | length > 0 %}
 # eut - {{ site.job }} - artifacts - {{ site.provider }}
 {{ site.job }}-artifacts:
   <<: *base
-  stage: eut-artifacts
+  stage: {{ group.stages.eut.deploy | last }}
   rules:
     - !reference [ .destroy_rules, rules ]
-    {%- for application in applications %}
+    {%- for application in group.applications %}
     {%- if application.base.provider == site.provider %}
     {%- for ref in application.base.refs %}
     {%- if ref.type == "site" %}
@@ -410,133 +391,111 @@ dashboard-deploy:
     {%- endfor %}
   script:
       - |
-        {%- for script in site.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=site.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
-  timeout: {{ eut.module.ci.timeout }}
+  {%- if group.eut.module.ci.timeout %}
+  timeout: {{ group.eut.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
+{% endif -%}
 {% endfor -%}
-{% for feature in features %}
+{% for feature in group.features %}
 # feature - {{ feature.job }} - deploy
 {{ feature.job }}-deploy:
   <<: *base
-  stage: feature-deploy
+  stage: {{ group.stages.features.deploy | first }}
   rules:
     - !reference [ .deploy_rules, rules ]
     - !reference [ .deploy_{{ feature.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in feature.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=feature.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if feature.module.ci.timeout %}
   timeout: {{ feature.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 2
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% if feature.scripts | filter(attribute="artifacts") | length > 0 %}
 # feature - {{ feature.job }} - artifacts
 {{ feature.job }}-artifacts:
   <<: *base
-  stage: feature-artifacts
+  stage: {{ group.stages.features.deploy | last }}
   rules:
     - !reference [ .destroy_rules, rules ]
-    {%- for application in applications %}
+    {%- for application in group.applications %}
     {%- for ref in application.base.refs %}
     {%- if ref.type == "feature" %}
-    - !reference [ .deploy{{ application.job | replace(from="-", to="_") }}_rules, rules ]
+    - !reference [ .deploy_{{ application.job | replace(from="-", to="_") }}_rules, rules ]
     {%- endif %}
     {%- endfor %}
     {%- endfor %}
   script:
       - |
-        {%- for script in feature.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=feature.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if feature.module.ci.timeout %}
   timeout: {{ feature.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
+{% endif -%}
 {% endfor -%}
-{% for application in applications %}
+{% for application in group.applications %}
 # application - {{ application.job }} - deploy
 {{ application.job }}-deploy:
   <<: *base
-  stage: application-deploy
+  stage: {{ group.stages.applications.deploy | first }}
   rules:
     - !reference [ .deploy_rules, rules ]
     - !reference [ .deploy_{{ application.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in application.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=application.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if application.module.ci.timeout %}
   timeout: {{ application.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% if application.scripts | filter(attribute="artifacts") | length > 0 %}
 # application - {{ application.job }} - artifacts
 {{ application.job }}-artifacts:
   <<: *base
-  stage: application-artifacts
+  stage: {{ group.stages.applications.deploy | last }}
   rules:
     - !reference [ .regression_sequential_test_rules, rules ]
     - !reference [ .regression_test_seq_and_verification_rules, rules ]
-    {%- for rte in rtes %}
+    {%- for rte in group.rtes %}
     {%- for test in rte.tests %}
     {%- if application.base.provider == rte.base.provider %}
     - !reference [ .regression_{{ test.job | replace(from="-", to="_") }}_rules, rules ]
@@ -545,28 +504,23 @@ dashboard-deploy:
     {%- endfor %}
   script:
       - |
-        {%- for script in application.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=application.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if application.module.ci.timeout %}
   timeout: {{ application.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
+{% endif -%}
 {% endfor -%}
-{% for rte in rtes -%}
+{% for rte in group.rtes -%}
 {% for test in rte.tests %}
 # test - {{ test.job }} - deploy
 {{ test.job }}-deploy:
@@ -574,23 +528,17 @@ dashboard-deploy:
   rules:
     - !reference [ .regression_test_rules, rules ]
     - !reference [ .regression_{{ test.job | replace(from="-", to="_") }}_rules, rules ]
-  stage: regression-test
+  stage: {{ group.stages.tests.deploy | first }}
   script:
       - |
-        {%- for script in test.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=test.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if test.ci.timeout %}
   timeout: {{ test.ci.timeout }}
+  {%- endif %}
   allow_failure: true
   retry:
     max: 0
@@ -598,7 +546,7 @@ dashboard-deploy:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% if test.scripts | filter(attribute="artifacts") | length > 0 %}
 # test - {{ test.job }} - artifacts
 {{ test.job }}-artifacts:
   <<: *base
@@ -607,56 +555,50 @@ dashboard-deploy:
     {%- for verification in test.verifications %}
     - !reference [ .regression_{{ verification.job | replace(from="-", to="_") }}_rules, rules ]
     {%- endfor %}
-    {%- for report in reports %}
+    {%- for report in group.reports %}
     - !reference [ .regression_{{ report.job | replace(from="-", to="_") }}_rules, rules ]
     {%- endfor %}
-  stage: regression-test-artifacts
+  stage: {{ group.stages.tests.deploy | last }}
   script:
       - |
-        {%- for script in test.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "artifacts" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=test.scripts, action="artifacts") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if test.ci.timeout %}
   timeout: {{ test.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
       - script_failure
       - stuck_or_timeout_failure
       - runner_system_failure
-
+{% endif %}
 # test - {{ test.job }} - seq - deploy
 {{ test.job }}-seq-deploy:
   <<: *base
   rules:
     - !reference [ .regression_sequential_test_rules, rules ]
     - !reference [ .regression_test_seq_and_verification_rules, rules ]
-  stage: test-{{ rte.name | replace(from="_", to="-") }}-{{ test.provider }}-{{ test.module | replace(from="_", to="-") }}-{{ test.name | replace(from="_", to="-") }}-deploy
+  stage: {{ test.stage }}
+  {%- if test.needs or test.after %}
+  needs:
+    {%- for need in test.needs | concat(with=test.after) %}
+    - job: {{ need }}-seq-deploy
+    {%- endfor %}
+  {%- endif %}
   script:
       - |
-        {%- for script in test.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=test.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if test.ci.timeout %}
   timeout: {{ test.ci.timeout }}
+  {%- endif %}
   allow_failure: true
   retry:
     max: 1
@@ -666,7 +608,7 @@ dashboard-deploy:
       - runner_system_failure
 {% endfor -%}
 {% endfor -%}
-{% for rte in rtes -%}
+{% for rte in group.rtes -%}
 {% for test in rte.tests -%}
 {% for verification in test.verifications %}
 # verification - {{ verification.job }} - deploy
@@ -676,19 +618,13 @@ dashboard-deploy:
     - !reference [ .regression_verification_rules, rules ]
     - !reference [ .regression_{{ verification.job | replace(from="-", to="_") }}_rules, rules ]
     - !reference [ .regression_test_seq_and_verification_rules, rules ]
-  stage: regression-test-verify
+  stage: {{ verification.stage }}
   script:
       - |
-        {%- for script in verification.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=verification.scripts, action="apply") }}
+  {%- if verification.ci.timeout %}
   timeout: {{ verification.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
@@ -698,32 +634,25 @@ dashboard-deploy:
 {% endfor -%}
 {% endfor -%}
 {% endfor -%}
-
-{% for collector in collectors %}
+{% for collector in group.collectors %}
 # collector - {{ collector.job }} - deploy
 {{ collector.job }}-deploy:
   <<: *base
   rules:
-    {%- for report in reports %}
+    {%- for report in group.reports %}
     - !reference [ .regression_{{ report.job | replace(from="-", to="_") }}_rules, rules ]
     {%- endfor %}
-  stage: {{ collector.module.stages.deploy[0] }}
+  stage: {{ group.prefix }}{{ collector.module.stages.deploy | first }}
   script:
       - |
-        {%- for script in collector.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "apply" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=collector.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if collector.module.ci.timeout %}
   timeout: {{ collector.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 0
     when:
@@ -731,60 +660,44 @@ dashboard-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 {% endfor -%}
-
-{% for report in reports %}
+{% for report in group.reports %}
 # report - {{ report.job }} - deploy
 {{ report.job }}-deploy:
   <<: *base
   rules:
-    {%- for report in reports %}
     - !reference [ .regression_{{ report.job | replace(from="-", to="_") }}_rules, rules ]
-    {%- endfor %}
-  stage: report-deploy
+  stage: {{ group.stages.reports.deploy | first }}
   script:
-        - |
-          {%- for script in report.scripts %}
-          {%- for k, v in script %}
-          {%- if k == "apply" %}
-          {%- for command in v %}
-          {{ command }}
-          {%- endfor %}
-          {%- endif %}
-          {%- endfor %}
-          {%- endfor %}
+      - |
+        {{- self::script(scripts=report.scripts, action="apply") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if report.module.ci.timeout %}
   timeout: {{ report.module.ci.timeout }}
+  {%- endif %}
   retry:
-      max: 0
-      when:
-        - script_failure
-        - stuck_or_timeout_failure
-        - runner_system_failure
+    max: 0
+    when:
+      - script_failure
+      - stuck_or_timeout_failure
+      - runner_system_failure
 {% endfor -%}
-
-{% for feature in features %}
+{% for feature in group.features %}
 # feature - {{ feature.job }} - destroy
 {{ feature.job }}-destroy:
   <<: *base
-  stage: feature-destroy
+  stage: {{ group.stages.features.destroy | first }}
   rules:
     - !reference [ .destroy_rules, rules ]
     - !reference [ .destroy_{{ feature.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in feature.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "destroy" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=feature.scripts, action="destroy") }}
+  {%- if feature.module.ci.timeout %}
   timeout: {{ feature.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
@@ -792,30 +705,24 @@ dashboard-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 {% endfor -%}
-{% for site in eut.sites %}
+{% for site in group.eut.sites %}
 # eut {{ site.job }} - destroy
 {{ site.job }}-destroy:
   <<: *base
-  stage: eut-destroy
+  stage: {{ group.stages.eut.destroy | first }}
   rules:
     - !reference [ .destroy_rules, rules ]
     - !reference [ .destroy_{{ site.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in site.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "destroy" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=site.scripts, action="destroy") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
-  timeout: {{ eut.module.ci.timeout }}
+  {%- if group.eut.module.ci.timeout %}
+  timeout: {{ group.eut.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
@@ -823,30 +730,24 @@ dashboard-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 {% endfor -%}
-{% for application in applications %}
+{% for application in group.applications %}
 # application - {{ application.job }} - destroy
 {{ application.job }}-destroy:
   <<: *base
-  stage: application-destroy
+  stage: {{ group.stages.applications.destroy | first }}
   rules:
     - !reference [ .destroy_rules, rules ]
     - !reference [ .destroy_{{ application.job | replace(from="-", to="_") }}_rules, rules ]
   script:
       - |
-        {%- for script in application.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "destroy" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=application.scripts, action="destroy") }}
   artifacts:
     paths:
       - $ARTIFACTS_ROOT_DIR/
     expire_in: {{ config.ci.artifacts.expire_in }}
+  {%- if application.module.ci.timeout %}
   timeout: {{ application.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
@@ -854,28 +755,20 @@ dashboard-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 {% endfor -%}
-{% for rte in rtes -%}
+{% for rte in group.rtes -%}
 {% for component in rte.components %}
 # {{ component.job | replace(from="_", to="-") }} - destroy
 {{ component.job | replace(from="_", to="-") }}-destroy:
   <<: *base
-  stage: rte-destroy
+  stage: {{ group.stages.rte.destroy | first }}
   rules:
     - !reference [ .destroy_rules, rules ]
     - !reference [ .destroy_{{ component.job | replace(from="-", to="_") }}_rules, rules ]
   script:
-    - |
-      export TF_VAR_f5xc_api_url_rte=$f5xc_api_url_rte
-      export TF_VAR_f5xc_api_token_rte=$f5xc_api_token_rte
-      {%- for script in component.scripts %}
-      {%- for k, v in script %}
-      {%- if k == "destroy" %}
-      {%- for command in v %}
-      {{ command }}
-      {%- endfor %}
-      {%- endif %}
-      {%- endfor %}
-      {%- endfor %}
+      - |
+        export TF_VAR_f5xc_api_url_rte=$f5xc_api_url_rte
+        export TF_VAR_f5xc_api_token_rte=$f5xc_api_token_rte
+        {{- self::script(scripts=component.scripts, action="destroy") }}
   {%- if rte.ci[component.provider].artifacts.needs_deploy %}
   needs:
     - project: $CI_PROJECT_PATH
@@ -883,7 +776,9 @@ dashboard-deploy:
       ref: $CI_COMMIT_REF_NAME
       artifacts: true
   {%- endif %}
+  {%- if rte.ci.timeout %}
   timeout: {{ rte.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
@@ -891,25 +786,20 @@ dashboard-deploy:
       - stuck_or_timeout_failure
       - runner_system_failure
 {% endfor -%}
+{% endfor -%}
 {% endfor %}
 # dashboard - {{ dashboard.base.module }} - destroy
 dashboard-destroy:
   <<: *base
-  stage: dashboard-destroy
+  stage: {{ config.dashboard.ci.stages.destroy | first }}
   rules:
     - !reference [ .destroy_dashboard_rules, rules ]
   script:
       - |
-        {%- for script in dashboard.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "destroy" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=dashboard.scripts, action="destroy") }}
+  {%- if dashboard.provider.ci.timeout %}
   timeout: {{ dashboard.provider.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
@@ -920,24 +810,18 @@ dashboard-destroy:
 # project - {{ project.base.module }} - destroy
 project-destroy:
   <<: *base
-  stage: project-destroy
+  stage: {{ config.project.ci.stages.destroy | first }}
   rules:
     - !reference [ .destroy_project_rules, rules ]
   script:
       - |
-        {%- for script in project.scripts %}
-        {%- for k, v in script %}
-        {%- if k == "destroy" %}
-        {%- for command in v %}
-        {{ command }}
-        {%- endfor %}
-        {%- endif %}
-        {%- endfor %}
-        {%- endfor %}
+        {{- self::script(scripts=project.scripts, action="destroy") }}
+  {%- if project.module.ci.timeout %}
   timeout: {{ project.module.ci.timeout }}
+  {%- endif %}
   retry:
     max: 1
     when:
       - script_failure
       - stuck_or_timeout_failure
-      - runner_system_failure
\ No newline at end of file
+      - runner_system_failure
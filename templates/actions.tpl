@@ -1,4 +1,14 @@
 {
     "tests": {{ actions.tests | json_encode(pretty=true) | safe }},
-    "verifications": {{ actions.verifications | json_encode(pretty=true) | safe }}
+    "verifications": {{ actions.verifications | json_encode(pretty=true) | safe }},
+    "tags": {{ actions.tags | json_encode(pretty=true) | safe }},
+    "euts": {
+{%- for eut in euts %}
+        {{ eut.name | json_encode | safe }}: {
+            "tests": {{ eut.actions.tests | json_encode | safe }},
+            "verifications": {{ eut.actions.verifications | json_encode | safe }},
+            "tags": {{ eut.actions.tags | json_encode | safe }}
+        }{% if not loop.last %},{% endif %}
+{%- endfor %}
+    }
 }
\ No newline at end of file
@@ -1,11 +1,19 @@
 # Project: {{ config.project.module }}
 
+# Pipeline
+
+```mermaid
+{{ stages_mermaid }}```
+
 # Actions
 
-## EUTs:
+{% for eut in euts -%}
+## EUT: {{ eut.name }}
 
-{% for site in actions.sites -%}
-### {{ site }}
+### SITES:
+
+{% for site in eut.actions.sites -%}
+#### {{ site }}
 
 ```bash
 curl --request POST \
@@ -30,10 +38,10 @@ curl --request POST \
 ```
 {% endfor %}
 
-## FEATURES:
+### FEATURES:
 
-{% for feature in actions.features -%}
-### {{ feature }}
+{% for feature in eut.actions.features -%}
+#### {{ feature }}
 
 ```bash
 curl --request POST \
@@ -55,10 +63,10 @@ curl --request POST \
 
 {% endfor %}
 
-## RTEs:
+### RTEs:
 
-{% for rte in actions.rtes -%}
-### {{ rte }}
+{% for rte in eut.actions.rtes -%}
+#### {{ rte }}
 
 ```bash
 curl --request POST \
@@ -80,10 +88,10 @@ curl --request POST \
 
 {% endfor %}
 
-## TESTS:
+### TESTS:
 
-{% for test in actions.tests -%}
-### {{ test }}
+{% for test in eut.actions.tests -%}
+#### {{ test }}
 
 ```bash
 curl --request POST \
@@ -105,10 +113,10 @@ curl --request POST \
 
 {% endfor %}
 
-## VERIFICATIONS:
+### VERIFICATIONS:
 
-{% for verification in actions.verifications -%}
-### {{ verification }}
+{% for verification in eut.actions.verifications -%}
+#### {{ verification }}
 
 ```bash
 curl --request POST \
@@ -129,3 +137,4 @@ curl --request POST \
 ```
 
 {% endfor %}
+{% endfor %}
@@ -0,0 +1,129 @@
+{%- macro steps(job, scripts, action) -%}
+{%- for script in scripts -%}
+{%- for section, commands in script -%}
+{%- if action == "destroy" and section == "destroy" or action == "deploy" and section != "destroy" %}
+      - name: {{ job }} {{ section }}
+        if: ${{ '{{' }} inputs.action == 'all' || inputs.action == '{{ action }}-{{ job }}' {{ '}}' }}
+        run: |
+{%- for command in commands %}
+          {{ command }}
+{%- endfor %}
+{%- endif -%}
+{%- endfor -%}
+{%- endfor -%}
+{%- endmacro steps -%}
+# Generated by sense8. Do not edit.
+name: {{ workflow.name }}
+
+on:
+  workflow_dispatch:
+    inputs:
+      action:
+        description: Job to run. "all" runs the whole regression
+        type: choice
+        default: all
+        options:
+{%- for input in workflow.inputs %}
+          - {{ input }}
+{%- endfor %}
+
+env:
+{%- for variable in config.ci.variables %}
+  {{ variable.name }}: "{{ variable.value }}"
+{%- endfor %}
+
+jobs:
+{%- for job in workflow.jobs %}
+{%- set_global action = "deploy" %}
+{%- for stages in [config.project.ci.stages.destroy, config.dashboard.ci.stages.destroy] %}
+{%- if job.name in stages %}{% set_global action = "destroy" %}{% endif %}
+{%- endfor %}
+{%- for group in euts %}
+{%- for stages in [group.stages.rte.destroy, group.stages.features.destroy, group.stages.eut.destroy, group.stages.applications.destroy] %}
+{%- if job.name in stages %}{% set_global action = "destroy" %}{% endif %}
+{%- endfor %}
+{%- endfor %}
+  {{ job.id }}:
+    name: {{ job.name }}
+    runs-on: ubuntu-latest
+    container: {{ config.ci.image }}
+{%- if job.needs %}
+    needs: [{{ job.needs | join(sep=", ") }}]
+{%- endif %}
+{%- if action == "destroy" %}
+    if: ${{ '{{' }} !cancelled() {{ '}}' }}
+{%- endif %}
+    steps:
+      - uses: actions/checkout@v4
+{%- if job.needs %}
+      - uses: actions/download-artifact@v4
+        with:
+          name: {{ workflow.name }}-artifacts
+          path: {{ workflow.artifacts.path }}
+        continue-on-error: true
+{%- endif %}
+{%- if job.name in config.project.ci.stages[action] %}
+{{- self::steps(job=project.job, scripts=project.scripts, action=action) }}
+{%- endif %}
+{%- if job.name in config.dashboard.ci.stages[action] %}
+{{- self::steps(job=workflow.name ~ "-dashboard", scripts=dashboard.scripts, action=action) }}
+{%- endif %}
+{%- for group in euts %}
+{%- if job.name in group.stages.rte[action] %}
+{%- for rte in group.rtes %}{% for component in rte.components %}
+{{- self::steps(job=component.job, scripts=component.scripts, action=action) }}
+{%- endfor %}{% endfor %}
+{%- endif %}
+{%- if job.name in group.stages.features[action] %}
+{%- for feature in group.features %}
+{{- self::steps(job=feature.job, scripts=feature.scripts, action=action) }}
+{%- endfor %}
+{%- endif %}
+{%- if job.name in group.stages.eut[action] %}
+{%- for site in group.eut.sites %}
+{{- self::steps(job=site.job, scripts=site.scripts, action=action) }}
+{%- endfor %}
+{%- endif %}
+{%- if job.name in group.stages.applications[action] %}
+{%- for application in group.applications %}
+{{- self::steps(job=application.job, scripts=application.scripts, action=action) }}
+{%- endfor %}
+{%- endif %}
+{%- for rte in group.rtes %}{% for test in rte.tests %}
+{%- if test.stage %}{% set test_stage = test.stage %}{% else %}
+{%- set test_stage = "test-" ~ test.rte ~ "-" ~ test.provider ~ "-" ~ test.module ~ "-" ~ test.name ~ "-deploy" %}
+{%- set test_stage = test_stage | replace(from="_", to="-") %}
+{%- set test_stage = group.prefix ~ test_stage %}{% endif %}
+{%- if job.name == test_stage %}
+{{- self::steps(job=test.job, scripts=test.scripts, action=action) }}
+{%- endif %}
+{%- for verification in test.verifications %}
+{%- if verification.stage %}{% set verification_stage = verification.stage %}{% else %}
+{%- set verification_stage = "verification-" ~ verification.rte ~ "-" ~ test.provider ~ "-" ~ verification.test ~ "-" ~ verification.module ~ "-" ~ verification.name ~ "-deploy" %}
+{%- set verification_stage = verification_stage | replace(from="_", to="-") %}
+{%- set verification_stage = group.prefix ~ verification_stage %}{% endif %}
+{%- if job.name == verification_stage %}
+{{- self::steps(job=verification.job, scripts=verification.scripts, action=action) }}
+{%- endif %}
+{%- endfor %}
+{%- endfor %}{% endfor %}
+{%- for collector in group.collectors %}{% if collector.module.stages %}
+{%- for stage in collector.module.stages.deploy %}
+{%- if job.name == group.prefix ~ stage %}
+{{- self::steps(job=collector.job, scripts=collector.scripts, action=action) }}
+{%- endif %}
+{%- endfor %}
+{%- endif %}{% endfor %}
+{%- if job.name in group.stages.reports[action] %}
+{%- for report in group.reports %}
+{{- self::steps(job=report.job, scripts=report.scripts, action=action) }}
+{%- endfor %}
+{%- endif %}
+{%- endfor %}
+      - uses: actions/upload-artifact@v4
+        if: ${{ '{{' }} always() {{ '}}' }}
+        with:
+          name: {{ workflow.name }}-artifacts
+          path: {{ workflow.artifacts.path }}
+          overwrite: true
+{%- endfor %}
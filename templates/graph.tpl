@@ -1,7 +1,7 @@
 strict digraph sense8 {
 
 graph [
-charset = "UTF-8";
+charset = "UTF-8",
 label = "sense8 object graph",
 labelloc = "t",
 labeljust = "c",
@@ -17,7 +17,7 @@ nodesep = 0.9
 ];
 
 node [
-colorscheme = "rdylgn11"
+colorscheme = "rdylgn11",
 style = "solid,filled",
 fontsize = 16,
 fontcolor = 6,
@@ -40,11 +40,19 @@ labeldistance = 2.5,
 labelangle = 70
 ];
 
-{% for node in nodes -%}
-node[label="{{ node.label }}"]
-{{ node.id }} [shape={{ node.shape }}]
+{% for item in items -%}
+{% if item.kind == "begin" -%}
+subgraph "{{ item.id }}" {
+label = "{{ item.label }}";
+style = "rounded,dashed";
+color = white;
+{% elif item.kind == "end" -%}
+}
+{% else -%}
+"{{ item.id }}" [label="{{ item.label }}", shape={{ item.shape }}, fillcolor="{{ item.fillcolor }}"]
+{% endif -%}
 {% endfor %}
 {% for edge in edges -%}
-{{ edge.src }} -> {{ edge.dst }} [{{ edge.src }}={{ edge.dst }}]
+"{{ edge.src }}" -> "{{ edge.dst }}" [label="{{ edge.label }}"]
 {% endfor -%}
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+mod common;
+
+use std::fs;
+
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::error::Sense8Error;
+use sense8_ci_generator::pipeline::check;
+
+const PIPELINES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/pipelines");
+
+#[test]
+fn rendered_fixture_pipeline_passes_check() {
+    check(&common::render(BACKEND_GITLAB, &[])).unwrap();
+    check(&common::render(BACKEND_GITLAB, &[common::EUT_DEPENDENCIES])).unwrap();
+}
+
+#[test]
+fn invalid_pipeline_reports_every_offending_job() {
+    let data = fs::read_to_string(format!("{PIPELINES_PATH}/invalid-gitlab-ci.yml")).unwrap();
+    let issues = match check(&data).unwrap_err() {
+        Sense8Error::PipelineInvalid(issues) => issues,
+        err => panic!("unexpected error <{err}>"),
+    };
+    let issues: Vec<(&str, &str)> = issues.iter().map(|(j, m)| (j.as_str(), m.as_str())).collect();
+
+    assert_eq!(issues, vec![
+        ("project-deploy", "job name is not unique"),
+        ("unknown-stage-deploy", "stage <eut-deploy> is not defined in <stages>"),
+        ("unknown-extends-deploy", "extends unknown job <.destroy_rules>"),
+        ("unknown-needs-deploy", "needs unknown job <test-a-deploy>"),
+    ]);
+}
+
+#[test]
+fn unparsable_pipeline_is_invalid() {
+    assert!(matches!(check("stages: [\n"), Err(Sense8Error::PipelineInvalid(_))));
+}
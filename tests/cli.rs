@@ -0,0 +1,54 @@
+mod common;
+
+use std::fs;
+use std::process::{Command, Output};
+
+const EXPECTED_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/expected");
+
+/// Run `sense8` with fixture regression global options followed by `args`.
+fn sense8(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sense8"))
+        .args(["--root-path", common::ROOT_PATH, "--config-file", common::CONFIG_FILE,
+            "--eut-config-file", common::EUT_CONFIG_FILE])
+        .args(args)
+        .env("RUST_LOG", "off")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn generate_prints_pipeline() {
+    let output = sense8(&["generate", "--stdout"]);
+    let expected = fs::read_to_string(format!("{EXPECTED_PATH}/gitlab-ci.yml")).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap().trim_end(), expected.trim_end());
+}
+
+#[test]
+fn output_files_go_to_out_dir_or_output_path() {
+    let dir = common::scratch_dir("cli-output");
+    let out_dir = dir.join("out");
+    let actions = dir.join("nested/actions.json");
+
+    let by_dir = sense8(&["--out-dir", out_dir.to_str().unwrap(), "generate"]);
+    let by_path = sense8(&["actions", "--output", actions.to_str().unwrap()]);
+    let written = (out_dir.join(".gitlab-ci.yml").is_file(), actions.is_file());
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(by_dir.status.success(), "{}", String::from_utf8_lossy(&by_dir.stderr));
+    assert!(by_path.status.success(), "{}", String::from_utf8_lossy(&by_path.stderr));
+    assert_eq!(written, (true, true));
+}
+
+#[test]
+fn command_without_configuration_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_sense8"))
+        .args(["generate", "--stdout"])
+        .env("RUST_LOG", "off")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--root-path and --config-file are required"));
+}
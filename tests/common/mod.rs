@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::Result;
+use sense8_ci_generator::filter::{Filter, TagFilter};
+use sense8_ci_generator::overlay::{Assignment, Overlay};
+use sense8_ci_generator::Regression;
+use uuid::Uuid;
+
+pub const ROOT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/regression");
+pub const CONFIG_FILE: &str = "config.json";
+pub const EUT_CONFIG_FILE: &str = "eut.json";
+/// Template directory glob of the templates bundled with the crate.
+#[allow(dead_code)]
+pub const TEMPLATE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/*");
+/// Selects fixture EUT holding two rtes with tagged tests on distinct sites.
+#[allow(dead_code)]
+pub const EUT_SELECTION: &str = "eut.module=eut_c";
+/// Selects fixture EUT running three parallel tests one at a time where the second test depends on
+/// the first.
+#[allow(dead_code)]
+pub const EUT_DEPENDENCIES: &str = "eut.module=eut_d";
+
+/// Fixture regression with `sets` applied to its configuration like `--set` does.
+pub fn regression<'a>(db: &'a Db, sets: &[&str]) -> Regression<'a> {
+    regression_with_template(db, sets, "")
+}
+
+/// Fixture regression rendered with templates of `template` directory glob like `--template` does.
+pub fn regression_with_template<'a>(db: &'a Db, sets: &[&str], template: &str) -> Regression<'a> {
+    let sets: Vec<Assignment> = sets.iter().map(|s| Assignment::parse(s).unwrap()).collect();
+    let overlay = Overlay::default().with_sets(&sets);
+
+    Regression::new(db, ROOT_PATH, CONFIG_FILE, template, &Some(EUT_CONFIG_FILE.to_string()), &overlay).unwrap()
+}
+
+/// Build object graph of fixture regression. Returns regression and project object id.
+pub fn build<'a>(db: &'a Db, sets: &[&str]) -> (Regression<'a>, Uuid) {
+    try_build(db, sets, &[], &TagFilter::default()).unwrap()
+}
+
+/// Build object graph of fixture regression pruned to `filters` and `tags` like `--only`,
+/// `--include-tags` and `--exclude-tags` do.
+pub fn try_build<'a>(db: &'a Db, sets: &[&str], filters: &[Filter], tags: &TagFilter) -> Result<(Regression<'a>, Uuid)> {
+    let r = regression(db, sets);
+    let (p, refs) = r.init()?;
+    r.init_refs(p, &refs)?;
+    let refs = r.prune(filters, tags, refs)?;
+    r.init_artifacts(p, &refs)?;
+    r.init_stages(p)?;
+
+    Ok((r, p))
+}
+
+/// Build object graph of fixture regression and render pipeline file for `backend`.
+#[allow(dead_code)]
+pub fn render(backend: &str, sets: &[&str]) -> String {
+    try_render(backend, sets, &[], &TagFilter::default()).unwrap()
+}
+
+/// Build object graph of fixture regression pruned to `filters` and `tags` and render pipeline
+/// file for `backend`.
+#[allow(dead_code)]
+pub fn try_render(backend: &str, sets: &[&str], filters: &[Filter], tags: &TagFilter) -> Result<String> {
+    let db = Db::new();
+    let (r, p) = try_build(&db, sets, filters, tags)?;
+    let ctx = r.build_context(p)?;
+
+    r.render(new_backend(backend).unwrap().as_ref(), &ctx)
+}
+
+/// Build object graph of fixture regression and build pipeline file from native pipeline model of
+/// `backend`.
+#[allow(dead_code)]
+pub fn render_native(backend: &str, sets: &[&str]) -> String {
+    let db = Db::new();
+    let (r, p) = build(&db, sets);
+    let ctx = r.build_context(p).unwrap();
+
+    r.render_native(new_backend(backend).unwrap().as_ref(), &ctx).unwrap()
+}
+
+/// Build object graph of fixture regression, render pipeline file for `backend` with templates of
+/// `template` directory glob and check it like `generate` does before writing it.
+#[allow(dead_code)]
+pub fn render_with_template(backend: &str, sets: &[&str], template: &str) -> String {
+    let db = Db::new();
+    let r = regression_with_template(&db, sets, template);
+    let (p, refs) = r.init().unwrap();
+    r.init_refs(p, &refs).unwrap();
+    r.init_artifacts(p, &refs).unwrap();
+    r.init_stages(p).unwrap();
+    let ctx = r.build_context(p).unwrap();
+    let backend = new_backend(backend).unwrap();
+    let pipeline = r.render(backend.as_ref(), &ctx).unwrap();
+    backend.verify(&pipeline).unwrap();
+
+    pipeline
+}
+
+/// Scratch directory of `test` below the system temp directory. Previous content is removed.
+#[allow(dead_code)]
+pub fn scratch_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sense8-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Copy directory `src` recursively to `dst`.
+#[allow(dead_code)]
+pub fn copy_dir(src: &Path, dst: &Path) {
+    fs::create_dir_all(dst).unwrap();
+    for entry in fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        match entry.file_type().unwrap().is_dir() {
+            true => copy_dir(&entry.path(), &dst.join(entry.file_name())),
+            false => { fs::copy(entry.path(), dst.join(entry.file_name())).unwrap(); }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use sense8_ci_generator::diff::{diff, ChangeKind, DiffReport, Source, Subject};
+use sense8_ci_generator::overlay::{Assignment, Overlay};
+
+/// Diff fixture regression against itself with `sets` applied to the new side like `--set` does.
+fn diff_sets(sets: &[&str]) -> DiffReport {
+    let sets: Vec<Assignment> = sets.iter().map(|s| Assignment::parse(s).unwrap()).collect();
+    let eut_file = Some(common::EUT_CONFIG_FILE.to_string());
+    let old_overlay = Overlay::default();
+    let new_overlay = Overlay::default().with_sets(&sets);
+    let old = Source { path: common::ROOT_PATH, file: common::CONFIG_FILE, eut_file: &eut_file, overlay: &old_overlay };
+    let new = Source { path: common::ROOT_PATH, file: common::CONFIG_FILE, eut_file: &eut_file, overlay: &new_overlay };
+
+    diff(&old, &new).unwrap()
+}
+
+#[test]
+fn same_configuration_has_no_changes() {
+    let report = diff_sets(&[]);
+
+    assert!(report.is_empty(), "{report}");
+    assert_eq!(report.to_string(), "no changes");
+}
+
+#[test]
+fn other_eut_reports_stages_jobs_artifacts_and_refs() {
+    let report = diff_sets(&[common::EUT_DEPENDENCIES]);
+    let has = |kind: ChangeKind, subject: Subject, name: &str| report.changes.iter()
+        .any(|c| c.kind == kind && c.subject == subject && c.name == name);
+
+    assert!(has(ChangeKind::Removed, Subject::Stage, "test-rte-a-site-1-test-a-test-a-deploy"), "{report}");
+    assert!(has(ChangeKind::Added, Subject::Stage, "test-parallel-deploy"), "{report}");
+    assert!(has(ChangeKind::Removed, Subject::Job, "regression-x-eut-eut-a-site-1-deploy"), "{report}");
+    assert!(has(ChangeKind::Added, Subject::Job, "regression-x-test-site-3-test-a-deploy"), "{report}");
+    assert!(has(ChangeKind::Modified, Subject::Artifacts, "sites__site_site_1 artifacts_path"), "{report}");
+    assert!(has(ChangeKind::Added, Subject::Ref,
+                "project_regression_x__rtes__rte_rte_a__connections__connection__connection_src -[refers_site]-> sites__site_site_3"), "{report}");
+
+    let moved = report.changes.iter().find(|c| c.name == "regression-x-test-site-1-test-a-deploy").unwrap();
+    assert_eq!((moved.kind, &moved.details), (ChangeKind::Modified,
+               &vec!["stage: test-rte-a-site-1-test-a-test-a-deploy -> test-parallel-deploy".to_string()]));
+}
+
+#[test]
+fn json_mode_lists_changes() {
+    let report = diff_sets(&[common::EUT_DEPENDENCIES]);
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+
+    assert_eq!(json["changes"].as_array().unwrap().len(), report.changes.len());
+    assert_eq!(json["changes"][0]["kind"], "removed");
+    assert_eq!(json["changes"][0]["subject"], "stage");
+}
+
+#[test]
+fn edited_test_script_modifies_job() {
+    let dir = common::scratch_dir("diff-script");
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    fs::write(root.join("tests/test_a/scripts/deploy.sh"), "echo deploy\necho done\n").unwrap();
+
+    let eut_file = Some(common::EUT_CONFIG_FILE.to_string());
+    let overlay = Overlay::default();
+    let old = Source { path: common::ROOT_PATH, file: common::CONFIG_FILE, eut_file: &eut_file, overlay: &overlay };
+    let new = Source { path: root.to_str().unwrap(), file: common::CONFIG_FILE, eut_file: &eut_file, overlay: &overlay };
+    let report = diff(&old, &new).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(report.to_string(), "~ job regression-x-test-site-1-test-a-deploy\n    + echo done\n1 change");
+}
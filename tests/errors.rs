@@ -0,0 +1,31 @@
+mod common;
+
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::error::Sense8Error;
+use sense8_ci_generator::filter::TagFilter;
+
+/// Selects fixture EUT whose connection source names a site the EUT does not hold.
+const EUT_DANGLING: &str = "eut.module=eut_dangling";
+
+#[test]
+fn unknown_connection_source_site_is_dangling_reference() {
+    let err = common::try_render(BACKEND_GITLAB, &[EUT_DANGLING], &[], &TagFilter::default()).unwrap_err();
+
+    match err {
+        Sense8Error::DanglingReference { r#type, name, .. } => assert_eq!((r#type.as_str(), name.as_str()), ("site", "site_9")),
+        err => panic!("unexpected error <{err}>"),
+    }
+}
+
+#[test]
+fn unknown_application_ref_is_dangling_reference() {
+    let err = common::try_render(BACKEND_GITLAB, &["eut.module=eut_dangling_ref"], &[], &TagFilter::default()).unwrap_err();
+
+    match err {
+        Sense8Error::DanglingReference { id_path, r#type, name } => {
+            assert_eq!((r#type.as_str(), name.as_str()), ("site", "site_9"));
+            assert!(id_path.ends_with("app_a"), "unexpected id path <{id_path}>");
+        }
+        err => panic!("unexpected error <{err}>"),
+    }
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use sense8_ci_generator::constants::{BACKEND_GITHUB, BACKEND_GITLAB, TOPOLOGY_FORMAT_PLANTUML};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::topology::RteTopology;
+
+const EUTS: &str = r#"eut.variants=[{"name":"eut_a","module":"eut_a"},{"name":"eut_b","module":"eut_b"}]"#;
+
+/// Jobs of EUT scoped objects held by `eut` of the fixture regression.
+fn eut_jobs(eut: &str) -> Vec<String> {
+    let prefix = format!("regression-x-{}", eut.replace('_', "-"));
+
+    vec![
+        format!("{prefix}-feature-feature-a"),
+        format!("{prefix}-eut-{}-site-1", eut.replace('_', "-")),
+        format!("{prefix}-eut-{}-site-2", eut.replace('_', "-")),
+        format!("{prefix}-application-application-a-aws-app-a"),
+        format!("{prefix}-test-site-1-test-a"),
+        format!("{prefix}-verification-rte-a-site-1-test-a-verification-a"),
+        format!("{prefix}-collector-collector-a"),
+        format!("{prefix}-report-report-a-report-a"),
+    ]
+}
+
+#[test]
+fn gitlab_native_renders_jobs_of_every_eut() {
+    let pipeline = common::render_native(BACKEND_GITLAB, &[EUTS]);
+
+    for eut in ["eut_a", "eut_b"] {
+        for job in eut_jobs(eut) {
+            assert!(pipeline.contains(&format!("\n{job}-deploy:\n")), "missing job <{job}>");
+        }
+    }
+}
+
+#[test]
+fn github_renders_jobs_of_every_eut() {
+    let workflow = common::render(BACKEND_GITHUB, &[EUTS]);
+
+    for eut in ["eut_a", "eut_b"] {
+        for job in eut_jobs(eut) {
+            assert!(workflow.contains(&format!("- name: {job} deploy\n")), "missing job <{job}>");
+        }
+    }
+}
+
+#[test]
+fn gitlab_template_renders_jobs_of_every_eut() {
+    let pipeline = common::render_with_template(BACKEND_GITLAB, &[EUTS], common::TEMPLATE_PATH);
+
+    for eut in ["eut_a", "eut_b"] {
+        for job in eut_jobs(eut) {
+            assert!(pipeline.contains(&format!("\n{job}-deploy:\n")), "missing job <{job}>");
+        }
+        assert!(pipeline.contains(&format!("\n  stage: {eut}-test-rte-a-site-1-test-a-test-a-deploy\n")), "missing test stage of <{eut}>");
+    }
+}
+
+#[test]
+fn topology_names_rtes_after_their_eut() {
+    let db = Db::new();
+    common::build(&db, &[EUTS]);
+    let files: Vec<String> = RteTopology::load(&db).iter().map(|r| r.file_name(TOPOLOGY_FORMAT_PLANTUML)).collect();
+
+    assert_eq!(files, vec!["topology-eut_a-rte_a.puml", "topology-eut_b-rte_a.puml"]);
+}
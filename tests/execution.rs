@@ -0,0 +1,103 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::{Result, Sense8Error};
+use sense8_ci_generator::overlay::{Assignment, Overlay};
+use sense8_ci_generator::Regression;
+
+const TEST_A: &str = "regression-x-test-site-1-test-a-deploy";
+/// Depends on TEST_A.
+const TEST_B: &str = "regression-x-test-site-3-test-a-deploy";
+/// Shares site_2 with TEST_B.
+const TEST_C: &str = "regression-x-test-site-2-test-a-deploy";
+const PARALLEL_STAGE: &str = "test-parallel-deploy";
+
+/// Build native GitLab pipeline of copy of fixture EUT `eut_d` after `edit` changed its rte.
+fn render_edited(test: &str, edit: impl Fn(&mut Value)) -> Result<serde_yaml::Value> {
+    let dir = common::scratch_dir(test);
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    let file = root.join("eut/eut_d/eut.json");
+    let mut eut: Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    edit(&mut eut["rtes"][0]);
+    fs::write(&file, eut.to_string()).unwrap();
+
+    let db = Db::new();
+    let sets = [Assignment::parse(common::EUT_DEPENDENCIES).unwrap()];
+    let overlay = Overlay::default().with_sets(&sets);
+    let pipeline = Regression::new(&db, root.to_str().unwrap(), common::CONFIG_FILE, "",
+                                   &Some(common::EUT_CONFIG_FILE.to_string()), &overlay)
+        .and_then(|r| {
+            let (p, refs) = r.init()?;
+            r.init_refs(p, &refs)?;
+            r.init_artifacts(p, &refs)?;
+            r.init_stages(p)?;
+            let ctx = r.build_context(p)?;
+            r.render_native(new_backend(BACKEND_GITLAB).unwrap().as_ref(), &ctx)
+        });
+    fs::remove_dir_all(&dir).unwrap();
+
+    Ok(serde_yaml::from_str(&pipeline?).unwrap())
+}
+
+/// Jobs needed by `job` in `pipeline`.
+fn needs(pipeline: &serde_yaml::Value, job: &str) -> Vec<String> {
+    pipeline[job]["needs"].as_sequence().into_iter().flatten()
+        .map(|n| n["job"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn parallel_tests_share_one_stage() {
+    let pipeline: serde_yaml::Value = serde_yaml::from_str(&common::render_native(BACKEND_GITLAB, &[common::EUT_DEPENDENCIES])).unwrap();
+    let stages: Vec<&str> = pipeline["stages"].as_sequence().unwrap().iter().map(|s| s.as_str().unwrap()).collect();
+
+    assert!(stages.contains(&PARALLEL_STAGE), "{stages:?}");
+    assert!(!stages.iter().any(|s| s.starts_with("test-rte-a-")), "{stages:?}");
+    for job in [TEST_A, TEST_B, TEST_C] {
+        assert_eq!(pipeline[job]["stage"].as_str(), Some(PARALLEL_STAGE), "stage of <{job}>");
+    }
+}
+
+#[test]
+fn max_parallel_waits_for_previous_test() {
+    let pipeline = render_edited("execution-max", |_| {}).unwrap();
+
+    assert_eq!(needs(&pipeline, TEST_A), Vec::<String>::new());
+    assert_eq!(needs(&pipeline, TEST_B), vec![TEST_A]);
+    assert_eq!(needs(&pipeline, TEST_C), vec![TEST_B]);
+}
+
+#[test]
+fn unlimited_parallel_keeps_only_dependencies() {
+    let pipeline = render_edited("execution-unlimited", |rte| {
+        rte["execution"] = json!({"strategy": "parallel"});
+    }).unwrap();
+
+    assert_eq!(needs(&pipeline, TEST_B), vec![TEST_A]);
+    assert_eq!(needs(&pipeline, TEST_C), Vec::<String>::new());
+}
+
+#[test]
+fn serialized_sites_wait_for_test_on_same_site() {
+    let pipeline = render_edited("execution-sites", |rte| {
+        rte["execution"] = json!({"strategy": "parallel", "serialize_sites": true});
+    }).unwrap();
+
+    assert_eq!(needs(&pipeline, TEST_C), vec![TEST_B]);
+}
+
+#[test]
+fn parallel_test_depending_on_sequential_test_is_invalid() {
+    let err = render_edited("execution-mixed", |rte| {
+        rte["connections"][0]["execution"] = json!({"strategy": "sequential"});
+    }).unwrap_err();
+
+    assert!(matches!(err, Sense8Error::ConfigInvalid { ref msg, .. } if msg.contains("depends on sequential test")), "{err}");
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use serde_json::Value;
+use sense8_ci_generator::db::Db;
+
+/// Export object graph of fixture regression.
+fn export() -> Value {
+    let db = Db::new();
+    let (r, _) = common::build(&db, &[]);
+
+    r.export_graph()
+}
+
+#[test]
+fn export_does_not_depend_on_vertex_ids() {
+    assert_eq!(export(), export());
+}
+
+#[test]
+fn vertices_are_sorted_by_id_path_and_hold_all_property_layers() {
+    let graph = export();
+    let vertices = graph["vertices"].as_array().unwrap();
+    let id_paths: Vec<&str> = vertices.iter().map(|v| v["id_path"].as_str().unwrap()).collect();
+    let mut sorted = id_paths.clone();
+    sorted.sort();
+
+    assert_eq!(id_paths, sorted);
+    for v in vertices {
+        for layer in ["base", "gv", "module"] {
+            assert!(!v[layer].is_null(), "vertex <{}> has no {layer} properties", v["id_path"]);
+        }
+    }
+
+    let project = vertices.iter().find(|v| v["type"] == "project").unwrap();
+    assert_eq!(project["id_path"], "project_regression_x");
+    assert!(project["module"]["scripts"].is_array(), "{project}");
+}
+
+#[test]
+fn edges_name_both_ends_by_id_path() {
+    let graph = export();
+    let edges = graph["edges"].as_array().unwrap();
+
+    for (dst, t) in [("project_regression_x__ci", "has_ci"), ("project_regression_x__eut_eut_a", "has_eut")] {
+        assert!(edges.iter().any(|e| e["src"] == "project_regression_x" && e["dst"] == dst && e["type"] == t),
+                "no <{t}> edge to <{dst}>");
+    }
+    assert!(graph["config"].is_object());
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::error::Sense8Error;
+use sense8_ci_generator::filter::{Filter, TagFilter};
+
+const TEST_A: &str = "regression-x-test-site-1-test-a-deploy";
+const TEST_B: &str = "regression-x-test-site-3-test-a-deploy";
+/// Report refers to a collector no test refers to.
+const REPORT: &str = "regression-x-report-report-a-report-a-deploy";
+const COLLECTOR: &str = "regression-x-collector-collector-a-deploy";
+
+/// Render fixture pipeline pruned to `filter` and return which of the test jobs it holds.
+fn render_only(filter: &str) -> (bool, bool) {
+    let filters = [Filter::parse(filter).unwrap()];
+    let pipeline = common::try_render(BACKEND_GITLAB, &[common::EUT_SELECTION], &filters, &TagFilter::default()).unwrap();
+
+    assert!(pipeline.contains(&format!("\n{REPORT}:\n")), "<{filter}> drops report job");
+    assert!(pipeline.contains(&format!("\n{COLLECTOR}:\n")), "<{filter}> drops collector of report");
+
+    (pipeline.contains(&format!("\n{TEST_A}:\n")), pipeline.contains(&format!("\n{TEST_B}:\n")))
+}
+
+#[test]
+fn only_rte_keeps_tests_of_rte() {
+    assert_eq!(render_only("rte=rte_a"), (true, false));
+    assert_eq!(render_only("rte=rte_b"), (false, true));
+}
+
+#[test]
+fn only_site_keeps_tests_of_connections_using_site() {
+    assert_eq!(render_only("site=site_1"), (true, false));
+    assert_eq!(render_only("site=site_3"), (false, true));
+    assert_eq!(render_only("site=site_2"), (true, true));
+}
+
+#[test]
+fn only_test_keeps_selected_test() {
+    assert_eq!(render_only("test=test_a"), (true, false));
+    assert_eq!(render_only("test=test_b"), (false, true));
+}
+
+#[test]
+fn only_without_match_fails() {
+    let filters = [Filter::parse("rte=rte_x").unwrap()];
+    let err = common::try_render(BACKEND_GITLAB, &[common::EUT_SELECTION], &filters, &TagFilter::default()).unwrap_err();
+
+    assert!(matches!(err, Sense8Error::Filter(_)), "unexpected error <{err}>");
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::{Result, Sense8Error};
+use sense8_ci_generator::overlay::Overlay;
+use sense8_ci_generator::Regression;
+
+/// Copy of fixture regression tree in scratch directory of `test`. Returns scratch directory and
+/// regression root path.
+fn copy_fixture(test: &str) -> (PathBuf, PathBuf) {
+    let dir = common::scratch_dir(test);
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    (dir, root)
+}
+
+/// Replace JSON file `file` below `root` by file `to` holding `data`.
+fn convert(root: &Path, file: &str, to: &str, data: impl Fn(&Value) -> String) {
+    let path = root.join(file);
+    let value: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    fs::remove_file(&path).unwrap();
+    fs::write(path.with_file_name(to), data(&value)).unwrap();
+}
+
+/// Render pipeline of regression below `root` configured by `config_file` and `eut_file`.
+fn render_root(root: &Path, config_file: &str, eut_file: &str) -> Result<String> {
+    let db = Db::new();
+    let r = Regression::new(&db, root.to_str().unwrap(), config_file, "", &Some(eut_file.to_string()), &Overlay::default())?;
+    let (p, refs) = r.init()?;
+    r.init_refs(p, &refs)?;
+    r.init_artifacts(p, &refs)?;
+    r.init_stages(p)?;
+    let ctx = r.build_context(p)?;
+
+    r.render(new_backend(BACKEND_GITLAB).unwrap().as_ref(), &ctx)
+}
+
+#[test]
+fn yaml_and_toml_configs_render_same_pipeline_as_json() {
+    let (dir, root) = copy_fixture("formats-convert");
+    convert(&root, "regression/config/config.json", "config.yaml", |v| {
+        // Comments and template expressions survive the pre-render step
+        let yaml = serde_yaml::to_string(v).unwrap();
+        assert!(yaml.contains("value: eu"), "{yaml}");
+        format!("# regression configuration\n{}", yaml.replace("value: eu", "value: '{{ \"eu\" }}'"))
+    });
+    convert(&root, "eut/eut_a/eut.json", "eut.yml", |v| serde_yaml::to_string(v).unwrap());
+    convert(&root, "tests/test_a/config.json", "config.toml", |v| toml::to_string(v).unwrap());
+
+    let pipeline = render_root(&root, "config.yaml", "eut.yml");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(pipeline.unwrap(), common::render(BACKEND_GITLAB, &[]));
+}
+
+#[test]
+fn toml_parse_error_names_file_and_position() {
+    let (dir, root) = copy_fixture("formats-toml-error");
+    convert(&root, "tests/test_a/config.json", "config.toml", |_| "name = \"test_a\"\nscripts_path = \n".to_string());
+
+    let err = render_root(&root, common::CONFIG_FILE, common::EUT_CONFIG_FILE).unwrap_err();
+    fs::remove_dir_all(&dir).unwrap();
+
+    match err {
+        Sense8Error::JsonParse { path, line, .. } => {
+            assert!(path.ends_with("tests/test_a/config.toml"), "unexpected path <{path}>");
+            assert_eq!(line, 2);
+        }
+        err => panic!("unexpected error <{err}>"),
+    }
+}
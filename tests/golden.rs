@@ -0,0 +1,38 @@
+mod common;
+
+use std::fs;
+
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::constants::{BACKEND_GITHUB, BACKEND_GITLAB};
+
+const EXPECTED_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/expected");
+
+/// Compare pipeline file rendered for `backend` with checked in `expected` file.
+fn assert_golden(backend: &str, expected: &str) {
+    assert_matches(backend, &common::render(backend, &[]), expected);
+}
+
+/// Compare pipeline file `rendered` for `backend` with checked in `expected` file.
+fn assert_matches(backend: &str, rendered: &str, expected: &str) {
+    let path = format!("{EXPECTED_PATH}/{expected}");
+    let expected = fs::read_to_string(&path).unwrap();
+
+    assert_eq!(rendered, expected, "rendered {backend} pipeline differs from <{path}>");
+}
+
+#[test]
+fn gitlab_pipeline_matches_golden_file() {
+    assert_golden(BACKEND_GITLAB, "gitlab-ci.yml");
+}
+
+#[test]
+fn gitlab_native_pipeline_matches_golden_file() {
+    let rendered = common::render_native(BACKEND_GITLAB, &[]);
+    new_backend(BACKEND_GITLAB).unwrap().verify(&rendered).unwrap();
+    assert_matches(BACKEND_GITLAB, &rendered, "gitlab-ci-native.yml");
+}
+
+#[test]
+fn github_workflow_matches_golden_file() {
+    assert_golden(BACKEND_GITHUB, "github-workflow.yml");
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use graphviz_rust::printer::{DotPrinter, PrinterContext};
+use sense8_ci_generator::constants::{GV_VIEW_ALL, GV_VIEW_REFS, GV_VIEW_STAGES, GV_VIEW_TOPOLOGY};
+use sense8_ci_generator::db::Db;
+
+const EUTS: &str = r#"eut.variants=[{"name":"eut_a","module":"eut_a"},{"name":"eut_b","module":"eut_b"}]"#;
+
+/// Parse DOT rendered by `to_gv`, print it back and check printed DOT parses to the same graph.
+fn assert_round_trip(sets: &[&str], clusters: bool, view: &str) {
+    let db = Db::new();
+    let (r, _) = common::build(&db, sets);
+    let data = r.to_gv(clusters, view).unwrap();
+
+    let graph = graphviz_rust::parse(&data).unwrap();
+    let printed = graph.print(&mut PrinterContext::default());
+    let reparsed = graphviz_rust::parse(&printed).unwrap();
+
+    assert_eq!(graph, reparsed, "view <{view}> clusters <{clusters}> does not round-trip:\n{printed}");
+    assert_eq!(printed, reparsed.print(&mut PrinterContext::default()));
+}
+
+#[test]
+fn gv_round_trips_every_view() {
+    for view in [GV_VIEW_ALL, GV_VIEW_STAGES, GV_VIEW_TOPOLOGY, GV_VIEW_REFS] {
+        assert_round_trip(&[], false, view);
+        assert_round_trip(&[], true, view);
+    }
+}
+
+#[test]
+fn gv_round_trips_eut_clusters() {
+    assert_round_trip(&[EUTS], false, GV_VIEW_ALL);
+    assert_round_trip(&[EUTS], true, GV_VIEW_ALL);
+}
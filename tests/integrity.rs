@@ -0,0 +1,69 @@
+mod common;
+
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::integrity::{IntegrityReport, IssueKind};
+use sense8_ci_generator::{EdgeTypes, VertexTypes};
+
+/// Build object graph of fixture regression, let `edit` change it and check its integrity.
+fn check_edited(sets: &[&str], edit: impl Fn(&Db, uuid::Uuid)) -> IntegrityReport {
+    let db = Db::new();
+    let r = common::regression(&db, sets);
+    let (p, refs) = r.init().unwrap();
+    r.init_refs(p, &refs).unwrap();
+    r.init_artifacts(p, &refs).unwrap();
+    r.init_stages(p).unwrap();
+    edit(&db, p);
+
+    r.check_integrity(&refs)
+}
+
+/// Issues of `kind` in `report` as id_path and message pairs.
+fn issues(report: &IntegrityReport, kind: IssueKind) -> Vec<(&str, &str)> {
+    report.issues.iter().filter(|i| i.kind == kind).map(|i| (i.id_path.as_str(), i.msg.as_str())).collect()
+}
+
+#[test]
+fn fixture_graph_is_intact() {
+    let report = check_edited(&[], |_, _| {});
+
+    assert!(report.is_ok(), "{report}");
+}
+
+#[test]
+fn unlinked_site_is_orphan_without_provider() {
+    let report = check_edited(&[], |db, _| {
+        db.create_object_and_init(VertexTypes::Site, &mut vec!["sites".to_string()], "site_9", 0).unwrap();
+    });
+
+    assert_eq!(issues(&report, IssueKind::Orphan), vec![("sites__site_site_9", "site object is not reachable from project")]);
+    assert_eq!(issues(&report, IssueKind::SiteWithoutProvider), vec![("sites__site_site_9", "site does not use any provider")]);
+}
+
+#[test]
+fn stage_chain_looping_back_is_cycle() {
+    let report = check_edited(&[], |db, p| {
+        let ci = db.get_object_neighbour_out(&p, EdgeTypes::HasCi).unwrap();
+        let first = db.get_object_neighbour_out(&ci.id, EdgeTypes::HasDeployStages).unwrap();
+        let mut last = first.clone();
+        while let Some(next) = db.get_object_neighbour_out(&last.id, EdgeTypes::NextStage) {
+            last = next;
+        }
+        db.create_relationship(&last, &first).unwrap();
+    });
+    let cycles = issues(&report, IssueKind::StageCycle);
+
+    assert_eq!(cycles.len(), 1, "{report}");
+    assert!(cycles[0].1.ends_with("leads back to <project_regression_x__ci__deploy_project_deploy>"), "{report}");
+}
+
+#[test]
+fn unresolved_ref_is_dangling() {
+    // init_refs fails on the first unknown ref. Check graph before refs are resolved to get all
+    let db = Db::new();
+    let r = common::regression(&db, &["eut.module=eut_dangling_ref"]);
+    let (_, refs) = r.init().unwrap();
+    let report = r.check_integrity(&refs);
+
+    assert_eq!(issues(&report, IssueKind::DanglingReference),
+               vec![("applications__application_app_a", "refers to unknown site <site_9>")], "{report}");
+}
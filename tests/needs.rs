@@ -0,0 +1,37 @@
+mod common;
+
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+
+const TEST_A: &str = "regression-x-test-site-1-test-a";
+/// Depends on TEST_A.
+const TEST_B: &str = "regression-x-test-site-3-test-a";
+/// Waits for TEST_B to keep one test running at a time.
+const TEST_C: &str = "regression-x-test-site-2-test-a";
+
+/// `needs` of `job` in GitLab `pipeline` as pairs of needed job and optional flag.
+fn needs(pipeline: &str, job: &str) -> Vec<(String, bool)> {
+    new_backend(BACKEND_GITLAB).unwrap().verify(pipeline).unwrap();
+    let pipeline: serde_yaml::Value = serde_yaml::from_str(pipeline).unwrap();
+
+    pipeline[job]["needs"].as_sequence().into_iter().flatten()
+        .map(|n| (n["job"].as_str().unwrap().to_string(), n["optional"].as_bool().unwrap_or(false)))
+        .collect()
+}
+
+#[test]
+fn native_needs_of_declared_dependencies_are_required() {
+    let pipeline = common::render_native(BACKEND_GITLAB, &[common::EUT_DEPENDENCIES]);
+
+    assert_eq!(needs(&pipeline, &format!("{TEST_A}-deploy")), vec![]);
+    assert_eq!(needs(&pipeline, &format!("{TEST_B}-deploy")), vec![(format!("{TEST_A}-deploy"), false)]);
+    assert_eq!(needs(&pipeline, &format!("{TEST_C}-deploy")), vec![(format!("{TEST_B}-deploy"), true)]);
+}
+
+#[test]
+fn template_needs_of_tests_are_required() {
+    let pipeline = common::render(BACKEND_GITLAB, &[common::EUT_DEPENDENCIES]);
+
+    assert_eq!(needs(&pipeline, &format!("{TEST_B}-seq-deploy")), vec![(format!("{TEST_A}-seq-deploy"), false)]);
+    assert_eq!(needs(&pipeline, &format!("{TEST_C}-seq-deploy")), vec![(format!("{TEST_B}-seq-deploy"), false)]);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use serde_json::{json, Value};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::overlay::{ArrayMerge, Overlay};
+use sense8_ci_generator::Regression;
+
+const OVERLAYS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/overlays");
+
+/// Overlay of fixture overlay `files` merging arrays with `strategy`.
+fn overlay(files: &[&str], strategy: ArrayMerge) -> Overlay {
+    let files: Vec<String> = files.iter().map(|f| format!("{OVERLAYS_PATH}/{f}")).collect();
+
+    Overlay::load(&files, strategy).unwrap()
+}
+
+/// Effective `ci` section of fixture regression configuration with `overlay` applied.
+fn effective_ci(overlay: &Overlay) -> Value {
+    let db = Db::new();
+    let r = Regression::new(&db, common::ROOT_PATH, common::CONFIG_FILE, "", &Some(common::EUT_CONFIG_FILE.to_string()), overlay).unwrap();
+
+    serde_json::to_value(&r.config).unwrap()["ci"].clone()
+}
+
+#[test]
+fn array_merge_strategies() {
+    let variables = |strategy| effective_ci(&overlay(&["prod.yaml", "staging.json"], strategy))["variables"].clone();
+
+    assert_eq!(variables(ArrayMerge::Replace), json!([{"name": "STAGE", "value": "staging"}]));
+    assert_eq!(variables(ArrayMerge::Append), json!([
+        {"name": "REGION", "value": "eu"}, {"name": "REGION", "value": "us"},
+        {"name": "STAGE", "value": "prod"}, {"name": "STAGE", "value": "staging"},
+    ]));
+    assert_eq!(variables(ArrayMerge::MergeByName), json!([{"name": "REGION", "value": "us"}, {"name": "STAGE", "value": "staging"}]));
+}
+
+#[test]
+fn later_overlays_win() {
+    let ci = effective_ci(&overlay(&["prod.yaml", "staging.json"], ArrayMerge::Replace));
+
+    assert_eq!(ci["artifacts"], json!({"path": "artifacts", "expire_in": "2 days"}));
+    assert_eq!(ci["image"], "alpine:3");
+}
+
+#[test]
+fn module_configs_are_overlaid() {
+    let db = Db::new();
+    let overlay = overlay(&["prod.yaml"], ArrayMerge::Replace);
+    let r = Regression::new(&db, common::ROOT_PATH, common::CONFIG_FILE, "", &Some(common::EUT_CONFIG_FILE.to_string()), &overlay).unwrap();
+    let (p, refs) = r.init().unwrap();
+    r.init_refs(p, &refs).unwrap();
+    let graph = r.export_graph();
+    let tests: Vec<&Value> = graph["vertices"].as_array().unwrap().iter().filter(|v| v["type"] == "test").collect();
+
+    assert!(!tests.is_empty());
+    for t in tests {
+        assert_eq!(t["module"]["timeout"], 3600, "{t}");
+    }
+}
+
+#[test]
+fn show_effective_config_prints_overlaid_configuration() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_sense8"))
+        .args(["--root-path", common::ROOT_PATH, "--config-file", common::CONFIG_FILE,
+            "--overlay", &format!("{OVERLAYS_PATH}/prod.yaml"), "--show-effective-config"])
+        .env("RUST_LOG", "off")
+        .output()
+        .unwrap();
+    let config: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(config["ci"]["artifacts"]["expire_in"], "1 week");
+}
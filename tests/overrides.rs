@@ -0,0 +1,71 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::{Result, Sense8Error};
+use sense8_ci_generator::overlay::{Assignment, Overlay};
+use sense8_ci_generator::Regression;
+
+const VARS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/overlays/vars.yaml");
+
+/// Effective `ci` section of regression below `root` with `overlay` applied.
+fn effective_ci(root: &str, overlay: &Overlay) -> Result<Value> {
+    let db = Db::new();
+    let r = Regression::new(&db, root, common::CONFIG_FILE, "", &Some(common::EUT_CONFIG_FILE.to_string()), overlay)?;
+
+    Ok(serde_json::to_value(&r.config).unwrap()["ci"].clone())
+}
+
+fn assignments(sets: &[&str]) -> Vec<Assignment> {
+    sets.iter().map(|s| Assignment::parse(s).unwrap()).collect()
+}
+
+#[test]
+fn sets_override_values_after_load() {
+    let overlay = Overlay::default().with_sets(&assignments(&["ci.variables.0.value=us", "ci.artifacts.expire_in=3 days",
+        r#"ci.tags=["runner","large"]"#]));
+    let ci = effective_ci(common::ROOT_PATH, &overlay).unwrap();
+
+    assert_eq!(ci["variables"], json!([{"name": "REGION", "value": "us"}]));
+    assert_eq!(ci["artifacts"]["expire_in"], "3 days");
+    assert_eq!(ci["tags"], json!(["runner", "large"]));
+}
+
+#[test]
+fn invalid_sets_are_rejected() {
+    assert!(matches!(Assignment::parse("ci.image"), Err(Sense8Error::Override(_))));
+    assert!(matches!(Assignment::parse("ci..image=alpine"), Err(Sense8Error::Override(_))));
+
+    let overlay = Overlay::default().with_sets(&assignments(&["ci.variables.3.value=us"]));
+    assert!(matches!(effective_ci(common::ROOT_PATH, &overlay), Err(Sense8Error::Override(_))));
+}
+
+#[test]
+fn vars_and_env_are_available_to_configuration_template() {
+    let dir = common::scratch_dir("overrides-vars");
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    let file = root.join("regression/config/config.json");
+    let mut config: Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    config["ci"]["variables"] = json!([
+        {"name": "REGION", "value": "{{ vars.region }}"},
+        {"name": "RELEASE", "value": "{{ vars.release }}"},
+        {"name": "PACKAGE", "value": "{{ env(name='CARGO_PKG_NAME') }}"},
+        {"name": "PROFILE", "value": "{{ env(name='SENSE8_UNSET_VARIABLE', default='default') }}"},
+    ]);
+    fs::write(&file, config.to_string()).unwrap();
+
+    let overlay = Overlay::default().with_vars(Some(VARS_FILE), &assignments(&["release=2.0.1"])).unwrap();
+    let ci = effective_ci(root.to_str().unwrap(), &overlay);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(ci.unwrap()["variables"], json!([
+        {"name": "REGION", "value": "us"},
+        {"name": "RELEASE", "value": "2.0.1"},
+        {"name": "PACKAGE", "value": env!("CARGO_PKG_NAME")},
+        {"name": "PROFILE", "value": "default"},
+    ]));
+}
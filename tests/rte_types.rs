@@ -0,0 +1,36 @@
+mod common;
+
+use sense8_ci_generator::constants::{BACKEND_GITLAB, KEY_DST, KEY_SRC, RTE_TYPE_A};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::Result;
+use sense8_ci_generator::objects::{register_rte_type, Rte, RteCharacteristics};
+use sense8_ci_generator::RteCtxParameters;
+
+/// Rte type building no connection render contexts, hence no rte component, test or
+/// verification jobs.
+struct RteTypeSilent;
+
+impl RteCharacteristics for RteTypeSilent {
+    fn init(&self, _rte: &Box<Rte<'_>>) -> Result<()> {
+        Ok(())
+    }
+
+    fn build_conn_ctx(&self, _params: RteCtxParameters) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn silent(_db: &Db) -> Box<dyn RteCharacteristics + '_> {
+    Box::new(RteTypeSilent)
+}
+
+#[test]
+fn registered_rte_type_replaces_builtin() {
+    assert!(common::render(BACKEND_GITLAB, &[]).contains("\nregression-x-test-site-1-test-a-deploy:\n"));
+
+    register_rte_type(RTE_TYPE_A, &[KEY_SRC, KEY_DST], silent);
+    let pipeline = common::render(BACKEND_GITLAB, &[]);
+
+    assert!(!pipeline.contains("\nregression-x-test-site-1-test-a-deploy:\n"));
+    assert!(pipeline.contains("\nregression-x-eut-eut-a-site-1-deploy:\n"));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::error::Sense8Error;
+use sense8_ci_generator::objects::config::{schema, SCHEMA_KINDS};
+use sense8_ci_generator::overlay::Overlay;
+use sense8_ci_generator::Regression;
+
+#[test]
+fn every_kind_has_schema() {
+    for kind in SCHEMA_KINDS {
+        let schema = serde_json::to_value(schema(kind).unwrap()).unwrap();
+        assert!(schema["properties"].is_object(), "schema of <{kind}> has no properties");
+    }
+
+    assert!(schema("unknown").is_none());
+}
+
+#[test]
+fn test_module_schema_requires_scripts() {
+    let schema = serde_json::to_value(schema("test").unwrap()).unwrap();
+    let required: Vec<&str> = schema["required"].as_array().unwrap().iter().map(|r| r.as_str().unwrap()).collect();
+
+    assert!(required.contains(&"scripts"), "{schema}");
+}
+
+#[test]
+fn invalid_module_config_names_module_and_file() {
+    let dir = common::scratch_dir("schema-invalid");
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    let file = root.join("tests/test_a/config.json");
+    let mut cfg: serde_json::Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    cfg["scripts_path"] = serde_json::json!(5);
+    fs::write(&file, cfg.to_string()).unwrap();
+
+    let db = Db::new();
+    let err = Regression::new(&db, root.to_str().unwrap(), common::CONFIG_FILE, "",
+                              &Some(common::EUT_CONFIG_FILE.to_string()), &Overlay::default())
+        .and_then(|r| r.init().map(|_| ()))
+        .unwrap_err();
+    fs::remove_dir_all(&dir).unwrap();
+
+    match err {
+        Sense8Error::ModuleConfigInvalid { kind, module, path, .. } => {
+            assert_eq!((kind.as_str(), module.as_str()), ("test", "test_a"));
+            assert!(path.ends_with("tests/test_a/config.json"), "unexpected path <{path}>");
+        }
+        err => panic!("unexpected error <{err}>"),
+    }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use sense8_ci_generator::backend::new_backend;
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::overlay::Overlay;
+use sense8_ci_generator::Regression;
+
+#[test]
+fn loaded_graph_renders_saved_pipeline_after_source_edit() {
+    let dir = common::scratch_dir("snapshot");
+    let root = dir.join("regression");
+    let graph = dir.join("graph.msgpack");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    let backend = new_backend(BACKEND_GITLAB).unwrap();
+
+    let saved = {
+        let db = Db::create(graph.to_str().unwrap());
+        let r = Regression::new(&db, root.to_str().unwrap(), common::CONFIG_FILE, "",
+                                &Some(common::EUT_CONFIG_FILE.to_string()), &Overlay::default()).unwrap();
+        let (p, refs) = r.init().unwrap();
+        r.init_refs(p, &refs).unwrap();
+        r.init_artifacts(p, &refs).unwrap();
+        r.init_stages(p).unwrap();
+        r.save(p).unwrap();
+        r.render(backend.as_ref(), &r.build_context(p).unwrap()).unwrap()
+    };
+
+    // Sources the graph was built from are neither read again nor needed
+    let script = root.join("eut/eut_a/scripts/deploy.sh");
+    fs::write(&script, fs::read_to_string(&script).unwrap().replace("echo deploy", "echo edited")).unwrap();
+    fs::remove_file(root.join("regression/config").join(common::CONFIG_FILE)).unwrap();
+
+    let db = Db::open(graph.to_str().unwrap()).unwrap();
+    let (r, p) = Regression::open(&db, "").unwrap();
+    let loaded = r.render(backend.as_ref(), &r.build_context(p).unwrap()).unwrap();
+
+    assert!(saved.contains("echo deploy"));
+    assert_eq!(loaded, saved);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use sense8_ci_generator::constants::{GV_FORMAT_DOT, GV_FORMAT_MERMAID};
+use sense8_ci_generator::db::Db;
+
+const TEST_A: &str = "regression-x-test-site-1-test-a-deploy";
+/// Depends on TEST_A.
+const TEST_B: &str = "regression-x-test-site-3-test-a-deploy";
+
+/// Stage DAG of fixture regression with parallel tests in `format`.
+fn stages_graph(format: &str) -> String {
+    let db = Db::new();
+    let (r, p) = common::build(&db, &[common::EUT_DEPENDENCIES]);
+    let ctx = r.build_context(p).unwrap();
+
+    r.stages_graph(&ctx, format).unwrap()
+}
+
+#[test]
+fn dot_chains_stages_and_links_needs() {
+    let dot = stages_graph(GV_FORMAT_DOT);
+    graphviz_rust::parse(&dot).unwrap();
+
+    assert!(dot.contains("\"stage:application-deploy\" -> \"stage:test-deploy\" [style = bold];"), "{dot}");
+    assert!(dot.contains(&format!("\"stage:test-parallel-deploy\" -> \"{TEST_A}\" [style = dashed, arrowhead = none];")), "{dot}");
+    assert!(dot.contains(&format!("\"{TEST_B}\" -> \"{TEST_A}\" [label = \"needs\", color = blue];")), "{dot}");
+}
+
+#[test]
+fn mermaid_holds_jobs_in_stage_subgraphs() {
+    let mermaid = stages_graph(GV_FORMAT_MERMAID);
+    let lines: Vec<&str> = mermaid.lines().collect();
+    let stage = lines.iter().position(|l| *l == "    subgraph s7[\"test-parallel-deploy\"]").unwrap();
+
+    assert_eq!(lines[0], "flowchart LR");
+    assert_eq!(lines[stage + 1], format!("        j7[\"{TEST_A}\"]"));
+    assert_eq!(lines[stage + 2], format!("        j8[\"{TEST_B}\"]"));
+    assert!(lines.contains(&"    s6 --> s7"), "{mermaid}");
+    assert!(lines.contains(&"    j8 -. needs .-> j7"), "{mermaid}");
+}
+
+#[test]
+fn entry_page_embeds_stage_dag() {
+    let db = Db::new();
+    let r = common::regression_with_template(&db, &[], common::TEMPLATE_PATH);
+    let (p, refs) = r.init().unwrap();
+    r.init_refs(p, &refs).unwrap();
+    r.init_artifacts(p, &refs).unwrap();
+    r.init_stages(p).unwrap();
+    let ctx = r.build_context(p).unwrap();
+    let page = r.render_entry_page(&ctx).unwrap();
+
+    assert!(page.contains(&format!("```mermaid\n{}```", r.stages_graph(&ctx, GV_FORMAT_MERMAID).unwrap())), "{page}");
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use sense8_ci_generator::constants::BACKEND_GITLAB;
+use sense8_ci_generator::filter::TagFilter;
+
+/// Test tagged smoke.
+const TEST_A: &str = "regression-x-test-site-1-test-a-deploy";
+/// Test tagged nightly.
+const TEST_B: &str = "regression-x-test-site-3-test-a-deploy";
+
+/// Render fixture pipeline pruned to `tags` and return which of the test jobs it holds.
+fn render_tags(include: &[&str], exclude: &[&str]) -> (bool, bool) {
+    let tags = TagFilter {
+        include: include.iter().map(|t| t.to_string()).collect(),
+        exclude: exclude.iter().map(|t| t.to_string()).collect(),
+    };
+    let pipeline = common::try_render(BACKEND_GITLAB, &[common::EUT_SELECTION], &[], &tags).unwrap();
+
+    (pipeline.contains(&format!("\n{TEST_A}:\n")), pipeline.contains(&format!("\n{TEST_B}:\n")))
+}
+
+#[test]
+fn include_tags_keeps_tagged_tests() {
+    assert_eq!(render_tags(&["smoke"], &[]), (true, false));
+    assert_eq!(render_tags(&["nightly"], &[]), (false, true));
+    assert_eq!(render_tags(&["smoke", "nightly"], &[]), (true, true));
+}
+
+#[test]
+fn exclude_tags_drops_tagged_tests() {
+    assert_eq!(render_tags(&[], &["smoke"]), (false, true));
+    assert_eq!(render_tags(&["smoke", "nightly"], &["nightly"]), (true, false));
+}
+
+#[test]
+fn include_tags_without_match_renders_pipeline_without_tests() {
+    assert_eq!(render_tags(&["weekly"], &[]), (false, false));
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use std::fs;
+
+use sense8_ci_generator::constants::{GV_FORMAT_MERMAID, TOPOLOGY_FORMAT_PLANTUML};
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::topology::RteTopology;
+
+const EXPECTED_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/expected");
+
+/// Topology of every rte of fixture regression with `sets` applied.
+fn topology(sets: &[&str]) -> Vec<RteTopology> {
+    let db = Db::new();
+    common::build(&db, sets);
+
+    RteTopology::load(&db)
+}
+
+#[test]
+fn rte_connections_are_collected() {
+    let rtes = topology(&[]);
+
+    assert_eq!(rtes.len(), 1);
+    let rte = &rtes[0];
+    assert_eq!((rte.name.as_str(), rte.eut.as_deref(), rte.provider.as_str()), ("rte_a", None, "aws"));
+    let conn = &rte.connections[0];
+    assert_eq!((conn.source.site.as_str(), conn.source.components.clone()), ("site_1", vec![("src".to_string(), "client".to_string())]));
+    assert_eq!(conn.destinations.iter().map(|d| d.site.as_str()).collect::<Vec<_>>(), vec!["site_2"]);
+    assert_eq!(conn.tests, vec!["test_a"]);
+}
+
+#[test]
+fn topology_matches_golden_files() {
+    let rtes = topology(&[]);
+
+    for format in [GV_FORMAT_MERMAID, TOPOLOGY_FORMAT_PLANTUML] {
+        let path = format!("{EXPECTED_PATH}/{}", rtes[0].file_name(format));
+        let expected = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(rtes[0].render(format), expected, "rendered topology differs from <{path}>");
+    }
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use sense8_ci_generator::db::Db;
+use sense8_ci_generator::overlay::Overlay;
+use sense8_ci_generator::validate::{validate, IssueKind, ValidationReport};
+
+/// Validate copy of fixture regression tree after `edit` changed it.
+fn validate_edited(test: &str, edit: impl Fn(&Path)) -> ValidationReport {
+    let dir = common::scratch_dir(test);
+    let root = dir.join("regression");
+    common::copy_dir(Path::new(common::ROOT_PATH), &root);
+    edit(&root);
+
+    let report = validate(&Db::new(), root.to_str().unwrap(), common::CONFIG_FILE,
+                          &Some(common::EUT_CONFIG_FILE.to_string()), &Overlay::default());
+    fs::remove_dir_all(&dir).unwrap();
+    report
+}
+
+#[test]
+fn fixture_regression_is_valid() {
+    let report = validate_edited("validate-fixture", |_| {});
+
+    assert!(report.is_ok(), "{report}");
+}
+
+#[test]
+fn missing_scripts_of_every_module_kind_are_reported() {
+    let report = validate_edited("validate-scripts", |root| {
+        for script in ["eut/eut_a/scripts/deploy.sh", "rte/rte_a/scripts/aws/server/deploy.sh",
+            "project/regression_x/scripts/deploy.sh", "dashboard/dashboard_a/scripts/aws/deploy.sh"] {
+            fs::remove_file(root.join(script)).unwrap();
+        }
+    });
+    let mut missing: Vec<&str> = report.issues.iter()
+        .filter(|i| i.kind == IssueKind::MissingScript)
+        .map(|i| i.location.as_str())
+        .collect();
+    missing.sort();
+
+    assert_eq!(missing, vec!["dashboard/dashboard_a", "eut/eut_a", "eut/eut_a/rtes/rte_a", "project/regression_x"], "{report}");
+}
+
+#[test]
+fn site_without_count_is_reported() {
+    let report = validate_edited("validate-count", |root| {
+        let file = root.join("eut/eut_a/eut.json");
+        let mut eut: serde_json::Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+        eut["sites"]["site_1"].as_object_mut().unwrap().remove("count");
+        fs::write(&file, eut.to_string()).unwrap();
+    });
+
+    assert!(report.issues.iter().any(|i| i.kind == IssueKind::Config && i.msg.contains("count")), "{report}");
+}